@@ -1,4 +1,5 @@
 use crate::errors::{Result, ScrapingError};
+use crate::filter::{LeadFilter, SourceKind};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
@@ -8,6 +9,234 @@ pub struct Config {
     pub scraper: ScraperConfig,
     pub sources: SourcesConfig,
     pub patterns: PatternsConfig,
+    #[serde(default)]
+    pub forges: ForgesConfig,
+    #[serde(default)]
+    pub technologies: TechnologiesConfig,
+    #[serde(default)]
+    pub tagging: TaggingConfig,
+    #[serde(default)]
+    pub email_validation: EmailValidationConfig,
+    #[serde(default)]
+    pub websites: WebsitesConfig,
+    #[serde(default)]
+    pub scoring: ScoringConfig,
+    /// Ideal customer profiles, each written to its own outputs
+    #[serde(default)]
+    pub segments: Vec<SegmentConfig>,
+}
+
+/// Named segment: leads matching a filter and, optionally, a minimum score
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SegmentConfig {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Same conditions as scoring rules (`config/scoring.yaml`)
+    #[serde(default)]
+    pub when: LeadFilter,
+    /// Minimum total from the scoring model
+    #[serde(default)]
+    pub min_score: Option<f32>,
+}
+
+/// Weighted rule scoring of leads
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ScoringConfig {
+    pub enabled: bool,
+    /// Scoring model file
+    pub model: String,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            model: "config/scoring.yaml".to_string(),
+        }
+    }
+}
+
+/// Website canonicalization, liveness checks and dedup
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WebsitesConfig {
+    /// Fetch each website, following redirects, to record its final URL
+    /// and mark parked or dead domains
+    pub check: bool,
+    /// Merge leads sharing a canonical domain (or repository)
+    pub dedupe: bool,
+    /// Query parameters stripped from URLs; a trailing `*` matches a prefix
+    pub tracking_params: Vec<String>,
+    /// Directory sites whose links are listings, not the lead's own website
+    pub listing_hosts: Vec<String>,
+    /// Domain marketplaces and parking services redirected to by parked domains
+    pub parking_hosts: Vec<String>,
+    /// Page text marking a parked or for-sale domain (lowercase)
+    pub parked_phrases: Vec<String>,
+}
+
+impl Default for WebsitesConfig {
+    fn default() -> Self {
+        let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+        Self {
+            check: false,
+            dedupe: true,
+            tracking_params: strings(&[
+                "utm_*", "gclid", "gclsrc", "dclid", "fbclid", "msclkid", "mc_cid", "mc_eid",
+                "igshid", "_hsenc", "_hsmi", "mkt_tok", "yclid", "ref", "ref_src",
+            ]),
+            listing_hosts: strings(&[
+                "betalist.com",
+                "ycombinator.com",
+                "producthunt.com",
+                "news.ycombinator.com",
+            ]),
+            parking_hosts: strings(&[
+                "sedo.com",
+                "sedoparking.com",
+                "dan.com",
+                "afternic.com",
+                "hugedomains.com",
+                "bodis.com",
+                "parkingcrew.net",
+                "above.com",
+                "undeveloped.com",
+            ]),
+            parked_phrases: strings(&[
+                "this domain is for sale",
+                "this domain may be for sale",
+                "buy this domain",
+                "domain is parked",
+                "parked free, courtesy of",
+                "the domain name is for sale",
+                "inquire about this domain",
+            ]),
+        }
+    }
+}
+
+/// Deliverability checks run on every email found for a lead
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct EmailValidationConfig {
+    pub enabled: bool,
+    /// Disposable and free-provider domain lists
+    pub domain_lists: String,
+    pub dns: DnsConfig,
+    pub smtp: SmtpProbeConfig,
+}
+
+impl Default for EmailValidationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            domain_lists: "config/email_domains.yaml".to_string(),
+            dns: DnsConfig::default(),
+            smtp: SmtpProbeConfig::default(),
+        }
+    }
+}
+
+/// Resolver used for MX/A lookups
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DnsConfig {
+    /// Nameservers as `ip:port`; empty uses the system resolver
+    pub nameservers: Vec<String>,
+    pub timeout_seconds: u64,
+}
+
+impl Default for DnsConfig {
+    fn default() -> Self {
+        Self {
+            nameservers: Vec::new(),
+            timeout_seconds: 5,
+        }
+    }
+}
+
+/// SMTP `RCPT TO` probing of mailboxes
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SmtpProbeConfig {
+    pub enabled: bool,
+    /// Connect here instead of the domain's MX hosts (relay or local stand-in)
+    pub host: Option<String>,
+    pub port: u16,
+    /// Name sent with `EHLO`
+    pub helo: String,
+    /// Envelope sender for `MAIL FROM`
+    pub mail_from: String,
+    pub timeout_seconds: u64,
+    /// Also probe a random mailbox to detect catch-all domains
+    pub detect_catch_all: bool,
+}
+
+impl Default for SmtpProbeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: None,
+            port: 25,
+            helo: "localhost".to_string(),
+            mail_from: "postmaster@localhost".to_string(),
+            timeout_seconds: 10,
+            detect_catch_all: true,
+        }
+    }
+}
+
+/// Industry tagging from a keyword taxonomy
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TaggingConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Taxonomy file
+    #[serde(default = "default_taxonomy")]
+    pub taxonomy: String,
+    /// Fetch each lead's homepage for its title, description and keywords
+    #[serde(default)]
+    pub website_metadata: bool,
+}
+
+fn default_taxonomy() -> String {
+    "config/taxonomy.yaml".to_string()
+}
+
+impl Default for TaggingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            taxonomy: default_taxonomy(),
+            website_metadata: false,
+        }
+    }
+}
+
+/// Website technology fingerprinting
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TechnologiesConfig {
+    /// Fetch each lead's homepage and match it against the fingerprints
+    #[serde(default)]
+    pub enabled: bool,
+    /// Fingerprint rule file
+    #[serde(default = "default_fingerprints")]
+    pub rules: String,
+}
+
+fn default_fingerprints() -> String {
+    "config/fingerprints.yaml".to_string()
+}
+
+impl Default for TechnologiesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rules: default_fingerprints(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,6 +245,9 @@ pub struct ScraperConfig {
     pub max_concurrent_requests: usize,
     pub user_agent: String,
     pub github_token: Option<String>, // NEW: Optional GitHub token for higher rate limits
+    /// Visit `/team`, `/about`, ... on lead websites to collect people
+    #[serde(default)]
+    pub team_pages: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -23,6 +255,66 @@ pub struct SourcesConfig {
     pub ycombinator: YCombinatorConfig,
     pub github_awesome: GitHubAwesomeConfig,
     pub betalist: BetaListConfig,
+    #[serde(default)]
+    pub github_search: GitHubSearchConfig,
+    #[serde(default)]
+    pub package_registries: PackageRegistriesConfig,
+}
+
+impl SourcesConfig {
+    /// Enable exactly the listed sources, whatever their `enabled` flags say
+    pub fn only(&mut self, sources: &[SourceKind]) {
+        let keep = |kind| sources.contains(&kind);
+        self.ycombinator.enabled = keep(SourceKind::Ycombinator);
+        self.github_awesome.enabled = keep(SourceKind::GithubAwesome);
+        self.betalist.enabled = keep(SourceKind::Betalist);
+        self.github_search.enabled = keep(SourceKind::GithubSearch);
+        self.package_registries.enabled = keep(SourceKind::PackageRegistry);
+    }
+
+    /// Disable the listed sources
+    pub fn exclude(&mut self, sources: &[SourceKind]) {
+        let drop = |kind| sources.contains(&kind);
+        self.ycombinator.enabled &= !drop(SourceKind::Ycombinator);
+        self.github_awesome.enabled &= !drop(SourceKind::GithubAwesome);
+        self.betalist.enabled &= !drop(SourceKind::Betalist);
+        self.github_search.enabled &= !drop(SourceKind::GithubSearch);
+        self.package_registries.enabled &= !drop(SourceKind::PackageRegistry);
+    }
+
+    /// Keep only the endpoints, awesome-list repositories and registry
+    /// keywords/owners `keep` accepts; sources left with none are disabled.
+    /// GitHub search queries are not endpoints and stay as they are.
+    pub fn retain_endpoints(&mut self, keep: impl Fn(&str) -> bool) {
+        self.ycombinator.endpoints.retain(|e| keep(e));
+        self.ycombinator.enabled &= !self.ycombinator.endpoints.is_empty();
+        self.betalist.endpoints.retain(|e| keep(e));
+        self.betalist.enabled &= !self.betalist.endpoints.is_empty();
+        self.github_awesome.repositories.retain(|r| keep(r));
+        self.github_awesome.enabled &= !self.github_awesome.repositories.is_empty();
+
+        let registries = &mut self.package_registries;
+        for registry in [
+            &mut registries.crates_io,
+            &mut registries.npm,
+            &mut registries.pypi,
+        ] {
+            registry.keywords.retain(|k| keep(k));
+            registry.owners.retain(|o| keep(o));
+            registry.enabled &= !(registry.keywords.is_empty() && registry.owners.is_empty());
+        }
+        registries.enabled &=
+            registries.crates_io.enabled || registries.npm.enabled || registries.pypi.enabled;
+    }
+
+    /// Cap the leads every source collects
+    pub fn cap_leads(&mut self, max_leads: usize) {
+        self.ycombinator.max_leads = Some(max_leads);
+        self.github_awesome.max_leads = Some(max_leads);
+        self.betalist.max_leads = Some(max_leads);
+        self.github_search.max_leads = Some(max_leads);
+        self.package_registries.max_leads = Some(max_leads);
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -31,13 +323,91 @@ pub struct YCombinatorConfig {
     pub base_url: String,
     pub endpoints: Vec<String>,
     pub selectors: HashMap<String, String>,
+    /// Fetch each company's YC page for its founders
+    #[serde(default)]
+    pub fetch_founders: bool,
+    /// Stop once this many leads are collected (none = no cap)
+    #[serde(default)]
+    pub max_leads: Option<usize>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GitHubAwesomeConfig {
     pub enabled: bool,
+    /// `owner/repo` shorthands (GitHub) or full repository URLs on any configured forge
     pub repositories: Vec<String>,
+    /// How many levels of linked awesome lists to follow (0 = no recursion)
+    #[serde(default)]
+    pub max_depth: usize,
+    /// Maximum number of lists fetched per run, including the configured ones
+    #[serde(default = "default_max_lists")]
+    pub max_lists: usize,
+    /// Stop once this many leads are collected (none = no cap)
+    #[serde(default)]
+    pub max_leads: Option<usize>,
+}
+
+fn default_max_lists() -> usize {
+    50
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitHubSearchConfig {
+    pub enabled: bool,
     pub api_base: String,
+    pub queries: Vec<GitHubSearchQuery>,
+    pub per_page: u32,
+    pub max_pages: u32,
+    /// Stop once this many leads are collected (none = no cap)
+    #[serde(default)]
+    pub max_leads: Option<usize>,
+}
+
+/// What a GitHub search query looks for; every hit is reduced to its owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GitHubSearchKind {
+    Repositories,
+    Organizations,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GitHubSearchQuery {
+    pub kind: GitHubSearchKind,
+    #[serde(default)]
+    pub topics: Vec<String>,
+    pub language: Option<String>,
+    pub min_stars: Option<u32>,
+    pub max_stars: Option<u32>,
+    /// Creation date lower bound, `YYYY-MM-DD`
+    pub created_after: Option<String>,
+    /// Creation date upper bound, `YYYY-MM-DD`
+    pub created_before: Option<String>,
+    /// Owner location; repository owners are matched against their profile
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackageRegistriesConfig {
+    pub enabled: bool,
+    pub max_results_per_query: usize,
+    pub crates_io: RegistryQueryConfig,
+    pub npm: RegistryQueryConfig,
+    pub pypi: RegistryQueryConfig,
+    /// Stop once this many leads are collected (none = no cap)
+    #[serde(default)]
+    pub max_leads: Option<usize>,
+}
+
+/// Keyword and owner queries run against a single package registry
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RegistryQueryConfig {
+    pub enabled: bool,
+    pub api_base: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub owners: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -46,6 +416,61 @@ pub struct BetaListConfig {
     pub base_url: String,
     pub endpoints: Vec<String>,
     pub selectors: HashMap<String, String>,
+    /// Visit each startup's BetaList page for its real website, full
+    /// description, topics and makers
+    #[serde(default = "default_fetch_details")]
+    pub fetch_details: bool,
+    /// Stop once this many leads are collected (none = no cap)
+    #[serde(default)]
+    pub max_leads: Option<usize>,
+}
+
+fn default_fetch_details() -> bool {
+    true
+}
+
+/// Code forges used for README fetching and commit-email enrichment
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForgesConfig {
+    pub github: ForgeConfig,
+    pub gitlab: ForgeConfig,
+    /// Gitea/Forgejo instances such as Codeberg
+    #[serde(default)]
+    pub gitea: Vec<ForgeConfig>,
+    /// How much commit history is sampled for contributor mining
+    #[serde(default)]
+    pub commits: CommitMiningConfig,
+    /// Read maintainers and sponsor links from `package.json`, `CODEOWNERS`,
+    /// `FUNDING.yml` and other metadata files of each repository
+    #[serde(default = "default_metadata_files")]
+    pub metadata_files: bool,
+    /// Enrich repository leads from their forge: contributors, health,
+    /// metadata files and the owner profile
+    #[serde(default = "default_enrich_repositories")]
+    pub enrich_repositories: bool,
+}
+
+fn default_metadata_files() -> bool {
+    true
+}
+
+fn default_enrich_repositories() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CommitMiningConfig {
+    pub per_page: usize,
+    pub max_pages: u32,
+    /// Ranked contributors kept on each lead
+    pub max_contributors: usize,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForgeConfig {
+    pub web_base: String,
+    pub api_base: String,
+    pub token: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -53,6 +478,12 @@ pub struct PatternsConfig {
     pub email: EmailPatterns,
     pub location: LocationPatterns,
     pub tld_mapping: HashMap<String, String>,
+    #[serde(default)]
+    pub text: TextNormalization,
+    /// Localized patterns by ISO 639-1 language code, used on top of the
+    /// defaults when a description or page is detected in that language
+    #[serde(default)]
+    pub languages: HashMap<String, LanguagePatterns>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -67,6 +498,43 @@ pub struct LocationPatterns {
     pub country_indicators: Vec<String>,
 }
 
+/// Patterns for one language
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LanguagePatterns {
+    /// Location phrases with the place in capture group 1 ("mit Sitz in (...)")
+    pub country_indicators: Vec<String>,
+    /// Words introducing a phone number ("Telefon", "電話")
+    pub phone_labels: Vec<String>,
+    /// Website paths holding contact details ("/kontakt", "/impressum")
+    pub contact_pages: Vec<String>,
+}
+
+/// How scraped text is cleaned before patterns run on it
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct TextNormalization {
+    /// Decode HTML entities (`&amp;`, `&eacute;`)
+    pub decode_entities: bool,
+    /// Drop control and zero-width characters
+    pub strip_control: bool,
+    /// Drop emoji and pictographs
+    pub strip_emoji: bool,
+    /// Collapse whitespace and line breaks into single spaces
+    pub collapse_whitespace: bool,
+}
+
+impl Default for TextNormalization {
+    fn default() -> Self {
+        Self {
+            decode_entities: true,
+            strip_control: true,
+            strip_emoji: true,
+            collapse_whitespace: true,
+        }
+    }
+}
+
 impl Config {
     pub fn load(path: &str) -> Result<Self> {
         let content = fs::read_to_string(path).map_err(|e| {
@@ -76,9 +544,25 @@ impl Config {
         let config: Config = serde_yaml::from_str(&content).map_err(|e| {
             ScrapingError::ConfigError(format!("Failed to parse config file '{}': {}", path, e))
         })?;
+        crate::segments::check_segment_names(&config.segments)?;
 
         Ok(config)
     }
+
+    /// Turn off network-bound enrichment: per-lead detail pages and forge
+    /// lookups while scraping, then website checks, team/contact pages,
+    /// homepage fingerprints and metadata, and email validation.
+    /// Canonicalization, dedup, tagging and scoring stay.
+    pub fn skip_enrichment(&mut self) {
+        self.sources.ycombinator.fetch_founders = false;
+        self.sources.betalist.fetch_details = false;
+        self.forges.enrich_repositories = false;
+        self.websites.check = false;
+        self.scraper.team_pages = false;
+        self.technologies.enabled = false;
+        self.tagging.website_metadata = false;
+        self.email_validation.enabled = false;
+    }
 }
 
 impl Default for Config {
@@ -89,6 +573,7 @@ impl Default for Config {
                 max_concurrent_requests: 10,
                 user_agent: "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36".to_string(),
                 github_token: None,
+                team_pages: false,
             },
             sources: SourcesConfig {
                 ycombinator: YCombinatorConfig {
@@ -96,31 +581,158 @@ impl Default for Config {
                     base_url: "https://www.ycombinator.com".to_string(),
                     endpoints: vec!["/companies".to_string()],
                     selectors: HashMap::new(),
+                    fetch_founders: false,
+                    max_leads: None,
                 },
                 github_awesome: GitHubAwesomeConfig {
                     enabled: true,
                     repositories: vec!["awesome-startup-tools".to_string()],
-                    api_base: "https://api.github.com/repos".to_string(),
+                    max_depth: 0,
+                    max_lists: default_max_lists(),
+                    max_leads: None,
                 },
                 betalist: BetaListConfig {
                     enabled: true,
                     base_url: "https://betalist.com".to_string(),
                     endpoints: vec!["/startups".to_string()],
                     selectors: HashMap::new(),
+                    fetch_details: default_fetch_details(),
+                    max_leads: None,
                 },
+                github_search: GitHubSearchConfig::default(),
+                package_registries: PackageRegistriesConfig::default(),
             },
-            patterns: PatternsConfig {
-                email: EmailPatterns {
-                    mailto: "mailto:([a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\\.[a-zA-Z]{2,})".to_string(),
-                    contact: "contact@|info@|hello@".to_string(),
-                    generic: "[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\\.[a-zA-Z]{2,}".to_string(),
-                },
-                location: LocationPatterns {
-                    country_indicators: vec!["based in ([A-Za-z\\s]+)".to_string()],
-                },
-                tld_mapping: HashMap::new(),
+            patterns: PatternsConfig::default(),
+            forges: ForgesConfig::default(),
+            technologies: TechnologiesConfig::default(),
+            tagging: TaggingConfig::default(),
+            email_validation: EmailValidationConfig::default(),
+            websites: WebsitesConfig::default(),
+            scoring: ScoringConfig::default(),
+            segments: Vec::new(),
+        }
+    }
+}
+
+impl Default for ForgesConfig {
+    fn default() -> Self {
+        Self {
+            github: ForgeConfig::new("https://github.com", "https://api.github.com"),
+            gitlab: ForgeConfig::new("https://gitlab.com", "https://gitlab.com/api/v4"),
+            gitea: vec![ForgeConfig::new(
+                "https://codeberg.org",
+                "https://codeberg.org/api/v1",
+            )],
+            commits: CommitMiningConfig::default(),
+            metadata_files: default_metadata_files(),
+            enrich_repositories: default_enrich_repositories(),
+        }
+    }
+}
+
+impl Default for CommitMiningConfig {
+    fn default() -> Self {
+        Self {
+            per_page: 100,
+            max_pages: 3,
+            max_contributors: 5,
+        }
+    }
+}
+
+impl ForgeConfig {
+    pub fn new(web_base: &str, api_base: &str) -> Self {
+        Self {
+            web_base: web_base.to_string(),
+            api_base: api_base.to_string(),
+            token: None,
+        }
+    }
+}
+
+impl Default for GitHubSearchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            api_base: "https://api.github.com".to_string(),
+            queries: Vec::new(),
+            per_page: 30,
+            max_pages: 1,
+            max_leads: None,
+        }
+    }
+}
+
+impl Default for PackageRegistriesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_results_per_query: 25,
+            crates_io: RegistryQueryConfig::new("https://crates.io/api/v1"),
+            npm: RegistryQueryConfig::new("https://registry.npmjs.org"),
+            pypi: RegistryQueryConfig::new("https://pypi.org"),
+            max_leads: None,
+        }
+    }
+}
+
+impl RegistryQueryConfig {
+    fn new(api_base: &str) -> Self {
+        Self {
+            enabled: true,
+            api_base: api_base.to_string(),
+            keywords: Vec::new(),
+            owners: Vec::new(),
+        }
+    }
+}
+
+impl Default for PatternsConfig {
+    fn default() -> Self {
+        Self {
+            email: EmailPatterns {
+                mailto: "mailto:([a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\\.[a-zA-Z]{2,})".to_string(),
+                contact: "contact@|info@|hello@".to_string(),
+                generic: "[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\\.[a-zA-Z]{2,}".to_string(),
+            },
+            location: LocationPatterns {
+                country_indicators: vec![
+                    "[Bb]ased in (?:the )?([\\p{Lu}][\\p{L}\\s,.'-]{1,60})".to_string()
+                ],
             },
+            tld_mapping: HashMap::new(),
+            text: TextNormalization::default(),
+            languages: default_language_patterns(),
         }
     }
 }
 
+/// German and French patterns; config/scraper.yaml ships more languages
+fn default_language_patterns() -> HashMap<String, LanguagePatterns> {
+    let place = "([\\p{Lu}][\\p{L}\\s,.'-]{1,60})";
+    let strings = |items: &[&str]| items.iter().map(|item| item.to_string()).collect();
+    HashMap::from([
+        (
+            "de".to_string(),
+            LanguagePatterns {
+                country_indicators: vec![
+                    format!("[Mm]it Sitz in {}", place),
+                    format!("[Aa]nsässig in {}", place),
+                ],
+                phone_labels: strings(&["Telefon", "Tel", "Fon"]),
+                contact_pages: strings(&["/kontakt", "/impressum"]),
+            },
+        ),
+        (
+            "fr".to_string(),
+            LanguagePatterns {
+                country_indicators: vec![
+                    format!("[Bb]asée? à {}", place),
+                    format!("[Ss]iège (?:social )?à {}", place),
+                ],
+                phone_labels: strings(&["Téléphone", "Tél", "Tel"]),
+                contact_pages: strings(&["/contact", "/mentions-legales"]),
+            },
+        ),
+    ])
+}
@@ -0,0 +1,398 @@
+use crate::errors::Result;
+use crate::models::{PhoneNumber, PostalAddress};
+use regex::Regex;
+use serde_json::Value;
+use std::sync::OnceLock;
+
+/// Country calling codes (ITU-T E.164 assignments) of the markets we see
+/// leads from, with ISO 3166-1 alpha-2 codes. `+1` is resolved separately through the NANP area code.
+const CALLING_CODES: &[(&str, &str)] = &[
+    ("7", "RU"),
+    ("20", "EG"),
+    ("27", "ZA"),
+    ("30", "GR"),
+    ("31", "NL"),
+    ("32", "BE"),
+    ("33", "FR"),
+    ("34", "ES"),
+    ("36", "HU"),
+    ("39", "IT"),
+    ("40", "RO"),
+    ("41", "CH"),
+    ("43", "AT"),
+    ("44", "GB"),
+    ("45", "DK"),
+    ("46", "SE"),
+    ("47", "NO"),
+    ("48", "PL"),
+    ("49", "DE"),
+    ("51", "PE"),
+    ("52", "MX"),
+    ("54", "AR"),
+    ("55", "BR"),
+    ("56", "CL"),
+    ("57", "CO"),
+    ("60", "MY"),
+    ("61", "AU"),
+    ("62", "ID"),
+    ("63", "PH"),
+    ("64", "NZ"),
+    ("65", "SG"),
+    ("66", "TH"),
+    ("81", "JP"),
+    ("82", "KR"),
+    ("84", "VN"),
+    ("86", "CN"),
+    ("90", "TR"),
+    ("91", "IN"),
+    ("92", "PK"),
+    ("234", "NG"),
+    ("254", "KE"),
+    ("351", "PT"),
+    ("352", "LU"),
+    ("353", "IE"),
+    ("354", "IS"),
+    ("358", "FI"),
+    ("359", "BG"),
+    ("370", "LT"),
+    ("371", "LV"),
+    ("372", "EE"),
+    ("380", "UA"),
+    ("381", "RS"),
+    ("385", "HR"),
+    ("386", "SI"),
+    ("420", "CZ"),
+    ("421", "SK"),
+    ("852", "HK"),
+    ("886", "TW"),
+    ("971", "AE"),
+    ("972", "IL"),
+];
+
+/// Canadian NANP area codes; every other `+1` number is treated as US
+const CANADIAN_AREA_CODES: &[&str] = &[
+    "204", "226", "236", "249", "250", "263", "289", "306", "343", "354", "365", "367", "368",
+    "382", "403", "416", "418", "428", "431", "437", "438", "450", "468", "474", "506", "514",
+    "519", "548", "579", "581", "584", "587", "604", "613", "639", "647", "672", "683", "705",
+    "709", "742", "753", "778", "780", "782", "807", "819", "825", "867", "873", "879", "902",
+    "905",
+];
+
+/// Countries whose national numbers keep the leading zero after the calling code
+const KEEPS_TRUNK_ZERO: &[&str] = &["39"];
+
+/// ISO country code for an E.164 number (`+` and digits)
+pub fn country_for_e164(e164: &str) -> Option<&'static str> {
+    let digits = e164.strip_prefix('+')?;
+    if let Some(national) = digits.strip_prefix('1') {
+        let area = national.get(..3)?;
+        return Some(if CANADIAN_AREA_CODES.contains(&area) {
+            "CA"
+        } else {
+            "US"
+        });
+    }
+    (1..=3).find_map(|len| {
+        let code = digits.get(..len)?;
+        CALLING_CODES
+            .iter()
+            .find(|(known, _)| *known == code)
+            .map(|(_, country)| *country)
+    })
+}
+
+/// Calling code for an ISO country code, used to read national numbers
+pub fn calling_code_for_country(country_code: &str) -> Option<&'static str> {
+    match country_code.to_uppercase().as_str() {
+        "US" | "CA" => Some("1"),
+        code => CALLING_CODES
+            .iter()
+            .find(|(_, known)| *known == code)
+            .map(|(calling_code, _)| *calling_code),
+    }
+}
+
+/// Normalize a written phone number to E.164. International numbers
+/// (`+` or `00` prefix) are read as-is; national numbers need the calling
+/// code of the country they were found in.
+pub fn normalize_phone(raw: &str, default_calling_code: Option<&str>) -> Option<PhoneNumber> {
+    let trimmed = raw.trim();
+    // "+49 (0) 30 ..." - the bracketed trunk prefix is dropped when dialing from abroad
+    let without_trunk = trimmed.replace("(0)", "");
+    if without_trunk
+        .chars()
+        .any(|c| !(c.is_ascii_digit() || " +-./()\u{a0}".contains(c)))
+    {
+        return None;
+    }
+    let digits: String = without_trunk.chars().filter(char::is_ascii_digit).collect();
+
+    let e164 = if without_trunk.trim_start().starts_with('+') {
+        format!("+{}", digits)
+    } else if let Some(international) = digits.strip_prefix("00") {
+        format!("+{}", international)
+    } else {
+        let code = default_calling_code?;
+        let national = if code == "1" {
+            digits.strip_prefix('1').unwrap_or(&digits)
+        } else if KEEPS_TRUNK_ZERO.contains(&code) {
+            &digits
+        } else {
+            digits.strip_prefix('0').unwrap_or(&digits)
+        };
+        format!("+{}{}", code, national)
+    };
+
+    // E.164 allows at most 15 digits; shorter than 8 is an extension or an ID
+    let length = e164.len() - 1;
+    if !(8..=15).contains(&length) || e164.starts_with("+0") {
+        return None;
+    }
+    let country = country_for_e164(&e164)?;
+
+    Some(PhoneNumber {
+        e164,
+        country: Some(country.to_string()),
+        raw: trimmed.to_string(),
+    })
+}
+
+/// Phone-looking runs in text: international numbers anywhere, national
+/// ones only after a phone label ("Tel", "Phone", "Telefon", ...)
+pub fn phone_candidates(text: &str) -> Vec<String> {
+    static INTERNATIONAL: OnceLock<Regex> = OnceLock::new();
+    static LABELED: OnceLock<Regex> = OnceLock::new();
+    let international = INTERNATIONAL.get_or_init(|| {
+        Regex::new(r"(?:\+|\b00)\d[\d \u{a0}().\-/]{6,20}\d").expect("valid phone regex")
+    });
+    let labeled = LABELED.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(?:tel|telephone|phone|call us|call|fon|telefon|t\xe9l\xe9phone|tél)\.?\s*:?\s*(\(?\d[\d \u{a0}().\-/]{5,20}\d)",
+        )
+        .expect("valid phone regex")
+    });
+
+    let mut candidates: Vec<String> = international
+        .find_iter(text)
+        .map(|found| found.as_str().to_string())
+        .collect();
+    for caps in labeled.captures_iter(text) {
+        let number = caps[1].to_string();
+        if !candidates.iter().any(|known| known.contains(number.trim())) {
+            candidates.push(number);
+        }
+    }
+    candidates
+}
+
+/// Pattern for a national or international number after one of `labels`
+/// ("Telefon", "電話"), captured in group 1. Latin labels must start a word;
+/// CJK labels can follow any text.
+pub fn labeled_phone_regex(labels: &[String]) -> Result<Regex> {
+    let mut labels: Vec<&String> = labels.iter().collect();
+    // Longest first so "Telefon" wins over "Tel"
+    labels.sort_by_key(|label| std::cmp::Reverse(label.chars().count()));
+    let alternatives: Vec<String> = labels.iter().map(|label| regex::escape(label)).collect();
+    Ok(Regex::new(&format!(
+        r"(?i)(?:^|[^\p{{Latin}}])(?:{})\.?\s*[:：]?\s*(\+?\(?\d[\d \u{{a0}}().\-/]{{5,20}}\d)",
+        alternatives.join("|")
+    ))?)
+}
+
+/// First `PostalAddress` in a JSON-LD document, looking through `@graph`
+/// and nested `address` properties
+pub fn postal_address_from_json_ld(value: &Value) -> Option<PostalAddress> {
+    match value {
+        Value::Array(items) => items.iter().find_map(postal_address_from_json_ld),
+        Value::Object(object) => {
+            let is_postal_address = match object.get("@type") {
+                Some(Value::String(kind)) => kind == "PostalAddress",
+                Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "PostalAddress"),
+                _ => false,
+            };
+            if is_postal_address {
+                let field = |key: &str| {
+                    match object.get(key) {
+                        Some(Value::String(text)) => Some(text.trim().to_string()),
+                        Some(Value::Number(number)) => Some(number.to_string()),
+                        // addressCountry may be a Country object
+                        Some(Value::Object(inner)) => inner
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .map(|text| text.trim().to_string()),
+                        _ => None,
+                    }
+                    .filter(|text| !text.is_empty())
+                };
+                let address = PostalAddress {
+                    street: field("streetAddress"),
+                    postal_code: field("postalCode"),
+                    locality: field("addressLocality"),
+                    region: field("addressRegion"),
+                    country: field("addressCountry"),
+                };
+                return (!address.is_empty()).then_some(address);
+            }
+            object
+                .get("address")
+                .into_iter()
+                .chain(object.get("@graph"))
+                .chain(object.get("location"))
+                .chain(object.values())
+                .find_map(postal_address_from_json_ld)
+        }
+        _ => None,
+    }
+}
+
+/// Postal address in free footer/imprint text, one block per line.
+/// Recognizes the European
+/// "Street 12, 10115 City" and North American "12 Main St, City, ST 12345"
+/// layouts.
+pub fn postal_address_from_text(text: &str) -> Option<PostalAddress> {
+    static EUROPEAN: OnceLock<Regex> = OnceLock::new();
+    static NORTH_AMERICAN: OnceLock<Regex> = OnceLock::new();
+    static UK: OnceLock<Regex> = OnceLock::new();
+
+    let north_american = NORTH_AMERICAN.get_or_init(|| {
+        Regex::new(
+            r"(?x)
+            (?P<street>\d{1,6}\ [\p{L}0-9.'\ ]{2,40}?
+                \b(?:Street|St|Avenue|Ave|Road|Rd|Boulevard|Blvd|Lane|Ln|Drive|Dr|Way|Court|Ct|Place|Pl|Square|Sq|Parkway|Pkwy)\b\.?
+                (?:,?\ (?:Suite|Ste\.?|Floor|Fl\.?|\#)\ ?[\w-]+)?)
+            ,\s*(?P<locality>[\p{L}.'\ ]{2,30}),\s*
+            (?P<region>[A-Z]{2})\s+
+            (?P<postal_code>\d{5}(?:-\d{4})?|[A-Z]\d[A-Z]\ ?\d[A-Z]\d)\b",
+        )
+        .expect("valid address regex")
+    });
+    let uk = UK.get_or_init(|| {
+        Regex::new(
+            r"(?P<street>\d{1,5}[A-Za-z]?\s[\p{L}0-9.'\- ]{2,40}?),\s*(?P<locality>[\p{L}.'\- ]{2,30}),?\s+(?P<postal_code>[A-Z]{1,2}\d[A-Z\d]?\s?\d[A-Z]{2})\b",
+        )
+        .expect("valid address regex")
+    });
+    let european = EUROPEAN.get_or_init(|| {
+        Regex::new(
+            r"(?P<street>\p{Lu}[\p{L}.'\-]*(?:[ \t][\p{L}.'\-]+){0,4}[ \t]\d{1,5}[a-zA-Z]?(?:[-/]\d{1,4})?)\s*[,·|]?\s*(?:(?:D|DE|A|AT|CH|F|FR|NL|B|BE|L|LU|I|IT|E|ES)-)?(?P<postal_code>\d{4,5}|\d{4} ?[A-Z]{2})[ \t]+(?P<locality>\p{Lu}[\p{L}.'\-]+(?:[ \t-]\p{Lu}[\p{L}.'\-]+){0,3})",
+        )
+        .expect("valid address regex")
+    });
+
+    let capture = |caps: &regex::Captures, key: &str| {
+        caps.name(key)
+            .map(|m| m.as_str().trim().trim_end_matches(',').to_string())
+            .filter(|text| !text.is_empty())
+    };
+
+    if let Some(caps) = north_american.captures(text) {
+        return Some(PostalAddress {
+            street: capture(&caps, "street"),
+            postal_code: capture(&caps, "postal_code"),
+            locality: capture(&caps, "locality"),
+            region: capture(&caps, "region"),
+            country: None,
+        });
+    }
+    if let Some(caps) = uk.captures(text) {
+        return Some(PostalAddress {
+            street: capture(&caps, "street"),
+            postal_code: capture(&caps, "postal_code"),
+            locality: capture(&caps, "locality"),
+            region: None,
+            country: Some("GB".to_string()),
+        });
+    }
+    european.captures(text).map(|caps| PostalAddress {
+        street: capture(&caps, "street"),
+        postal_code: capture(&caps, "postal_code"),
+        locality: capture(&caps, "locality"),
+        region: None,
+        country: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_phone() {
+        let e164 = |raw: &str, code: Option<&str>| normalize_phone(raw, code).map(|p| p.e164);
+
+        assert_eq!(
+            e164("+49 (0) 30 1234 5678", None).as_deref(),
+            Some("+493012345678")
+        );
+        assert_eq!(
+            e164("0044 20 7946 0958", None).as_deref(),
+            Some("+442079460958")
+        );
+        assert_eq!(
+            e164("030 / 123 456 78", Some("49")).as_deref(),
+            Some("+493012345678")
+        );
+        assert_eq!(
+            e164("06 1234 5678", Some("39")).as_deref(),
+            Some("+390612345678")
+        );
+        assert_eq!(
+            e164("(415) 555-0132", Some("1")).as_deref(),
+            Some("+14155550132")
+        );
+        // National numbers without a known country, too short, or not a number
+        assert_eq!(e164("030 1234567", None), None);
+        assert_eq!(e164("+49 30", None), None);
+        assert_eq!(e164("+49 30 1234 ext", None), None);
+
+        let phone = normalize_phone("+1 416 555 0199", None).unwrap();
+        assert_eq!(phone.country.as_deref(), Some("CA"));
+        let phone = normalize_phone("+1 (212) 555-0100", None).unwrap();
+        assert_eq!(phone.country.as_deref(), Some("US"));
+        let phone = normalize_phone("+33 1 23 45 67 89", None).unwrap();
+        assert_eq!(phone.country.as_deref(), Some("FR"));
+    }
+
+    #[test]
+    fn test_postal_address_extraction() {
+        let json_ld: Value = serde_json::from_str(
+            r#"{"@context": "https://schema.org", "@graph": [
+                {"@type": "WebSite", "name": "Acme"},
+                {"@type": "Organization", "name": "Acme", "address": {
+                    "@type": "PostalAddress", "streetAddress": "Torstraße 12",
+                    "postalCode": "10119", "addressLocality": "Berlin",
+                    "addressCountry": {"@type": "Country", "name": "DE"}}}
+            ]}"#,
+        )
+        .unwrap();
+        let address = postal_address_from_json_ld(&json_ld).unwrap();
+        assert_eq!(address.street.as_deref(), Some("Torstraße 12"));
+        assert_eq!(address.locality.as_deref(), Some("Berlin"));
+        assert_eq!(address.country.as_deref(), Some("DE"));
+
+        let address =
+            postal_address_from_text("Acme GmbH · Torstraße 12 · 10119 Berlin · Germany").unwrap();
+        assert_eq!(address.street.as_deref(), Some("Torstraße 12"));
+        assert_eq!(address.postal_code.as_deref(), Some("10119"));
+        assert_eq!(address.locality.as_deref(), Some("Berlin"));
+
+        let address = postal_address_from_text(
+            "© 2024 Acme Inc., 548 Market St, Suite 200, San Francisco, CA 94104",
+        )
+        .unwrap();
+        assert_eq!(address.street.as_deref(), Some("548 Market St, Suite 200"));
+        assert_eq!(address.locality.as_deref(), Some("San Francisco"));
+        assert_eq!(address.region.as_deref(), Some("CA"));
+        assert_eq!(address.postal_code.as_deref(), Some("94104"));
+
+        let address =
+            postal_address_from_text("Acme Ltd, 20 Fenchurch Street, London EC3M 3BY").unwrap();
+        assert_eq!(address.postal_code.as_deref(), Some("EC3M 3BY"));
+        assert_eq!(address.locality.as_deref(), Some("London"));
+
+        assert_eq!(
+            postal_address_from_text("Founded in 2019 by 3 engineers"),
+            None
+        );
+    }
+}
@@ -0,0 +1,516 @@
+pub mod smtp;
+pub mod syntax;
+
+use crate::config::EmailValidationConfig;
+use crate::errors::{Result, ScrapingError};
+use crate::models::{EmailStatus, EmailVerdict, Lead};
+use hickory_resolver::config::{
+    NameServerConfig, NameServerConfigGroup, Protocol, ResolverConfig, ResolverOpts,
+};
+use hickory_resolver::error::ResolveErrorKind;
+use hickory_resolver::proto::op::ResponseCode;
+use hickory_resolver::TokioAsyncResolver;
+use serde::Deserialize;
+use smtp::RcptOutcome;
+use std::collections::btree_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::Duration;
+use syntax::is_valid_syntax;
+use tracing::debug;
+
+/// Disposable and free-provider domains as written in `config/email_domains.yaml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DomainLists {
+    #[serde(default)]
+    disposable: HashSet<String>,
+    #[serde(default)]
+    free: HashSet<String>,
+}
+
+impl DomainLists {
+    pub fn load(path: &str) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ScrapingError::ConfigError(format!("Failed to read domain lists '{}': {}", path, e))
+        })?;
+        Self::from_yaml(&content).map_err(|e| {
+            ScrapingError::ConfigError(format!("Invalid domain lists '{}': {}", path, e))
+        })
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    pub fn is_disposable(&self, domain: &str) -> bool {
+        Self::listed(&self.disposable, domain)
+    }
+
+    pub fn is_free_provider(&self, domain: &str) -> bool {
+        Self::listed(&self.free, domain)
+    }
+
+    /// The domain or one of its parents is listed (`eu.mailinator.com`)
+    fn listed(list: &HashSet<String>, domain: &str) -> bool {
+        let mut candidate = domain;
+        loop {
+            if list.contains(candidate) {
+                return true;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => candidate = parent,
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// What DNS and earlier probes told us about a mail domain
+#[derive(Debug, Clone, Default)]
+struct DomainInfo {
+    /// Hosts accepting mail, best MX first; `Err` when DNS failed
+    mail_hosts: Option<std::result::Result<Vec<String>, String>>,
+    catch_all: Option<bool>,
+}
+
+/// Checks email deliverability: syntax, domain lists, MX/A records and an
+/// optional SMTP `RCPT TO` probe. DNS results are cached per domain.
+pub struct EmailValidator {
+    config: EmailValidationConfig,
+    lists: DomainLists,
+    resolver: TokioAsyncResolver,
+    domains: Mutex<HashMap<String, DomainInfo>>,
+}
+
+impl EmailValidator {
+    /// Validator with the configured resolver and domain list file
+    pub fn new(config: &EmailValidationConfig) -> Result<Self> {
+        let lists = DomainLists::load(&config.domain_lists)?;
+        Self::with_lists(config, lists)
+    }
+
+    pub fn with_lists(config: &EmailValidationConfig, lists: DomainLists) -> Result<Self> {
+        let mut options = ResolverOpts::default();
+        options.timeout = Duration::from_secs(config.dns.timeout_seconds);
+        options.attempts = 1;
+
+        let resolver = if config.dns.nameservers.is_empty() {
+            TokioAsyncResolver::tokio_from_system_conf().map_err(|e| {
+                ScrapingError::ConfigError(format!("Failed to load system DNS config: {}", e))
+            })?
+        } else {
+            let mut group = NameServerConfigGroup::new();
+            for nameserver in &config.dns.nameservers {
+                let address: SocketAddr = nameserver.parse().map_err(|e| {
+                    ScrapingError::ConfigError(format!(
+                        "Invalid nameserver '{}' (expected ip:port): {}",
+                        nameserver, e
+                    ))
+                })?;
+                group.push(NameServerConfig::new(address, Protocol::Udp));
+                group.push(NameServerConfig::new(address, Protocol::Tcp));
+            }
+            TokioAsyncResolver::tokio(ResolverConfig::from_parts(None, vec![], group), options)
+        };
+
+        Ok(Self {
+            config: config.clone(),
+            lists,
+            resolver,
+            domains: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Verdict for every email on the lead that doesn't have one yet
+    pub async fn validate_lead(&self, lead: &mut Lead) {
+        let emails: Vec<String> = lead
+            .email
+            .iter()
+            .chain(&lead.github_email)
+            .chain(
+                lead.people
+                    .iter()
+                    .filter_map(|person| person.email.as_ref()),
+            )
+            .chain(
+                lead.maintainers
+                    .iter()
+                    .filter_map(|maintainer| maintainer.email.as_ref()),
+            )
+            .map(|email| email.trim().to_lowercase())
+            .collect();
+
+        for email in emails {
+            if let Entry::Vacant(entry) = lead.email_verdicts.entry(email) {
+                let verdict = self.validate(entry.key()).await;
+                entry.insert(verdict);
+            }
+        }
+    }
+
+    pub async fn validate(&self, email: &str) -> EmailVerdict {
+        let email = email.trim().to_lowercase();
+        let mut verdict = EmailVerdict {
+            email: email.clone(),
+            status: EmailStatus::Unknown,
+            syntax_valid: is_valid_syntax(&email),
+            has_mail_server: None,
+            disposable: false,
+            free_provider: false,
+            smtp_accepted: None,
+            catch_all: None,
+            reason: None,
+        };
+        let undeliverable = |mut verdict: EmailVerdict, reason: &str| {
+            verdict.status = EmailStatus::Undeliverable;
+            verdict.reason = Some(reason.to_string());
+            verdict
+        };
+
+        let domain = match email.rsplit_once('@') {
+            Some((_, domain)) if verdict.syntax_valid => domain.to_string(),
+            _ => return undeliverable(verdict, "invalid syntax"),
+        };
+        verdict.disposable = self.lists.is_disposable(&domain);
+        verdict.free_provider = self.lists.is_free_provider(&domain);
+        if verdict.disposable {
+            return undeliverable(verdict, "disposable domain");
+        }
+
+        let hosts = match self.mail_hosts(&domain).await {
+            Ok(hosts) => hosts,
+            Err(e) => {
+                verdict.reason = Some(format!("DNS lookup failed: {}", e));
+                return verdict;
+            }
+        };
+        verdict.has_mail_server = Some(!hosts.is_empty());
+        if hosts.is_empty() {
+            return undeliverable(verdict, "no MX or A record");
+        }
+
+        if !self.config.smtp.enabled {
+            verdict.reason = Some("mailbox not probed".to_string());
+            return verdict;
+        }
+
+        match self.probe(&email, &domain, &hosts).await {
+            Ok(result) => {
+                verdict.catch_all = result.catch_all.or(self.known_catch_all(&domain));
+                if result.catch_all.is_some() {
+                    self.domain_info(&domain, |info| info.catch_all = result.catch_all);
+                }
+                match result.mailbox {
+                    RcptOutcome::Accepted if verdict.catch_all == Some(true) => {
+                        verdict.smtp_accepted = Some(true);
+                        verdict.status = EmailStatus::Risky;
+                        verdict.reason = Some("catch-all domain".to_string());
+                    }
+                    RcptOutcome::Accepted => {
+                        verdict.smtp_accepted = Some(true);
+                        verdict.status = EmailStatus::Deliverable;
+                    }
+                    RcptOutcome::Rejected(reply) => {
+                        verdict.smtp_accepted = Some(false);
+                        verdict = undeliverable(verdict, &format!("mailbox rejected: {}", reply));
+                    }
+                    RcptOutcome::Inconclusive(reply) => {
+                        verdict.reason = Some(format!("SMTP probe inconclusive: {}", reply));
+                    }
+                }
+            }
+            Err(e) => verdict.reason = Some(e),
+        }
+        verdict
+    }
+
+    /// Probe the configured SMTP host, or the domain's mail hosts in order
+    /// until one answers
+    async fn probe(
+        &self,
+        email: &str,
+        domain: &str,
+        hosts: &[String],
+    ) -> std::result::Result<smtp::ProbeResult, String> {
+        let smtp = &self.config.smtp;
+        let mut config = smtp.clone();
+        // No need to look for a catch-all again once we know
+        if self.known_catch_all(domain).is_some() {
+            config.detect_catch_all = false;
+        }
+
+        let hosts: Vec<&str> = match smtp.host {
+            Some(ref host) => vec![host.as_str()],
+            None => hosts.iter().map(String::as_str).collect(),
+        };
+        let mut last_error = "no mail host to probe".to_string();
+        for host in hosts {
+            match smtp::probe(host, smtp.port, &config, email).await {
+                Ok(result) => return Ok(result),
+                Err(e) => {
+                    debug!("SMTP probe of {} via {} failed: {}", email, host, e);
+                    last_error = e;
+                }
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Mail hosts of a domain: MX exchanges by preference, else the domain
+    /// itself when it has an address record (RFC 5321 implicit MX)
+    async fn mail_hosts(&self, domain: &str) -> std::result::Result<Vec<String>, String> {
+        if let Some(hosts) = self.domain_info(domain, |info| info.mail_hosts.clone()) {
+            return hosts;
+        }
+
+        let fqdn = format!("{}.", domain);
+        let hosts = match self.resolver.mx_lookup(fqdn.as_str()).await {
+            Ok(lookup) => {
+                let mut records: Vec<_> = lookup.iter().collect();
+                records.sort_by_key(|mx| mx.preference());
+                // A single "." exchange is a null MX: the domain takes no mail
+                Ok(records
+                    .into_iter()
+                    .map(|mx| mx.exchange().to_ascii().trim_end_matches('.').to_string())
+                    .filter(|host| !host.is_empty())
+                    .collect())
+            }
+            Err(e) => match e.kind() {
+                ResolveErrorKind::NoRecordsFound { response_code, .. }
+                    if *response_code == ResponseCode::NXDomain =>
+                {
+                    Ok(Vec::new())
+                }
+                ResolveErrorKind::NoRecordsFound { .. } => {
+                    match self.resolver.lookup_ip(fqdn.as_str()).await {
+                        Ok(ips) if ips.iter().next().is_some() => Ok(vec![domain.to_string()]),
+                        Ok(_) => Ok(Vec::new()),
+                        Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => {
+                            Ok(Vec::new())
+                        }
+                        Err(e) => Err(e.to_string()),
+                    }
+                }
+                _ => Err(e.to_string()),
+            },
+        };
+
+        self.domain_info(domain, |info| info.mail_hosts = Some(hosts.clone()));
+        hosts
+    }
+
+    fn known_catch_all(&self, domain: &str) -> Option<bool> {
+        self.domain_info(domain, |info| info.catch_all)
+    }
+
+    fn domain_info<T>(&self, domain: &str, f: impl FnOnce(&mut DomainInfo) -> T) -> T {
+        let mut domains = self.domains.lock().unwrap_or_else(|e| e.into_inner());
+        f(domains.entry(domain.to_string()).or_default())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_servers {
+    use hickory_resolver::proto::op::{Message, MessageType, ResponseCode};
+    use hickory_resolver::proto::rr::rdata::{A, MX};
+    use hickory_resolver::proto::rr::{Name, RData, Record, RecordType};
+    use std::net::Ipv4Addr;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{TcpListener, UdpSocket};
+
+    pub enum DnsRecord {
+        Mx(u16, &'static str),
+        A(Ipv4Addr),
+    }
+
+    /// Start a local DNS stand-in answering UDP queries from `(name, record)`
+    /// pairs; unknown names get NXDOMAIN. Returns its `ip:port`.
+    pub async fn dns(zone: Vec<(&'static str, DnsRecord)>) -> String {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.expect("bind test DNS");
+        let addr = socket.local_addr().expect("test DNS address");
+
+        tokio::spawn(async move {
+            let mut buffer = vec![0u8; 4096];
+            while let Ok((read, peer)) = socket.recv_from(&mut buffer).await {
+                let Ok(request) = Message::from_vec(&buffer[..read]) else {
+                    continue;
+                };
+                let Some(query) = request.queries().first() else {
+                    continue;
+                };
+                let name = query.name().to_ascii().trim_end_matches('.').to_lowercase();
+
+                let mut response = Message::new();
+                response
+                    .set_id(request.id())
+                    .set_message_type(MessageType::Response)
+                    .set_op_code(request.op_code())
+                    .set_recursion_desired(true)
+                    .set_recursion_available(true)
+                    .set_authoritative(true)
+                    .add_queries(request.queries().to_vec());
+
+                let records: Vec<&DnsRecord> = zone
+                    .iter()
+                    .filter(|(owner, _)| *owner == name)
+                    .map(|(_, record)| record)
+                    .collect();
+                if records.is_empty() {
+                    response.set_response_code(ResponseCode::NXDomain);
+                }
+                for record in records {
+                    let rdata = match (record, query.query_type()) {
+                        (DnsRecord::Mx(preference, exchange), RecordType::MX) => RData::MX(
+                            MX::new(*preference, Name::from_ascii(exchange).expect("MX name")),
+                        ),
+                        (DnsRecord::A(ip), RecordType::A) => RData::A(A(*ip)),
+                        _ => continue,
+                    };
+                    response.add_answer(Record::from_rdata(query.name().clone(), 60, rdata));
+                }
+
+                if let Ok(bytes) = response.to_vec() {
+                    let _ = socket.send_to(&bytes, peer).await;
+                }
+            }
+        });
+
+        addr.to_string()
+    }
+
+    /// Start a local SMTP stand-in accepting `RCPT TO` for the given
+    /// mailboxes (or for any mailbox when `catch_all`); returns its port.
+    pub async fn smtp(mailboxes: Vec<&'static str>, catch_all: bool) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test SMTP");
+        let port = listener.local_addr().expect("test SMTP address").port();
+
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let mailboxes = mailboxes.clone();
+                tokio::spawn(async move {
+                    let mut socket = BufReader::new(socket);
+                    let _ = socket.get_mut().write_all(b"220 mx.test ESMTP\r\n").await;
+                    let mut line = String::new();
+                    while socket.read_line(&mut line).await.unwrap_or(0) > 0 {
+                        let command = line.trim().to_string();
+                        line.clear();
+                        let reply = if command.starts_with("EHLO") {
+                            "250-mx.test\r\n250 SIZE 10240000\r\n".to_string()
+                        } else if let Some(rcpt) = command.strip_prefix("RCPT TO:") {
+                            let mailbox = rcpt.trim_matches(['<', '>']).to_lowercase();
+                            if catch_all || mailboxes.contains(&mailbox.as_str()) {
+                                "250 2.1.5 OK\r\n".to_string()
+                            } else {
+                                "550 5.1.1 No such user\r\n".to_string()
+                            }
+                        } else if command == "QUIT" {
+                            let _ = socket.get_mut().write_all(b"221 Bye\r\n").await;
+                            break;
+                        } else {
+                            "250 OK\r\n".to_string()
+                        };
+                        let _ = socket.get_mut().write_all(reply.as_bytes()).await;
+                    }
+                });
+            }
+        });
+
+        port
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_servers::{self, DnsRecord};
+    use super::*;
+    use crate::config::{DnsConfig, SmtpProbeConfig};
+    use std::net::Ipv4Addr;
+
+    #[tokio::test]
+    async fn test_validate_against_local_dns_and_smtp() {
+        let nameserver = test_servers::dns(vec![
+            ("acme.com", DnsRecord::Mx(10, "mx.acme.com.")),
+            ("catchall.io", DnsRecord::Mx(10, "mx.catchall.io.")),
+            ("a-only.dev", DnsRecord::A(Ipv4Addr::new(127, 0, 0, 1))),
+            ("gmail.com", DnsRecord::Mx(5, "gmail-smtp-in.l.google.com.")),
+        ])
+        .await;
+        let smtp_port = test_servers::smtp(vec!["jane@acme.com", "team@a-only.dev"], false).await;
+
+        let config = EmailValidationConfig {
+            enabled: true,
+            dns: DnsConfig {
+                nameservers: vec![nameserver.clone()],
+                timeout_seconds: 2,
+            },
+            smtp: SmtpProbeConfig {
+                enabled: true,
+                host: Some("127.0.0.1".to_string()),
+                port: smtp_port,
+                ..SmtpProbeConfig::default()
+            },
+            ..EmailValidationConfig::default()
+        };
+        let lists = DomainLists::from_yaml(include_str!("../../config/email_domains.yaml"))
+            .expect("shipped domain lists are valid");
+        let validator = EmailValidator::with_lists(&config, lists.clone()).unwrap();
+
+        let verdict = validator.validate("Jane@Acme.com").await;
+        assert_eq!(verdict.email, "jane@acme.com");
+        assert_eq!(verdict.status, EmailStatus::Deliverable);
+        assert_eq!(verdict.has_mail_server, Some(true));
+        assert_eq!(verdict.catch_all, Some(false));
+
+        let verdict = validator.validate("nobody@acme.com").await;
+        assert_eq!(verdict.status, EmailStatus::Undeliverable);
+        assert_eq!(verdict.smtp_accepted, Some(false));
+
+        // Implicit MX from the A record
+        let verdict = validator.validate("team@a-only.dev").await;
+        assert_eq!(verdict.status, EmailStatus::Deliverable);
+
+        let verdict = validator.validate("jane@no-such-domain.example").await;
+        assert_eq!(verdict.status, EmailStatus::Undeliverable);
+        assert_eq!(verdict.has_mail_server, Some(false));
+
+        let verdict = validator.validate("throwaway@mailinator.com").await;
+        assert!(verdict.disposable);
+        assert_eq!(verdict.status, EmailStatus::Undeliverable);
+
+        let verdict = validator.validate("jane..doe@acme.com").await;
+        assert!(!verdict.syntax_valid);
+        assert_eq!(verdict.reason.as_deref(), Some("invalid syntax"));
+
+        // Catch-all server: accepted but not confirmed
+        let catch_all_port = test_servers::smtp(vec![], true).await;
+        let config = EmailValidationConfig {
+            smtp: SmtpProbeConfig {
+                port: catch_all_port,
+                ..config.smtp.clone()
+            },
+            ..config.clone()
+        };
+        let validator = EmailValidator::with_lists(&config, lists.clone()).unwrap();
+        let verdict = validator.validate("sales@catchall.io").await;
+        assert_eq!(verdict.status, EmailStatus::Risky);
+        assert_eq!(verdict.catch_all, Some(true));
+
+        // Without probing, free providers resolve but stay unconfirmed
+        let config = EmailValidationConfig {
+            smtp: SmtpProbeConfig::default(),
+            ..config
+        };
+        let validator = EmailValidator::with_lists(&config, lists).unwrap();
+        let mut lead = Lead::new("Acme".to_string(), crate::models::Source::BetaList)
+            .with_email(Some("founder@gmail.com".to_string()));
+        validator.validate_lead(&mut lead).await;
+        let verdict = &lead.email_verdicts["founder@gmail.com"];
+        assert!(verdict.free_provider);
+        assert_eq!(verdict.status, EmailStatus::Unknown);
+        assert_eq!(verdict.reason.as_deref(), Some("mailbox not probed"));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::config::SmtpProbeConfig;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+/// How a mail server answered `RCPT TO` for a mailbox
+#[derive(Debug, Clone, PartialEq)]
+pub enum RcptOutcome {
+    Accepted,
+    /// Permanent (5xx) rejection, with the server's reply
+    Rejected(String),
+    /// Temporary (4xx) failure such as greylisting, or an unexpected reply
+    Inconclusive(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbeResult {
+    pub mailbox: RcptOutcome,
+    /// Whether a made-up mailbox on the same domain was accepted too
+    pub catch_all: Option<bool>,
+}
+
+/// Ask `host:port` whether it accepts mail for `email`, without sending
+/// any: the session ends after `RCPT TO`.
+pub async fn probe(
+    host: &str,
+    port: u16,
+    config: &SmtpProbeConfig,
+    email: &str,
+) -> std::result::Result<ProbeResult, String> {
+    let deadline = Duration::from_secs(config.timeout_seconds);
+    timeout(deadline, session(host, port, config, email))
+        .await
+        .map_err(|_| format!("SMTP probe of {}:{} timed out", host, port))?
+}
+
+async fn session(
+    host: &str,
+    port: u16,
+    config: &SmtpProbeConfig,
+    email: &str,
+) -> std::result::Result<ProbeResult, String> {
+    let stream = TcpStream::connect((host, port))
+        .await
+        .map_err(|e| format!("Failed to connect to {}:{}: {}", host, port, e))?;
+    let mut connection = Connection {
+        stream: BufReader::new(stream),
+    };
+
+    expect_ok(connection.reply().await?, "greeting")?;
+    let (code, _) = connection.command(&format!("EHLO {}", config.helo)).await?;
+    if !(200..300).contains(&code) {
+        expect_ok(
+            connection.command(&format!("HELO {}", config.helo)).await?,
+            "HELO",
+        )?;
+    }
+    expect_ok(
+        connection
+            .command(&format!("MAIL FROM:<{}>", config.mail_from))
+            .await?,
+        "MAIL FROM",
+    )?;
+
+    let mailbox = connection.rcpt(email).await?;
+
+    // A server accepting a mailbox nobody would own accepts everything
+    let catch_all = match (&mailbox, email.rsplit_once('@')) {
+        (RcptOutcome::Accepted, Some((_, domain))) if config.detect_catch_all => {
+            match connection.rcpt(&random_mailbox(domain)).await? {
+                RcptOutcome::Accepted => Some(true),
+                RcptOutcome::Rejected(_) => Some(false),
+                RcptOutcome::Inconclusive(_) => None,
+            }
+        }
+        _ => None,
+    };
+
+    let _ = connection.command("QUIT").await;
+    Ok(ProbeResult { mailbox, catch_all })
+}
+
+struct Connection {
+    stream: BufReader<TcpStream>,
+}
+
+impl Connection {
+    async fn command(&mut self, line: &str) -> std::result::Result<(u16, String), String> {
+        self.stream
+            .get_mut()
+            .write_all(format!("{}\r\n", line).as_bytes())
+            .await
+            .map_err(|e| format!("SMTP write failed: {}", e))?;
+        self.reply().await
+    }
+
+    async fn rcpt(&mut self, email: &str) -> std::result::Result<RcptOutcome, String> {
+        let (code, text) = self.command(&format!("RCPT TO:<{}>", email)).await?;
+        Ok(match code {
+            200..=299 => RcptOutcome::Accepted,
+            500..=599 => RcptOutcome::Rejected(format!("{} {}", code, text)),
+            _ => RcptOutcome::Inconclusive(format!("{} {}", code, text)),
+        })
+    }
+
+    /// One reply, joining the lines of multi-line (`250-...`) replies
+    async fn reply(&mut self) -> std::result::Result<(u16, String), String> {
+        let mut text = Vec::new();
+        loop {
+            let mut line = String::new();
+            let read = self
+                .stream
+                .read_line(&mut line)
+                .await
+                .map_err(|e| format!("SMTP read failed: {}", e))?;
+            if read == 0 {
+                return Err("SMTP server closed the connection".to_string());
+            }
+            let line = line.trim_end();
+            let code = line
+                .get(..3)
+                .and_then(|code| code.parse::<u16>().ok())
+                .ok_or_else(|| format!("Malformed SMTP reply: {}", line))?;
+            text.push(line.get(4..).unwrap_or("").to_string());
+            if line.as_bytes().get(3) != Some(&b'-') {
+                return Ok((code, text.join(" ")));
+            }
+        }
+    }
+}
+
+fn expect_ok(reply: (u16, String), step: &str) -> std::result::Result<(), String> {
+    match reply {
+        (200..=399, _) => Ok(()),
+        (code, text) => Err(format!("SMTP {} refused: {} {}", step, code, text)),
+    }
+}
+
+fn random_mailbox(domain: &str) -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    format!("no-such-mailbox-{:x}@{}", nanos, domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::email::test_servers;
+
+    #[tokio::test]
+    async fn test_probe_against_local_server() {
+        let port = test_servers::smtp(vec!["jane@acme.com"], false).await;
+        let config = SmtpProbeConfig::default();
+
+        let result = probe("127.0.0.1", port, &config, "jane@acme.com")
+            .await
+            .unwrap();
+        assert_eq!(result.mailbox, RcptOutcome::Accepted);
+        assert_eq!(result.catch_all, Some(false));
+
+        let result = probe("127.0.0.1", port, &config, "nobody@acme.com")
+            .await
+            .unwrap();
+        assert!(
+            matches!(result.mailbox, RcptOutcome::Rejected(ref reply) if reply.starts_with("550"))
+        );
+        assert_eq!(result.catch_all, None);
+
+        let port = test_servers::smtp(vec![], true).await;
+        let result = probe("127.0.0.1", port, &config, "anyone@acme.com")
+            .await
+            .unwrap();
+        assert_eq!(result.catch_all, Some(true));
+
+        // Nothing listening
+        assert!(probe("127.0.0.1", 1, &config, "jane@acme.com")
+            .await
+            .is_err());
+    }
+}
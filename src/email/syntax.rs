@@ -0,0 +1,80 @@
+/// RFC 5321 limits
+const MAX_LOCAL_PART: usize = 64;
+const MAX_DOMAIN: usize = 253;
+const MAX_LABEL: usize = 63;
+
+/// Whether an address is a deliverable `dot-atom@domain` (RFC 5322 without
+/// quoted local parts or IP literals, RFC 6531 internationalized text allowed)
+pub fn is_valid_syntax(email: &str) -> bool {
+    let Some((local, domain)) = email.rsplit_once('@') else {
+        return false;
+    };
+    is_valid_local_part(local) && is_valid_domain(domain)
+}
+
+fn is_valid_local_part(local: &str) -> bool {
+    !local.is_empty()
+        && local.len() <= MAX_LOCAL_PART
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && !local.contains("..")
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "!#$%&'*+-/=?^_`{|}~.".contains(c))
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    if domain.len() > MAX_DOMAIN || labels.len() < 2 {
+        return false;
+    }
+    let labels_valid = labels.iter().all(|label| {
+        !label.is_empty()
+            && label.len() <= MAX_LABEL
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+    });
+    // Top-level domains are alphabetic (or punycode)
+    let tld = labels[labels.len() - 1];
+    labels_valid
+        && tld.chars().count() >= 2
+        && (tld.chars().all(char::is_alphabetic) || tld.starts_with("xn--"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_email_syntax() {
+        for valid in [
+            "jane@acme.com",
+            "jane.doe+leads@mail.acme.co.uk",
+            "o'brien@acme.io",
+            "info@münchen.de",
+            "用户@例子.广告",
+            "contact@xn--mnchen-3ya.de",
+        ] {
+            assert!(is_valid_syntax(valid), "{}", valid);
+        }
+        for invalid in [
+            "",
+            "jane",
+            "@acme.com",
+            "jane@",
+            "jane@localhost",
+            "jane..doe@acme.com",
+            ".jane@acme.com",
+            "jane doe@acme.com",
+            "jane@acme..com",
+            "jane@-acme.com",
+            "jane@acme.c0m",
+            "jane@acme.c",
+            "\"jane doe\"@acme.com",
+        ] {
+            assert!(!is_valid_syntax(invalid), "{}", invalid);
+        }
+        assert!(!is_valid_syntax(&format!("{}@acme.com", "a".repeat(65))));
+    }
+}
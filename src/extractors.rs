@@ -1,63 +1,51 @@
-use crate::config::PatternsConfig;
+use crate::config::{CommitMiningConfig, ForgesConfig, PatternsConfig, TextNormalization};
+use crate::contact::{
+    calling_code_for_country, labeled_phone_regex, normalize_phone, phone_candidates,
+    postal_address_from_json_ld, postal_address_from_text,
+};
 use crate::errors::{Result, ScrapingError};
+use crate::forges::{
+    parse_metadata_file, Forge, ForgeCommit, ForgeRegistry, Mailmap, OwnerProfile, RepoInfo,
+    RepoMetadata, RepoRef, METADATA_FILES,
+};
+use crate::language::page_language;
+use crate::location::{Gazetteer, Place};
+use crate::models::{
+    Contributor, EvidenceKind, Lead, Location, LocationEvidence, Person, PhoneNumber,
+    PostalAddress, SocialNetwork, WebsiteMeta,
+};
+use crate::social::{brand_affinity, classify_social_url, has_brand_hints, SocialLink};
+use crate::text::normalize_text;
 use regex::Regex;
 use reqwest::Client;
-use serde::Deserialize;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
 use tracing::{debug, warn};
 use url::Url;
 
 type ExtractorResult<T> = std::result::Result<T, ScrapingError>;
 
-// GitHub API structures
-#[derive(Debug, Deserialize)]
-struct GitHubRepo {
-    name: String,
-    full_name: String,
-    fork: bool,
-    #[serde(rename = "default_branch")]
-    default_branch: String,
-    owner: GitHubOwner,
-}
-
-#[derive(Debug, Deserialize)]
-struct GitHubOwner {
-    login: String,
-    #[serde(rename = "type")]
-    owner_type: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct GitHubCommit {
-    commit: CommitDetails,
-    author: Option<GitHubUser>,
-}
-
-#[derive(Debug, Deserialize)]
-struct CommitDetails {
-    author: CommitAuthor,
-    committer: CommitAuthor,
-}
-
-#[derive(Debug, Deserialize)]
-struct CommitAuthor {
-    name: Option<String>,
-    email: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct GitHubUser {
-    login: String,
-    email: Option<String>,
-}
-
 #[derive(Clone)]
 pub struct DataExtractor {
     email_patterns: EmailPatterns,
     location_patterns: LocationPatterns,
-    tld_mapping: HashMap<String, String>,
-    client: Client,
-    github_token: Option<String>,
+    /// TLD label (`de`) to ISO country code
+    tld_mapping: BTreeMap<String, String>,
+    text: TextNormalization,
+    /// Localized patterns by ISO 639-1 language code
+    languages: HashMap<String, LocalizedPatterns>,
+    forges: ForgeRegistry,
+    commit_mining: CommitMiningConfig,
+    metadata_files: bool,
+    enrich_repositories: bool,
+}
+
+/// A lead's repository with its `repo_info`, fetched once and shared by
+/// every repository pass
+struct RepositoryContext {
+    forge: Arc<dyn Forge>,
+    repo: RepoRef,
+    info: RepoInfo,
 }
 
 // Also need to make the inner structs cloneable
@@ -72,6 +60,14 @@ struct LocationPatterns {
     country_indicators: Vec<Regex>,
 }
 
+#[derive(Clone)]
+struct LocalizedPatterns {
+    country_indicators: Vec<Regex>,
+    /// Phone number after one of the language's labels, in group 1
+    phone_label: Option<Regex>,
+    contact_pages: Vec<String>,
+}
+
 impl DataExtractor {
     pub fn new(
         patterns: &PatternsConfig,
@@ -90,15 +86,118 @@ impl DataExtractor {
 
         let location_patterns = LocationPatterns { country_indicators };
 
+        let mut languages = HashMap::new();
+        for (language, localized) in &patterns.languages {
+            let country_indicators = localized
+                .country_indicators
+                .iter()
+                .map(|pattern| Regex::new(pattern))
+                .collect::<std::result::Result<_, _>>()?;
+            let phone_label = if localized.phone_labels.is_empty() {
+                None
+            } else {
+                Some(labeled_phone_regex(&localized.phone_labels)?)
+            };
+            languages.insert(
+                language.to_lowercase(),
+                LocalizedPatterns {
+                    country_indicators,
+                    phone_label,
+                    contact_pages: localized.contact_pages.clone(),
+                },
+            );
+        }
+
+        // Bundled ccTLDs, overridden by configured ones (".de": "Germany")
+        let gazetteer = Gazetteer::bundled();
+        let mut tld_mapping: BTreeMap<String, String> = gazetteer
+            .countries
+            .iter()
+            .filter_map(|country| Some((country.tld.clone()?, country.code.clone())))
+            .collect();
+        for (tld, country) in &patterns.tld_mapping {
+            let label = tld.rsplit('.').next().unwrap_or(tld).to_lowercase();
+            match gazetteer.country(country) {
+                Some(country) => {
+                    tld_mapping.insert(label, country.code.clone());
+                }
+                None => warn!("⚠️  Unknown country '{}' for TLD '{}'", country, tld),
+            }
+        }
+
         Ok(Self {
             email_patterns,
             location_patterns,
-            tld_mapping: patterns.tld_mapping.clone(),
-            client,
-            github_token,
+            tld_mapping,
+            text: patterns.text.clone(),
+            languages,
+            forges: ForgeRegistry::from_config(client, &ForgesConfig::default(), github_token),
+            commit_mining: CommitMiningConfig::default(),
+            metadata_files: true,
+            enrich_repositories: true,
         })
     }
 
+    /// Replace the default forges (github.com, gitlab.com, codeberg.org) with configured ones
+    pub fn with_forges(mut self, forges: ForgeRegistry) -> Self {
+        self.forges = forges;
+        self
+    }
+
+    /// Configure how much commit history is sampled per repository
+    pub fn with_commit_mining(mut self, commit_mining: CommitMiningConfig) -> Self {
+        self.commit_mining = commit_mining;
+        self
+    }
+
+    /// Read repository metadata files in `enrich_repository_lead`
+    pub fn with_metadata_files(mut self, enabled: bool) -> Self {
+        self.metadata_files = enabled;
+        self
+    }
+
+    /// Look up repository leads on their forge at all
+    pub fn with_repository_enrichment(mut self, enabled: bool) -> Self {
+        self.enrich_repositories = enabled;
+        self
+    }
+
+    pub fn forges(&self) -> &ForgeRegistry {
+        &self.forges
+    }
+
+    /// Forge API calls of `enrich_repository_activity`, at most
+    pub fn repository_activity_requests(&self) -> usize {
+        if !self.enrich_repositories {
+            return 0;
+        }
+        // Repository info, .mailmap and commit pages, then contributor count and releases
+        2 + self.commit_mining.max_pages as usize + 2
+    }
+
+    /// Forge API calls of `enrich_repository_lead`, at most
+    pub fn repository_requests(&self) -> usize {
+        if !self.enrich_repositories {
+            return 0;
+        }
+        let metadata = if self.metadata_files {
+            // Directory listings, then only the files that exist
+            1 + Self::metadata_directories().len() + METADATA_FILES.len()
+        } else {
+            0
+        };
+        // Plus the owner profile
+        self.repository_activity_requests() + metadata + 1
+    }
+
+    /// Subdirectories holding metadata files (`.github`)
+    fn metadata_directories() -> BTreeSet<&'static str> {
+        METADATA_FILES
+            .iter()
+            .filter_map(|path| path.rsplit_once('/').map(|(dir, _)| dir))
+            .collect()
+    }
+
     pub fn extract_email(&self, text: &str, html: &str) -> Option<String> {
         // Try mailto links first (highest priority)
         if let Some(caps) = self.email_patterns.mailto.captures(html) {
@@ -119,41 +218,93 @@ impl DataExtractor {
         None
     }
 
+    /// Normalize scraped text, keeping accents and non-Latin scripts
     pub fn clean_text(&self, text: &str) -> String {
-        text.lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>()
-            .join(" ")
-            .chars()
-            .filter(|c| c.is_ascii() || c.is_whitespace())
-            .collect()
+        normalize_text(text, &self.text)
     }
 
-    pub fn extract_country(&self, text: &str, website: Option<&str>) -> Option<String> {
-        // Try location indicators in text first
-        for pattern in &self.location_patterns.country_indicators {
-            if let Some(caps) = pattern.captures(text) {
-                if let Some(country) = caps.get(1) {
-                    return Some(country.as_str().trim().to_string());
-                }
-            }
+    /// Location from "based in ..." mentions (plus the localized phrases of
+    /// `language`) and the website's ccTLD, each kept as evidence
+    pub fn extract_location(
+        &self,
+        text: &str,
+        website: Option<&str>,
+        language: Option<&str>,
+    ) -> Option<Location> {
+        let mut location = Location::default();
+        for evidence in self.location_mentions(text, language) {
+            location.add_evidence(evidence);
         }
 
-        // Fallback to TLD mapping
-        if let Some(website_url) = website {
-            if let Ok(url) = Url::parse(website_url) {
-                if let Some(host) = url.host_str() {
-                    for (tld, country) in &self.tld_mapping {
-                        if host.ends_with(tld) {
-                            return Some(country.clone());
-                        }
-                    }
+        if let Some(evidence) = website.and_then(|website| self.cctld_evidence(website)) {
+            location.add_evidence(evidence);
+        }
+
+        (!location.evidence.is_empty()).then_some(location)
+    }
+
+    /// Places named by the location phrases; captures that don't name a
+    /// known place are dropped
+    fn location_mentions(&self, text: &str, language: Option<&str>) -> Vec<LocationEvidence> {
+        let gazetteer = Gazetteer::bundled();
+        let localized = language
+            .and_then(|language| self.languages.get(language))
+            .map(|localized| localized.country_indicators.as_slice())
+            .unwrap_or_default();
+
+        let mut evidence = Vec::new();
+        for pattern in self
+            .location_patterns
+            .country_indicators
+            .iter()
+            .chain(localized)
+        {
+            for caps in pattern.captures_iter(text) {
+                let (Some(mention), Some(place)) = (caps.get(0), caps.get(1)) else {
+                    continue;
+                };
+                if let Some(place) = gazetteer.resolve_place(place.as_str()) {
+                    let value = mention.as_str().trim();
+                    evidence.push(gazetteer.evidence(EvidenceKind::TextMention, value, place));
                 }
             }
         }
+        evidence
+    }
 
-        None
+    /// Website paths with contact details for a language ("/impressum")
+    pub fn contact_pages(&self, language: Option<&str>) -> &[String] {
+        language
+            .and_then(|language| self.languages.get(language))
+            .map(|localized| localized.contact_pages.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Most contact pages any configured language adds
+    pub fn max_contact_pages(&self) -> usize {
+        self.languages
+            .values()
+            .map(|localized| localized.contact_pages.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Country of the website's top-level domain (`acme.co.uk` -> GB)
+    fn cctld_evidence(&self, website: &str) -> Option<LocationEvidence> {
+        let url = Url::parse(website).ok()?;
+        let host = url.host_str()?;
+        let tld = host.rsplit('.').next()?.to_lowercase();
+        let country_code = self.tld_mapping.get(&tld)?;
+        let gazetteer = Gazetteer::bundled();
+        Some(gazetteer.evidence(
+            EvidenceKind::CcTld,
+            &format!(".{}", tld),
+            Place {
+                country_code: country_code.clone(),
+                region: None,
+                city: None,
+            },
+        ))
     }
 
     pub fn extract_website(&self, html: &str, _base_url: Option<&str>) -> Option<String> {
@@ -184,217 +335,717 @@ impl DataExtractor {
         None
     }
 
-    fn is_valid_website_url(&self, url: &str) -> bool {
-        if let Ok(parsed) = Url::parse(url) {
-            let scheme = parsed.scheme();
-            return scheme == "http" || scheme == "https";
+    /// Phone numbers from `tel:` links and page text, normalized to E.164.
+    /// National numbers are read with the calling code of `country`, and
+    /// the phone labels of `language` are recognized too.
+    pub fn extract_phones(
+        &self,
+        text: &str,
+        html: &str,
+        country: Option<&str>,
+        language: Option<&str>,
+    ) -> Vec<PhoneNumber> {
+        use scraper::{Html, Selector};
+
+        let default_code = country.and_then(calling_code_for_country);
+        let mut candidates = Vec::new();
+
+        let document = Html::parse_fragment(html);
+        if let Ok(selector) = Selector::parse("a[href^='tel:']") {
+            for anchor in document.select(&selector) {
+                let href = anchor.value().attr("href").unwrap_or("");
+                candidates.push(href.trim_start_matches("tel:").replace("%20", " "));
+            }
         }
-        false
+        candidates.extend(phone_candidates(text));
+        if let Some(pattern) = language
+            .and_then(|language| self.languages.get(language))
+            .and_then(|localized| localized.phone_label.as_ref())
+        {
+            candidates.extend(pattern.captures_iter(text).map(|caps| caps[1].to_string()));
+        }
+
+        let mut phones: Vec<PhoneNumber> = Vec::new();
+        for candidate in candidates {
+            if let Some(phone) = normalize_phone(&candidate, default_code) {
+                if !phones.iter().any(|known| known.e164 == phone.e164) {
+                    phones.push(phone);
+                }
+            }
+        }
+        phones
     }
 
-    pub fn extract_social_media(&self, text: &str, html: &str) -> (Option<String>, Option<String>) {
-        let linkedin = self.extract_linkedin(text, html);
-        let twitter = self.extract_twitter(text, html);
-        (linkedin, twitter)
+    /// Postal address from JSON-LD `PostalAddress` data, falling back to
+    /// the footer, imprint and `<address>` text of the page
+    pub fn extract_address(&self, html: &str) -> Option<PostalAddress> {
+        use scraper::{Html, Selector};
+
+        let document = Html::parse_document(html);
+
+        if let Ok(selector) = Selector::parse("script[type='application/ld+json']") {
+            for script in document.select(&selector) {
+                let json = script.text().collect::<String>();
+                let Ok(value) = serde_json::from_str::<serde_json::Value>(&json) else {
+                    continue;
+                };
+                if let Some(address) = postal_address_from_json_ld(&value) {
+                    return Some(address);
+                }
+            }
+        }
+
+        let selector = Selector::parse(
+            "address, footer, [class*='footer'], [id*='footer'], [class*='imprint'], \
+             [id*='imprint'], [class*='impressum'], [id*='impressum'], \
+             [itemtype*='schema.org/PostalAddress']",
+        )
+        .ok()?;
+        document.select(&selector).find_map(|element| {
+            let text = element
+                .text()
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            postal_address_from_text(&text)
+        })
     }
 
-    fn extract_linkedin(&self, text: &str, html: &str) -> Option<String> {
-        let linkedin_patterns = [
-            r"https?://(?:www\.)?linkedin\.com/in/([a-zA-Z0-9-]+)",
-            r"https?://(?:www\.)?linkedin\.com/company/([a-zA-Z0-9-]+)",
-            r"linkedin\.com/in/([a-zA-Z0-9-]+)",
-            r"linkedin\.com/company/([a-zA-Z0-9-]+)",
-        ];
+    /// Fill phones and address from a page, recording both as location
+    /// evidence along with the page's location phrases. Localized patterns
+    /// follow the page language, falling back to the lead's. The country is
+    /// settled from the text and address first, as national-format phone
+    /// numbers can only be read once it is known.
+    pub fn apply_contact_details(&self, lead: &mut Lead, text: &str, html: &str) {
+        let gazetteer = Gazetteer::bundled();
+
+        let language = page_language(text, html).or_else(|| lead.language.clone());
+        if lead.language.is_none() {
+            lead.language = language.clone();
+        }
+        for evidence in self.location_mentions(text, language.as_deref()) {
+            lead.add_location_evidence(evidence);
+        }
 
-        for pattern in &linkedin_patterns {
-            if let Ok(regex) = regex::Regex::new(pattern) {
-                if let Some(caps) = regex.captures(html) {
-                    if let Some(profile) = caps.get(0) {
-                        let url = profile.as_str();
-                        if url.starts_with("http") {
-                            return Some(url.to_string());
-                        } else {
-                            return Some(format!("https://{}", url));
-                        }
-                    }
+        if lead.address.is_none() {
+            lead.address = self.extract_address(html);
+            let evidence = lead.address.as_ref().and_then(|address| {
+                let place = gazetteer.resolve_address(address)?;
+                Some(gazetteer.evidence(EvidenceKind::Address, &address.one_line(), place))
+            });
+            if let Some(evidence) = evidence {
+                lead.add_location_evidence(evidence);
+            }
+        }
+
+        // International numbers may settle the country for national ones:
+        // read the page a second time when they change it
+        let mut country_code = lead.country_code().map(str::to_string);
+        for _ in 0..2 {
+            for phone in
+                self.extract_phones(text, html, country_code.as_deref(), language.as_deref())
+            {
+                if lead.phones.iter().any(|known| known.e164 == phone.e164) {
+                    continue;
                 }
-                // Try in text too
-                if let Some(caps) = regex.captures(text) {
-                    if let Some(profile) = caps.get(0) {
-                        let url = profile.as_str();
-                        if url.starts_with("http") {
-                            return Some(url.to_string());
-                        } else {
-                            return Some(format!("https://{}", url));
-                        }
-                    }
+                if let Some(ref code) = phone.country {
+                    lead.add_location_evidence(gazetteer.evidence(
+                        EvidenceKind::PhonePrefix,
+                        &phone.e164,
+                        Place {
+                            country_code: code.clone(),
+                            region: None,
+                            city: None,
+                        },
+                    ));
                 }
+                lead.phones.push(phone);
+            }
+            let settled = lead.country_code().map(str::to_string);
+            if settled == country_code {
+                break;
             }
+            country_code = settled;
         }
-        None
     }
 
-    fn extract_twitter(&self, text: &str, html: &str) -> Option<String> {
-        let twitter_patterns = [
-            r"https?://(?:www\.)?(?:twitter\.com|x\.com)/([a-zA-Z0-9_]+)",
-            r"(?:twitter\.com|x\.com)/([a-zA-Z0-9_]+)",
-            r"@([a-zA-Z0-9_]+)",
+    /// Title, description and keywords from a page's `<head>`
+    pub fn extract_website_meta(&self, html: &str) -> Option<WebsiteMeta> {
+        use scraper::{Html, Selector};
+
+        let document = Html::parse_document(html);
+        let first = |selector: &str, attr: Option<&str>| {
+            let selector = Selector::parse(selector).ok()?;
+            let element = document.select(&selector).next()?;
+            let text = match attr {
+                Some(attr) => element.value().attr(attr)?.to_string(),
+                None => element.text().collect::<String>(),
+            };
+            let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            (!text.is_empty()).then_some(text)
+        };
+
+        let meta = WebsiteMeta {
+            title: first("title", None),
+            description: first("meta[name='description']", Some("content"))
+                .or_else(|| first("meta[property='og:description']", Some("content"))),
+            keywords: first("meta[name='keywords']", Some("content"))
+                .map(|keywords| {
+                    keywords
+                        .split(',')
+                        .map(|keyword| keyword.trim().to_string())
+                        .filter(|keyword| !keyword.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+        (meta != WebsiteMeta::default()).then_some(meta)
+    }
+
+    /// Extract people from a team/about/founders page: cards holding a
+    /// name, an optional role, and personal profile links
+    pub fn extract_people(&self, html: &str, origin: &str) -> Vec<Person> {
+        use scraper::{Html, Selector};
+
+        let document = Html::parse_document(html);
+        let select = |selector: &str| Selector::parse(selector).ok();
+
+        let Some(card_selector) = select(
+            "[itemtype*='schema.org/Person'], [class*='founder'], [class*='team-member'], \
+             [class*='member'], [class*='person'], [class*='profile']",
+        ) else {
+            return Vec::new();
+        };
+        let name_selectors = [
+            "[itemprop='name']",
+            "[class*='name']",
+            "h3",
+            "h4",
+            "h2",
+            "strong",
+        ];
+        let role_selectors = [
+            "[itemprop='jobTitle']",
+            "[class*='title']",
+            "[class*='role']",
+            "[class*='position']",
         ];
+        let text_of = |element: scraper::ElementRef| {
+            element
+                .text()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let link_selector = select("a[href]");
+
+        let mut people: Vec<Person> = Vec::new();
+        for card in document.select(&card_selector) {
+            // Only innermost cards: a `team-members` wrapper holds everyone's links
+            if card
+                .select(&card_selector)
+                .any(|inner| inner.id() != card.id())
+            {
+                continue;
+            }
 
-        for pattern in &twitter_patterns {
-            if let Ok(regex) = regex::Regex::new(pattern) {
-                if let Some(caps) = regex.captures(html) {
-                    if let Some(handle) = caps.get(1) {
-                        let username = handle.as_str();
-                        if !username.is_empty() && username != "twitter" && username != "x" {
-                            return Some(format!("https://twitter.com/{}", username));
-                        }
-                    } else if let Some(full_match) = caps.get(0) {
-                        let url = full_match.as_str();
-                        if url.starts_with("http") {
-                            return Some(url.to_string());
-                        }
-                    }
+            let name = name_selectors
+                .iter()
+                .filter_map(|selector| select(selector))
+                .find_map(|selector| card.select(&selector).next().map(text_of))
+                .filter(|name| Self::looks_like_person_name(name));
+            let Some(name) = name else {
+                continue;
+            };
+
+            let mut person = Person::new(&name, origin);
+            person.role = role_selectors
+                .iter()
+                .filter_map(|selector| select(selector))
+                .find_map(|selector| card.select(&selector).next().map(text_of))
+                .filter(|role| !role.is_empty() && *role != name);
+
+            for link in link_selector
+                .iter()
+                .flat_map(|selector| card.select(selector))
+            {
+                let href = link.value().attr("href").unwrap_or("").trim();
+                if let Some(email) = href.strip_prefix("mailto:") {
+                    person.email.get_or_insert_with(|| email.to_string());
+                    continue;
                 }
-                // Try in text too
-                if let Some(caps) = regex.captures(text) {
-                    if let Some(handle) = caps.get(1) {
-                        let username = handle.as_str();
-                        if !username.is_empty() && username != "twitter" && username != "x" {
-                            return Some(format!("https://twitter.com/{}", username));
-                        }
+                match classify_social_url(href, false) {
+                    Some(link) if link.network == SocialNetwork::LinkedInPerson => {
+                        person.linkedin.get_or_insert(link.url);
+                    }
+                    Some(link) if link.network == SocialNetwork::Twitter => {
+                        person.twitter.get_or_insert(link.url);
                     }
+                    Some(link) if link.network == SocialNetwork::GitHub => {
+                        person.github.get_or_insert(link.handle);
+                    }
+                    _ => {}
                 }
             }
+
+            if !people.iter().any(|known| known.name == person.name) {
+                people.push(person);
+            }
         }
-        None
+
+        people
+    }
+
+    /// Short, capitalized, multi-letter text without digits or sentence punctuation
+    fn looks_like_person_name(text: &str) -> bool {
+        let words = text.split_whitespace().count();
+        (1..=5).contains(&words)
+            && text.len() <= 60
+            && text.chars().next().is_some_and(char::is_uppercase)
+            && !text
+                .chars()
+                .any(|c| c.is_ascii_digit() || matches!(c, '.' | '!' | '?' | ':' | '@'))
+    }
+
+    fn is_valid_website_url(&self, url: &str) -> bool {
+        if let Ok(parsed) = Url::parse(url) {
+            let scheme = parsed.scheme();
+            return scheme == "http" || scheme == "https";
+        }
+        false
     }
 
-    // NEW: Extract real emails from GitHub repository commits
-    pub async fn extract_github_commit_emails(&self, github_url: &str) -> Vec<String> {
-        match self.get_repo_commit_emails(github_url).await {
-            Ok(emails) => {
-                if !emails.is_empty() {
+    /// Social profiles linked from a page, one per network. Anchors are
+    /// classified by host and path; when a network has several candidates,
+    /// the one resembling `brands` (lead name, domain) wins over unrelated accounts.
+    pub fn extract_socials(
+        &self,
+        text: &str,
+        html: &str,
+        brands: &[String],
+    ) -> BTreeMap<SocialNetwork, String> {
+        use scraper::{Html, Selector};
+
+        let mut candidates: Vec<SocialLink> = Vec::new();
+
+        let document = Html::parse_fragment(html);
+        if let Ok(selector) = Selector::parse("a[href]") {
+            for anchor in document.select(&selector) {
+                let href = anchor.value().attr("href").unwrap_or("");
+                let rel_me = anchor
+                    .value()
+                    .attr("rel")
+                    .is_some_and(|rel| rel.split_whitespace().any(|r| r == "me"));
+                candidates.extend(classify_social_url(href, rel_me));
+            }
+        }
+
+        // Bare URLs in text (Markdown descriptions, plain listings)
+        if let Ok(url_regex) = Regex::new(r#"https?://[^\s<>"'()\[\]]+"#) {
+            for found in url_regex.find_iter(text) {
+                let url = found.as_str().trim_end_matches(['.', ',', ';', '!']);
+                candidates.extend(classify_social_url(url, false));
+            }
+        }
+
+        // With a brand to compare against, unrelated accounts (designers,
+        // agencies, embeds) are dropped; person profiles never match a brand
+        let require_affinity = has_brand_hints(brands);
+        let mut socials: BTreeMap<SocialNetwork, (usize, String)> = BTreeMap::new();
+        for link in candidates {
+            let affinity = brand_affinity(&link.handle, brands);
+            if require_affinity && affinity == 0 && link.network != SocialNetwork::LinkedInPerson {
+                continue;
+            }
+            match socials.get(&link.network) {
+                Some((best, _)) if *best >= affinity => {}
+                _ => {
+                    socials.insert(link.network, (affinity, link.url));
+                }
+            }
+        }
+
+        socials
+            .into_iter()
+            .map(|(network, (_, url))| (network, url))
+            .collect()
+    }
+
+    /// Lead name and website domain label, used to tell the lead's own
+    /// accounts from unrelated ones
+    pub fn brand_hints(name: &str, website: Option<&str>) -> Vec<String> {
+        let mut brands = vec![name.to_string()];
+        let host = website
+            .and_then(|website| Url::parse(website).ok())
+            .and_then(|url| url.host_str().map(str::to_string));
+        if let Some(host) = host {
+            let host = host.trim_start_matches("www.");
+            brands.extend(host.split('.').next().map(str::to_string));
+        }
+        brands
+    }
+
+    /// Extract the best real emails from the commits of a repository on any configured forge
+    pub async fn extract_commit_emails(&self, repo_url: &str) -> Vec<String> {
+        self.mine_contributors(repo_url, None)
+            .await
+            .into_iter()
+            .map(|contributor| contributor.email)
+            .take(2)
+            .collect()
+    }
+
+    /// Ranked contributors of a repository, from its sampled commit history.
+    /// Addresses on the `website` domain rank first.
+    pub async fn mine_contributors(
+        &self,
+        repo_url: &str,
+        website: Option<&str>,
+    ) -> Vec<Contributor> {
+        match self.get_repo_contributors(repo_url, website).await {
+            Ok(contributors) => {
+                if !contributors.is_empty() {
                     debug!(
-                        "Found {} real commit emails for {}: {:?}",
-                        emails.len(),
-                        github_url,
-                        emails
+                        "Found {} real commit authors for {}: {:?}",
+                        contributors.len(),
+                        repo_url,
+                        contributors
+                            .iter()
+                            .map(|contributor| &contributor.email)
+                            .collect::<Vec<_>>()
                     );
                 }
-                emails
+                contributors
             }
             Err(e) => {
-                debug!("Failed to extract commit emails from {}: {}", github_url, e);
+                debug!("Failed to extract commit emails from {}: {}", repo_url, e);
                 vec![]
             }
         }
     }
 
-    async fn get_repo_commit_emails(&self, github_url: &str) -> ExtractorResult<Vec<String>> {
-        let repo_path = self.parse_github_url(github_url)?;
+    /// Run all repository-based enrichment for a lead with a `repository`:
+    /// contributors, repository health, metadata files and the owner profile
+    pub async fn enrich_repository_lead(&self, lead: &mut Lead) {
+        if !self.enrich_repositories {
+            return;
+        }
+        if let Some(context) = self.repository_context(lead).await {
+            self.enrich_contributors(lead, &context).await;
+            self.enrich_repo_health(lead, &context).await;
+            if self.metadata_files {
+                self.enrich_from_metadata_files(lead, &context).await;
+            }
+        }
+        self.enrich_from_owner_profile(lead).await;
+        Self::add_repository_people(lead);
+    }
 
-        // Check if it's a fork - skip forks
-        let repo_info = self.get_repo_info(&repo_path).await?;
-        if repo_info.fork {
-            debug!("Skipping fork repository: {}", repo_path);
-            return Ok(vec![]);
+    /// Attach contributors and repository health, from one repository lookup
+    pub async fn enrich_repository_activity(&self, lead: &mut Lead) {
+        if !self.enrich_repositories {
+            return;
+        }
+        if let Some(context) = self.repository_context(lead).await {
+            self.enrich_contributors(lead, &context).await;
+            self.enrich_repo_health(lead, &context).await;
         }
+    }
 
-        // Get recent commits from main branch
-        let commits = self
-            .get_recent_commits(&repo_path, &repo_info.default_branch)
-            .await?;
+    /// Resolve the lead's repository and fetch its `repo_info`
+    async fn repository_context(&self, lead: &Lead) -> Option<RepositoryContext> {
+        let (forge, repo) = self.forges.resolve(lead.repository.as_deref()?)?;
+        match forge.repo_info(&repo).await {
+            Ok(info) => Some(RepositoryContext { forge, repo, info }),
+            Err(e) => {
+                debug!("Failed to fetch repository info for {}: {}", repo.path, e);
+                None
+            }
+        }
+    }
 
-        // Extract unique valid emails
-        let emails = self.extract_valid_emails_from_commits(commits);
+    /// Turn declared maintainers and top contributors into people
+    fn add_repository_people(lead: &mut Lead) {
+        for maintainer in lead.maintainers.clone() {
+            let Some(name) = maintainer.name.clone().or(maintainer.github.clone()) else {
+                continue;
+            };
+            let mut person = Person::new(&name, &maintainer.source_file);
+            person.role = Some("Maintainer".to_string());
+            person.email = maintainer.email;
+            person.github = maintainer.github;
+            lead.add_person(person);
+        }
 
-        Ok(emails)
+        for contributor in lead.contributors.clone() {
+            let name = contributor.name.unwrap_or_else(|| {
+                contributor
+                    .email
+                    .split('@')
+                    .next()
+                    .unwrap_or("")
+                    .to_string()
+            });
+            let mut person = Person::new(&name, "commits");
+            person.role = Some("Contributor".to_string());
+            person.email = Some(contributor.email);
+            lead.add_person(person);
+        }
     }
 
-    fn parse_github_url(&self, url: &str) -> ExtractorResult<String> {
-        if let Ok(parsed_url) = Url::parse(url) {
-            if let Some(path) = parsed_url.path_segments() {
-                let segments: Vec<&str> = path.collect();
-                if segments.len() >= 2 {
-                    return Ok(format!("{}/{}", segments[0], segments[1]));
-                }
+    /// Collect maintainers and sponsor links from `package.json`, `Cargo.toml`,
+    /// `CODEOWNERS`, `SECURITY.md`, `FUNDING.yml`, `CITATION.cff` and friends
+    async fn enrich_from_metadata_files(&self, lead: &mut Lead, context: &RepositoryContext) {
+        let RepositoryContext { forge, repo, info } = context;
+        let mut metadata = RepoMetadata::default();
+        for path in self.existing_metadata_files(context).await {
+            match forge.fetch_file(repo, &info.default_branch, path).await {
+                Ok(content) => metadata.merge(parse_metadata_file(path, &content)),
+                Err(e) => debug!("Failed to fetch {} in {}: {}", path, repo.path, e),
             }
         }
-        Err(ScrapingError::ParseError(format!(
-            "Invalid GitHub URL: {}",
-            url
-        )))
+
+        if lead.email.is_none() {
+            lead.email = metadata
+                .contacts
+                .iter()
+                .find_map(|contact| contact.email.clone());
+        }
+        lead.maintainers = metadata.contacts;
+        lead.sponsor_links = metadata.sponsor_links;
     }
 
-    async fn get_repo_info(&self, repo_path: &str) -> ExtractorResult<GitHubRepo> {
-        let url = format!("https://api.github.com/repos/{}", repo_path);
+    /// Metadata files present in the repository, from directory listings;
+    /// every candidate when the forge cannot list directories
+    async fn existing_metadata_files(&self, context: &RepositoryContext) -> Vec<&'static str> {
+        let RepositoryContext { forge, repo, info } = context;
+        let branch = &info.default_branch;
+        let mut entries: BTreeSet<String> = match forge.list_files(repo, branch, "").await {
+            Ok(Some(entries)) => entries.into_iter().collect(),
+            Ok(None) => return METADATA_FILES.to_vec(),
+            Err(e) => {
+                debug!("Failed to list files of {}: {}", repo.path, e);
+                return Vec::new();
+            }
+        };
 
-        let mut request = self.client.get(&url);
-        if let Some(ref token) = self.github_token {
-            request = request.header("Authorization", format!("token {}", token));
+        for dir in Self::metadata_directories() {
+            if !entries.contains(&format!("{}/", dir)) {
+                continue;
+            }
+            match forge.list_files(repo, branch, dir).await {
+                Ok(Some(listed)) => entries.extend(listed),
+                Ok(None) => {}
+                Err(e) => debug!("Failed to list {} of {}: {}", dir, repo.path, e),
+            }
         }
-        request = request.header("User-Agent", "Lead-Scraper/1.0");
 
-        let response = request
-            .send()
+        METADATA_FILES
+            .iter()
+            .copied()
+            .filter(|path| entries.contains(*path))
+            .collect()
+    }
+
+    /// Attach ranked contributors and fill `github_email` from the best one
+    async fn enrich_contributors(&self, lead: &mut Lead, context: &RepositoryContext) {
+        let contributors = match self
+            .contributors_from_history(context, lead.website.as_deref())
             .await
-            .map_err(|e| ScrapingError::NetworkError(format!("Failed to get repo info: {}", e)))?;
+        {
+            Ok(contributors) => contributors,
+            Err(e) => {
+                debug!(
+                    "Failed to mine contributors of {}: {}",
+                    context.repo.path, e
+                );
+                return;
+            }
+        };
+        if lead.github_email.is_none() {
+            lead.github_email = contributors
+                .first()
+                .map(|contributor| contributor.email.clone());
+        }
+        if !contributors.is_empty() {
+            lead.contributors = contributors;
+        }
+    }
 
-        if !response.status().is_success() {
-            return Err(ScrapingError::NetworkError(format!(
-                "GitHub API error: {}",
-                response.status()
-            )));
+    /// Attach stars, activity and release cadence of the lead's repository
+    async fn enrich_repo_health(&self, lead: &mut Lead, context: &RepositoryContext) {
+        let RepositoryContext { forge, repo, info } = context;
+        match forge.repo_health(repo, info).await {
+            Ok(health) => lead.repo_health = health.or(lead.repo_health.take()),
+            Err(e) => debug!("Failed to fetch repository health for {}: {}", repo.path, e),
         }
+    }
 
-        response
-            .json()
-            .await
-            .map_err(|e| ScrapingError::ParseError(format!("Failed to parse repo info: {}", e)))
+    /// Enrich a lead from the forge profile of its repository owner
+    pub async fn enrich_from_owner_profile(&self, lead: &mut Lead) {
+        let Some(ref repository) = lead.repository else {
+            return;
+        };
+        let Some((forge, repo)) = self.forges.resolve(repository) else {
+            return;
+        };
+
+        match forge.owner_profile(repo.owner()).await {
+            Ok(Some(profile)) => self.apply_owner_profile(lead, profile),
+            Ok(None) => {}
+            Err(e) => debug!("Failed to fetch owner profile for {}: {}", repo.path, e),
+        }
     }
 
-    async fn get_recent_commits(
-        &self,
-        repo_path: &str,
-        branch: &str,
-    ) -> ExtractorResult<Vec<GitHubCommit>> {
-        let url = format!(
-            "https://api.github.com/repos/{}/commits?sha={}&per_page=15",
-            repo_path, branch
-        );
+    /// Fill website, email and twitter when absent, add the profile location
+    /// as evidence, and store the owner's company and follower/repository counts
+    pub fn apply_owner_profile(&self, lead: &mut Lead, profile: OwnerProfile) {
+        // A repository page is no substitute for the owner's own site
+        let website_absent = lead.website.is_none() || lead.website == lead.repository;
+        if website_absent {
+            if let Some(blog) = profile.blog.as_deref().and_then(Self::normalize_blog_url) {
+                lead.website = Some(blog);
+            }
+        }
 
-        let mut request = self.client.get(&url);
-        if let Some(ref token) = self.github_token {
-            request = request.header("Authorization", format!("token {}", token));
+        if lead.email.is_none() {
+            lead.email = profile.email.filter(|email| !email.trim().is_empty());
+        }
+        if lead.twitter.is_none() {
+            lead.twitter = profile
+                .twitter_username
+                .filter(|handle| !handle.trim().is_empty())
+                .map(|handle| format!("https://twitter.com/{}", handle.trim()));
         }
-        request = request.header("User-Agent", "Lead-Scraper/1.0");
+        if let Some(ref twitter) = lead.twitter {
+            lead.socials
+                .entry(SocialNetwork::Twitter)
+                .or_insert_with(|| twitter.clone());
+        }
+        // Only GitHub serves owner profiles, on whatever host it is configured at
+        let on_forge = Url::parse(&profile.html_url)
+            .ok()
+            .and_then(|url| url.host_str().map(|host| self.forges.is_forge_host(host)))
+            .unwrap_or(false);
+        if on_forge {
+            lead.socials
+                .entry(SocialNetwork::GitHub)
+                .or_insert_with(|| profile.html_url.clone());
+        }
+        if let Some(location) = profile.location.as_deref().map(str::trim) {
+            let gazetteer = Gazetteer::bundled();
+            if let Some(place) = gazetteer.resolve_place(location) {
+                lead.add_location_evidence(gazetteer.evidence(
+                    EvidenceKind::ForgeProfile,
+                    location,
+                    place,
+                ));
+            }
+        }
+
+        lead.company = profile
+            .company
+            .map(|company| company.trim().trim_start_matches('@').to_string())
+            .filter(|company| !company.is_empty())
+            .or(lead.company.take());
+        lead.owner_followers = profile.followers.or(lead.owner_followers);
+        lead.owner_public_repos = profile.public_repos.or(lead.owner_public_repos);
+    }
+
+    /// Profile `blog` fields are often bare domains (`acme.dev`)
+    fn normalize_blog_url(blog: &str) -> Option<String> {
+        let blog = blog.trim();
+        if blog.is_empty() {
+            None
+        } else if blog.starts_with("http://") || blog.starts_with("https://") {
+            Some(blog.to_string())
+        } else {
+            Some(format!("https://{}", blog))
+        }
+    }
 
-        let response = request
-            .send()
+    async fn get_repo_contributors(
+        &self,
+        repo_url: &str,
+        website: Option<&str>,
+    ) -> ExtractorResult<Vec<Contributor>> {
+        let (forge, repo) = self.forges.resolve(repo_url).ok_or_else(|| {
+            ScrapingError::ParseError(format!("Not a known forge repository URL: {}", repo_url))
+        })?;
+        let info = forge.repo_info(&repo).await?;
+        self.contributors_from_history(&RepositoryContext { forge, repo, info }, website)
             .await
-            .map_err(|e| ScrapingError::NetworkError(format!("Failed to get commits: {}", e)))?;
+    }
 
-        if !response.status().is_success() {
-            warn!(
-                "Failed to get commits for {}: {}",
-                repo_path,
-                response.status()
-            );
+    /// Rank the authors of the sampled default-branch history; forks have none
+    async fn contributors_from_history(
+        &self,
+        context: &RepositoryContext,
+        website: Option<&str>,
+    ) -> ExtractorResult<Vec<Contributor>> {
+        let RepositoryContext { forge, repo, info } = context;
+        if info.fork {
+            debug!("Skipping fork repository: {}", repo.path);
             return Ok(vec![]);
         }
+        let branch = &info.default_branch;
 
-        response
-            .json()
-            .await
-            .map_err(|e| ScrapingError::ParseError(format!("Failed to parse commits: {}", e)))
+        let mailmap = match forge.fetch_file(repo, branch, ".mailmap").await {
+            Ok(content) => Mailmap::parse(&content),
+            Err(_) => Mailmap::default(),
+        };
+
+        // Sample history page by page from the default branch
+        let per_page = self.commit_mining.per_page;
+        let mut commits = Vec::new();
+        for page in 1..=self.commit_mining.max_pages {
+            match forge.commits(repo, branch, page, per_page).await {
+                Ok(batch) => {
+                    let last_page = batch.len() < per_page;
+                    commits.extend(batch);
+                    if last_page {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        "Failed to get commits for {} (page {}): {}",
+                        repo.path, page, e
+                    );
+                    break;
+                }
+            }
+        }
+
+        let website_domain = website.and_then(|website| self.website_domain(website));
+        Ok(self.rank_contributors(commits, &mailmap, website_domain.as_deref()))
     }
 
-    fn extract_valid_emails_from_commits(&self, commits: Vec<GitHubCommit>) -> Vec<String> {
-        let mut emails = HashSet::new();
+    /// Domain of a lead website, unless it is hosted on a forge
+    fn website_domain(&self, website: &str) -> Option<String> {
+        let host = Url::parse(website).ok()?.host_str()?.to_lowercase();
+        if self.forges.is_forge_host(&host) {
+            return None;
+        }
+        Some(host.trim_start_matches("www.").to_string())
+    }
+
+    /// Aggregate commits per (mailmap-canonical) author and rank them:
+    /// website-domain matches, then company addresses, then commit count and recency
+    fn rank_contributors(
+        &self,
+        commits: Vec<ForgeCommit>,
+        mailmap: &Mailmap,
+        website_domain: Option<&str>,
+    ) -> Vec<Contributor> {
         let invalid_domains = [
             "users.noreply.github.com",
             "noreply.github.com",
+            "users.noreply.gitlab.com",
+            "noreply.codeberg.org",
             "example.com",
             "localhost",
             "test.com",
@@ -412,31 +1063,61 @@ impl DataExtractor {
             "snyk-bot@users.noreply.github.com",
         ];
 
+        let mut by_email: HashMap<String, Contributor> = HashMap::new();
         for commit in commits {
-            // Check author email
-            let author_email = &commit.commit.author.email;
-            if self.is_valid_commit_email(author_email, &invalid_domains, &invalid_emails) {
-                emails.insert(author_email.clone());
-            }
+            // The committer counts too when it is someone else than the author
+            let committer = commit
+                .committer
+                .filter(|committer| !committer.email.eq_ignore_ascii_case(&commit.author.email));
+
+            for person in std::iter::once(commit.author).chain(committer) {
+                let (name, email) = mailmap.resolve(person.name.as_deref(), &person.email);
+                if !self.is_valid_commit_email(&email, &invalid_domains, &invalid_emails) {
+                    continue;
+                }
 
-            // Check committer email (different from author sometimes)
-            let committer_email = &commit.commit.committer.email;
-            if self.is_valid_commit_email(committer_email, &invalid_domains, &invalid_emails) {
-                emails.insert(committer_email.clone());
+                let contributor =
+                    by_email
+                        .entry(email.to_lowercase())
+                        .or_insert_with(|| Contributor {
+                            name: None,
+                            domain_match: website_domain
+                                .is_some_and(|domain| Self::email_matches_domain(&email, domain)),
+                            email,
+                            commits: 0,
+                            last_commit_at: None,
+                        });
+                contributor.commits += 1;
+                if person.date > contributor.last_commit_at {
+                    contributor.last_commit_at = person.date;
+                }
+                if contributor.name.is_none() {
+                    contributor.name = name.filter(|name| !name.trim().is_empty());
+                }
             }
         }
 
-        // Convert to sorted vec, prioritize company emails
-        let mut email_vec: Vec<String> = emails.into_iter().collect();
-        email_vec.sort_by(|a, b| {
-            let a_priority = self.email_priority(a);
-            let b_priority = self.email_priority(b);
-            b_priority.cmp(&a_priority)
+        let mut contributors: Vec<Contributor> = by_email.into_values().collect();
+        contributors.sort_by(|a, b| {
+            b.domain_match
+                .cmp(&a.domain_match)
+                .then_with(|| {
+                    self.email_priority(&b.email)
+                        .cmp(&self.email_priority(&a.email))
+                })
+                .then_with(|| b.commits.cmp(&a.commits))
+                .then_with(|| b.last_commit_at.cmp(&a.last_commit_at))
+                .then_with(|| a.email.cmp(&b.email))
         });
 
-        // Limit to top 2 emails
-        email_vec.truncate(2);
-        email_vec
+        contributors.truncate(self.commit_mining.max_contributors);
+        contributors
+    }
+
+    /// `jane@acme.dev` and `jane@eu.acme.dev` both match `acme.dev`
+    fn email_matches_domain(email: &str, domain: &str) -> bool {
+        let email_domain = email.rsplit('@').next().unwrap_or("").to_lowercase();
+        email_domain == domain || email_domain.ends_with(&format!(".{}", domain))
     }
 
     fn is_valid_commit_email(
@@ -537,5 +1218,448 @@ mod tests {
         let email = extractor.extract_email(text, html);
         assert_eq!(email, Some("test@example.com".to_string()));
     }
-}
 
+    #[test]
+    fn test_apply_owner_profile_fills_absent_fields() {
+        let patterns = crate::config::PatternsConfig::default();
+        let extractor = DataExtractor::new(&patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        let repository = "https://github.com/acme/tool".to_string();
+        let mut lead = Lead::new("tool".to_string(), crate::models::Source::BetaList)
+            .with_website(Some(repository.clone()))
+            .with_repository(Some(repository))
+            .with_location(extractor.extract_location(
+                "Proudly based in Berlin, Germany",
+                Some("https://tool.de"),
+                None,
+            ));
+
+        let profile = OwnerProfile {
+            login: "acme".to_string(),
+            blog: Some("acme.dev".to_string()),
+            company: Some("@acme-inc".to_string()),
+            location: Some("Paris, France".to_string()),
+            twitter_username: Some("acmehq".to_string()),
+            followers: Some(340),
+            public_repos: Some(12),
+            ..OwnerProfile::default()
+        };
+        extractor.apply_owner_profile(&mut lead, profile);
+
+        assert_eq!(lead.website.as_deref(), Some("https://acme.dev"));
+        assert_eq!(lead.twitter.as_deref(), Some("https://twitter.com/acmehq"));
+        // Text mention and ccTLD (3 + 1) tie with the profile (4); two
+        // agreeing clues beat one
+        assert_eq!(lead.country_code(), Some("DE"));
+        assert_eq!(lead.location.as_ref().unwrap().evidence.len(), 3);
+        assert_eq!(lead.company.as_deref(), Some("acme-inc"));
+        assert_eq!(lead.owner_followers, Some(340));
+        assert_eq!(lead.owner_public_repos, Some(12));
+    }
+
+    #[test]
+    fn test_owner_profile_link_on_configured_forge() {
+        use crate::config::{ForgeConfig, ForgesConfig};
+
+        let client = reqwest::Client::new();
+        let forges = ForgesConfig {
+            github: ForgeConfig::new(
+                "https://github.acme.corp",
+                "https://github.acme.corp/api/v3",
+            ),
+            ..ForgesConfig::default()
+        };
+        let extractor = DataExtractor::new(
+            &crate::config::PatternsConfig::default(),
+            client.clone(),
+            None,
+        )
+        .unwrap()
+        .with_forges(ForgeRegistry::from_config(client, &forges, None));
+        let profile = |html_url: &str| OwnerProfile {
+            login: "acme".to_string(),
+            html_url: html_url.to_string(),
+            ..OwnerProfile::default()
+        };
+
+        let mut lead = Lead::new("tool".to_string(), crate::models::Source::BetaList);
+        extractor.apply_owner_profile(&mut lead, profile("https://github.acme.corp/acme"));
+        assert_eq!(
+            lead.socials.get(&SocialNetwork::GitHub).map(String::as_str),
+            Some("https://github.acme.corp/acme")
+        );
+
+        let mut lead = Lead::new("tool".to_string(), crate::models::Source::BetaList);
+        extractor.apply_owner_profile(&mut lead, profile("https://elsewhere.example/acme"));
+        assert!(!lead.socials.contains_key(&SocialNetwork::GitHub));
+    }
+
+    #[tokio::test]
+    async fn test_metadata_files_fetched_only_when_listed() {
+        use crate::config::ForgeConfig;
+        use crate::forges::test_server;
+        use base64::{engine::general_purpose, Engine as _};
+
+        let content = |text: &str| {
+            format!(
+                r#"{{"content": "{}"}}"#,
+                general_purpose::STANDARD.encode(text)
+            )
+        };
+        let base =
+            test_server::serve(vec![
+            (
+                "/repos/acme/tool/contents?ref=main",
+                r#"[{"path": "package.json", "type": "file"}, {"path": ".github", "type": "dir"}]"#
+                    .to_string(),
+            ),
+            (
+                "/repos/acme/tool/contents/.github?ref=main",
+                r#"[{"path": ".github/FUNDING.yml", "type": "file"}]"#.to_string(),
+            ),
+            (
+                "/repos/acme/tool/contents/package.json",
+                content(r#"{"author": "Jane Doe <jane@acme.dev>"}"#),
+            ),
+            (
+                "/repos/acme/tool/contents/.github/FUNDING.yml",
+                content("github: acme\n"),
+            ),
+            // Served, but absent from the listing: never fetched
+            (
+                "/repos/acme/tool/contents/Cargo.toml",
+                content("[package]\nauthors = [\"Ghost <ghost@acme.dev>\"]\n"),
+            ),
+            ("/repos/acme/tool/commits", "[]".to_string()),
+            ("/repos/acme/tool/contributors", "[]".to_string()),
+            ("/repos/acme/tool/releases", "[]".to_string()),
+            ("/users/acme", r#"{"login": "acme", "html_url": ""}"#.to_string()),
+            (
+                "/repos/acme/tool",
+                r#"{"fork": false, "default_branch": "main", "stargazers_count": 42}"#
+                    .to_string(),
+            ),
+        ])
+            .await;
+
+        let forges = ForgesConfig {
+            github: ForgeConfig::new(&base, &base),
+            ..ForgesConfig::default()
+        };
+        let extractor = DataExtractor::new(&PatternsConfig::default(), Client::new(), None)
+            .expect("Failed to create extractor")
+            .with_forges(ForgeRegistry::from_config(Client::new(), &forges, None));
+
+        let mut lead = Lead::new("tool".to_string(), crate::models::Source::BetaList)
+            .with_repository(Some(format!("{}/acme/tool", base)));
+        extractor.enrich_repository_lead(&mut lead).await;
+
+        assert_eq!(
+            lead.repo_health.as_ref().map(|health| health.stars),
+            Some(42)
+        );
+        assert_eq!(lead.email.as_deref(), Some("jane@acme.dev"));
+        assert_eq!(lead.maintainers.len(), 1);
+        assert_eq!(lead.sponsor_links, vec!["https://github.com/sponsors/acme"]);
+
+        // Without metadata files, maintainers stay empty
+        let extractor = extractor.with_metadata_files(false);
+        let mut lead = Lead::new("tool".to_string(), crate::models::Source::BetaList)
+            .with_repository(Some(format!("{}/acme/tool", base)));
+        extractor.enrich_repository_lead(&mut lead).await;
+        assert!(lead.maintainers.is_empty());
+        assert_eq!(
+            lead.repo_health.as_ref().map(|health| health.stars),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn test_rank_contributors() {
+        use crate::forges::CommitPerson;
+
+        let patterns = crate::config::PatternsConfig::default();
+        let extractor = DataExtractor::new(&patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        let commit = |name: &str, email: &str, date: &str| ForgeCommit {
+            author: CommitPerson {
+                name: Some(name.to_string()),
+                email: email.to_string(),
+                date: Some(date.parse().unwrap()),
+            },
+            committer: Some(CommitPerson {
+                name: Some("GitHub".to_string()),
+                email: "noreply@github.com".to_string(),
+                date: Some(date.parse().unwrap()),
+            }),
+        };
+        let commits = vec![
+            commit("Joe", "joe@gmail.com", "2025-05-03T00:00:00Z"),
+            commit("Joe", "joe@gmail.com", "2025-05-02T00:00:00Z"),
+            commit("Joe", "joe@gmail.com", "2025-05-01T00:00:00Z"),
+            commit("jane", "jane@laptop.local", "2025-04-01T00:00:00Z"),
+            commit("Jane Doe", "jane@acme.dev", "2025-03-01T00:00:00Z"),
+            commit("Sam", "sam@other.io", "2025-02-01T00:00:00Z"),
+            commit(
+                "bot",
+                "dependabot[bot]@users.noreply.github.com",
+                "2025-05-04T00:00:00Z",
+            ),
+        ];
+        let mailmap = Mailmap::parse("Jane Doe <jane@acme.dev> <jane@laptop.local>");
+
+        let contributors = extractor.rank_contributors(commits, &mailmap, Some("acme.dev"));
+        let emails: Vec<&str> = contributors.iter().map(|c| c.email.as_str()).collect();
+        assert_eq!(
+            emails,
+            vec!["jane@acme.dev", "sam@other.io", "joe@gmail.com"]
+        );
+
+        let jane = &contributors[0];
+        assert!(jane.domain_match);
+        assert_eq!(jane.commits, 2);
+        assert_eq!(jane.name.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            jane.last_commit_at,
+            Some("2025-04-01T00:00:00Z".parse().unwrap())
+        );
+        assert_eq!(contributors[2].commits, 3);
+    }
+
+    #[test]
+    fn test_extract_people_from_team_page() {
+        let patterns = crate::config::PatternsConfig::default();
+        let extractor = DataExtractor::new(&patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        let html = r#"
+            <section class="team-members">
+              <div class="team-member">
+                <h3>Jane Doe</h3>
+                <p class="title">Co-founder &amp; CEO</p>
+                <a href="https://www.linkedin.com/in/janedoe">LinkedIn</a>
+                <a href="https://x.com/janedoe">X</a>
+              </div>
+              <div class="team-member">
+                <h3>Joe Smith</h3>
+                <span class="role">CTO</span>
+                <a href="https://github.com/joesmith">GitHub</a>
+                <a href="mailto:joe@acme.dev">Email</a>
+              </div>
+              <div class="team-member"><h3>Join our team today!</h3></div>
+            </section>
+        "#;
+
+        let people = extractor.extract_people(html, "team page");
+        assert_eq!(people.len(), 2);
+        assert_eq!(people[0].name, "Jane Doe");
+        assert_eq!(people[0].role.as_deref(), Some("Co-founder & CEO"));
+        assert_eq!(
+            people[0].linkedin.as_deref(),
+            Some("https://www.linkedin.com/in/janedoe")
+        );
+        assert_eq!(
+            people[0].twitter.as_deref(),
+            Some("https://twitter.com/janedoe")
+        );
+        assert_eq!(people[1].github.as_deref(), Some("joesmith"));
+        assert_eq!(people[1].email.as_deref(), Some("joe@acme.dev"));
+        assert_eq!(people[1].origin, "team page");
+    }
+
+    #[test]
+    fn test_extract_socials() {
+        let patterns = crate::config::PatternsConfig::default();
+        let extractor = DataExtractor::new(&patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        let html = r#"
+            <a href="https://twitter.com/intent/tweet?url=https://acme.dev">Share</a>
+            <a href="https://twitter.com/webflowfan">Site by @webflowfan</a>
+            <a href="https://x.com/AcmeHQ">Follow us</a>
+            <a href="https://www.linkedin.com/company/acme/">LinkedIn</a>
+            <a rel="me" href="https://social.example/@acme">Mastodon</a>
+            <a href="https://discord.gg/acme42">Chat</a>
+        "#;
+        let text = "Write to hello@acme.dev or see https://github.com/acme-hq.";
+
+        let brands = DataExtractor::brand_hints("Acme", Some("https://www.acme.dev"));
+        let socials = extractor.extract_socials(text, html, &brands);
+
+        assert_eq!(
+            socials.get(&SocialNetwork::Twitter).map(String::as_str),
+            Some("https://twitter.com/AcmeHQ")
+        );
+        assert_eq!(
+            socials
+                .get(&SocialNetwork::LinkedInCompany)
+                .map(String::as_str),
+            Some("https://www.linkedin.com/company/acme")
+        );
+        assert_eq!(
+            socials.get(&SocialNetwork::Mastodon).map(String::as_str),
+            Some("https://social.example/@acme")
+        );
+        assert_eq!(
+            socials.get(&SocialNetwork::GitHub).map(String::as_str),
+            Some("https://github.com/acme-hq")
+        );
+        assert!(socials.contains_key(&SocialNetwork::Discord));
+        assert_eq!(socials.len(), 5);
+
+        // A lone unrelated account is not the lead's
+        let html = r#"<a href="https://twitter.com/webflowfan">Site by @webflowfan</a>"#;
+        let socials = extractor.extract_socials("", html, &brands);
+        assert!(socials.is_empty());
+    }
+
+    #[test]
+    fn test_apply_contact_details() {
+        let patterns = crate::config::PatternsConfig::default();
+        let extractor = DataExtractor::new(&patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        let html = r#"<html><body>
+            <a href="tel:+4930123456789">Call us</a>
+            <footer><p>Acme GmbH</p><p>Torstraße 12</p><p>10119 Berlin</p>
+            <p>Telefon: 030 987 654 32</p></footer>
+        </body></html>"#;
+        let text = "Acme GmbH Torstraße 12 10119 Berlin Telefon: 030 987 654 32";
+
+        let mut lead = Lead::new("Acme".to_string(), crate::models::Source::BetaList);
+        extractor.apply_contact_details(&mut lead, text, html);
+
+        // The address settles the country before the national number is read
+        assert_eq!(lead.country(), Some("Germany"));
+        let address = lead.address.clone().unwrap();
+        assert_eq!(address.one_line(), "Torstraße 12, 10119 Berlin");
+        assert_eq!(
+            lead.phones
+                .iter()
+                .map(|p| p.e164.as_str())
+                .collect::<Vec<_>>(),
+            vec!["+4930123456789", "+493098765432"]
+        );
+
+        // Without an address, the international number settles the country
+        let html = r#"<a href="tel:+4930123456789">Call</a> <p>Telefon: 030 987 654 32</p>"#;
+        let mut lead = Lead::new("Acme".to_string(), crate::models::Source::BetaList);
+        extractor.apply_contact_details(&mut lead, "Call Telefon: 030 987 654 32", html);
+        assert_eq!(lead.phones.len(), 2);
+    }
+
+    #[test]
+    fn test_extract_location_evidence() {
+        let mut patterns = crate::config::PatternsConfig::default();
+        patterns
+            .location
+            .country_indicators
+            .push("[Ff]rom ([\\p{Lu}][\\p{L}\\s,.'-]{1,60})".to_string());
+        patterns
+            .tld_mapping
+            .insert(".co.uk".to_string(), "United Kingdom".to_string());
+        let extractor = DataExtractor::new(&patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        // "from the ground up" is not a place
+        let text = "Built from the ground up. Based in Austin, TX and London.";
+        assert_eq!(
+            extractor.extract_location("Built from the ground up", None, None),
+            None
+        );
+
+        let location = extractor
+            .extract_location(text, Some("https://acme.co.uk"), None)
+            .unwrap();
+        assert_eq!(location.country_code.as_deref(), Some("US"));
+        assert_eq!(location.country.as_deref(), Some("United States"));
+        assert_eq!(location.region.as_deref(), Some("Texas"));
+        assert_eq!(location.city.as_deref(), Some("Austin"));
+        let kinds: Vec<_> = location.evidence.iter().map(|e| e.kind).collect();
+        assert_eq!(kinds, vec![EvidenceKind::TextMention, EvidenceKind::CcTld]);
+        assert_eq!(location.evidence[1].country_code, "GB");
+
+        // "based in the ..." before country names taking an article
+        for (text, code) in [
+            ("Proudly based in the UK", "GB"),
+            ("We are based in the Netherlands.", "NL"),
+            ("Founded in 2019 and based in the US", "US"),
+        ] {
+            let location = extractor.extract_location(text, None, None);
+            assert_eq!(
+                location
+                    .and_then(|location| location.country_code)
+                    .as_deref(),
+                Some(code),
+                "{}",
+                text
+            );
+        }
+
+        // Generic-use ccTLDs say nothing about location
+        assert_eq!(
+            extractor.extract_location("", Some("https://acme.io"), None),
+            None
+        );
+
+        // Cleaned text keeps accented place names intact
+        let text = extractor.clean_text("🚀 Fintech startup\n  Based in Z&uuml;rich");
+        assert_eq!(text, "Fintech startup Based in Zürich");
+        let location = extractor.extract_location(&text, None, None).unwrap();
+        assert_eq!(location.country_code.as_deref(), Some("CH"));
+        assert_eq!(location.city.as_deref(), Some("Zurich"));
+    }
+
+    #[test]
+    fn test_localized_patterns_follow_language() {
+        let config: crate::config::Config =
+            serde_yaml::from_str(include_str!("../config/scraper.yaml"))
+                .expect("shipped config is valid");
+        let extractor = DataExtractor::new(&config.patterns, reqwest::Client::new(), None)
+            .expect("Failed to create extractor");
+
+        // German phrases only apply to German text
+        let text = "Softwarehaus mit Sitz in München";
+        assert_eq!(extractor.extract_location(text, None, Some("en")), None);
+        let location = extractor.extract_location(text, None, Some("de")).unwrap();
+        assert_eq!(location.city.as_deref(), Some("Munich"));
+        assert_eq!(
+            extractor.contact_pages(Some("de")),
+            ["/kontakt", "/impressum"]
+        );
+        assert!(extractor.contact_pages(None).is_empty());
+
+        // Page language is detected, then drives location and phone labels
+        let pages = [
+            (
+                "東京を拠点とするスタートアップです。お問い合わせ 電話：03-1234-5678",
+                "ja",
+                "JP",
+                "+81312345678",
+            ),
+            (
+                "서울에 본사를 둔 핀테크 스타트업입니다. 대표번호: 02-123-4567",
+                "ko",
+                "KR",
+                "+8221234567",
+            ),
+            (
+                "Nous sommes une jeune entreprise basée à Lyon. Tél : 04 72 00 00 00",
+                "fr",
+                "FR",
+                "+33472000000",
+            ),
+        ];
+        for (text, language, country, phone) in pages {
+            let html = format!("<html><body><p>{}</p></body></html>", text);
+            let mut lead = Lead::new("Acme".to_string(), crate::models::Source::BetaList);
+            extractor.apply_contact_details(&mut lead, text, &html);
+            assert_eq!(lead.language.as_deref(), Some(language), "{}", text);
+            assert_eq!(lead.country_code(), Some(country), "{}", text);
+            let phones: Vec<&str> = lead.phones.iter().map(|p| p.e164.as_str()).collect();
+            assert_eq!(phones, vec![phone], "{}", text);
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::errors::{Result, ScrapingError};
+use crate::models::{EmailStatus, Lead, Source, WebsiteState};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Local parts of shared mailboxes; `sales+eu@` and `support.de@` count too.
+/// Default of `role_mailboxes` in the scoring model.
+pub const ROLE_MAILBOXES: &[&str] = &[
+    "info",
+    "hello",
+    "hi",
+    "contact",
+    "team",
+    "sales",
+    "support",
+    "help",
+    "admin",
+    "office",
+    "press",
+    "media",
+    "jobs",
+    "careers",
+    "billing",
+    "marketing",
+    "mail",
+    "noreply",
+    "no-reply",
+    "security",
+    "legal",
+    "privacy",
+    "partners",
+];
+
+pub fn default_role_mailboxes() -> Vec<String> {
+    ROLE_MAILBOXES
+        .iter()
+        .map(|mailbox| mailbox.to_string())
+        .collect()
+}
+
+/// Whether an address is a shared mailbox (`info@`, `sales+eu@`) rather
+/// than a person's own
+pub fn is_role_address(email: &str) -> bool {
+    is_role_address_in(email, ROLE_MAILBOXES)
+}
+
+/// Like [`is_role_address`], against a configured list of mailboxes
+pub fn is_role_address_in<S: AsRef<str>>(email: &str, role_mailboxes: &[S]) -> bool {
+    let local = email.split('@').next().unwrap_or_default().to_lowercase();
+    let mailbox = local.split(['+', '.', '-', '_']).next().unwrap_or_default();
+    role_mailboxes.iter().any(|role| {
+        let role = role.as_ref();
+        role.eq_ignore_ascii_case(&local) || role.eq_ignore_ascii_case(mailbox)
+    })
+}
+
+/// Conditions over lead attributes, as written under `when:` in the scoring
+/// model and segment definitions. All set conditions must hold; list
+/// conditions match when any listed value does.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LeadFilter {
+    pub has: Vec<Attribute>,
+    pub missing: Vec<Attribute>,
+    pub source: Vec<SourceKind>,
+    /// ISO 3166-1 alpha-2 codes
+    pub country: Vec<String>,
+    /// ISO 639-1 codes
+    pub language: Vec<String>,
+    /// Industry tag names from the taxonomy
+    pub industry: Vec<String>,
+    pub technology: Vec<String>,
+    /// Verdict of the best email (direct, else commit email)
+    pub email_status: Vec<EmailStatus>,
+    pub email_kind: Vec<EmailKind>,
+    pub website_state: Vec<WebsiteState>,
+    pub stars: Option<Range>,
+    pub followers: Option<Range>,
+    pub people: Option<Range>,
+    /// Last push to the repository within this many days
+    pub pushed_within_days: Option<i64>,
+    /// Repository not archived and pushed to within this many days
+    pub active_within_days: Option<i64>,
+    /// Repository archived (read-only) on its forge
+    pub archived: Option<bool>,
+    /// Shared mailboxes behind `email_kind`, from the scoring model;
+    /// `ROLE_MAILBOXES` when unset
+    #[serde(skip)]
+    pub role_mailboxes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Attribute {
+    Email,
+    GithubEmail,
+    Linkedin,
+    Twitter,
+    Website,
+    Repository,
+    Phone,
+    Address,
+    Location,
+    People,
+    Description,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceKind {
+    Ycombinator,
+    GithubAwesome,
+    Betalist,
+    GithubSearch,
+    PackageRegistry,
+    Website,
+}
+
+impl SourceKind {
+    pub fn of(source: &Source) -> Self {
+        match source {
+            Source::YCombinator => SourceKind::Ycombinator,
+            Source::GitHubAwesome { .. } => SourceKind::GithubAwesome,
+            Source::BetaList => SourceKind::Betalist,
+            Source::GitHubSearch { .. } => SourceKind::GithubSearch,
+            Source::PackageRegistry { .. } => SourceKind::PackageRegistry,
+            Source::Website { .. } => SourceKind::Website,
+        }
+    }
+}
+
+impl std::str::FromStr for SourceKind {
+    type Err = ScrapingError;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.to_lowercase().replace('-', "_").as_str() {
+            "ycombinator" | "yc" => Ok(SourceKind::Ycombinator),
+            "github_awesome" | "awesome" => Ok(SourceKind::GithubAwesome),
+            "betalist" => Ok(SourceKind::Betalist),
+            "github_search" => Ok(SourceKind::GithubSearch),
+            "package_registry" | "package_registries" | "registries" => {
+                Ok(SourceKind::PackageRegistry)
+            }
+            "website" => Ok(SourceKind::Website),
+            _ => Err(ScrapingError::ConfigError(format!(
+                "Unknown source '{}' (expected ycombinator, github_awesome, betalist, github_search or package_registry)",
+                name
+            ))),
+        }
+    }
+}
+
+/// Shared mailbox (`info@`, `sales@`) or a person's own address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailKind {
+    Role,
+    Personal,
+}
+
+/// Inclusive bounds; either may be left out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Range {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+impl Range {
+    pub fn contains(&self, value: Option<f64>) -> bool {
+        value.is_some_and(|value| {
+            self.min.is_none_or(|min| value >= min) && self.max.is_none_or(|max| value <= max)
+        })
+    }
+}
+
+impl LeadFilter {
+    /// Use these shared mailboxes for `email_kind`
+    pub fn with_role_mailboxes(mut self, role_mailboxes: &[String]) -> Self {
+        self.role_mailboxes = Some(role_mailboxes.to_vec());
+        self
+    }
+
+    /// Whether a lead meets every condition of the filter
+    pub fn matches(&self, lead: &Lead) -> bool {
+        let now = Utc::now();
+        let any_of = |wanted: &[String], value: Option<&str>| {
+            wanted.is_empty()
+                || value.is_some_and(|value| wanted.iter().any(|w| w.eq_ignore_ascii_case(value)))
+        };
+        let email = lead.email.as_deref().or(lead.github_email.as_deref());
+        let health = lead.repo_health.as_ref();
+
+        self.has.iter().all(|attribute| has(lead, *attribute))
+            && !self.missing.iter().any(|attribute| has(lead, *attribute))
+            && (self.source.is_empty() || self.source.contains(&SourceKind::of(&lead.source)))
+            && any_of(&self.country, lead.country_code())
+            && any_of(&self.language, lead.language.as_deref())
+            && (self.industry.is_empty()
+                || lead
+                    .industries
+                    .iter()
+                    .any(|industry| any_of(&self.industry, Some(&industry.name))))
+            && (self.technology.is_empty()
+                || self
+                    .technology
+                    .iter()
+                    .any(|technology| lead.uses_technology(technology)))
+            && (self.email_status.is_empty()
+                || email
+                    .and_then(|email| lead.email_status(email))
+                    .is_some_and(|status| self.email_status.contains(&status)))
+            && (self.email_kind.is_empty()
+                || email.is_some_and(|email| {
+                    let role = match self.role_mailboxes {
+                        Some(ref mailboxes) => is_role_address_in(email, mailboxes),
+                        None => is_role_address(email),
+                    };
+                    let kind = if role {
+                        EmailKind::Role
+                    } else {
+                        EmailKind::Personal
+                    };
+                    self.email_kind.contains(&kind)
+                }))
+            && (self.website_state.is_empty()
+                || lead
+                    .website_status
+                    .as_ref()
+                    .is_some_and(|status| self.website_state.contains(&status.state)))
+            && self
+                .stars
+                .is_none_or(|range| range.contains(health.map(|h| h.stars as f64)))
+            && self
+                .followers
+                .is_none_or(|range| range.contains(lead.owner_followers.map(f64::from)))
+            && self
+                .people
+                .is_none_or(|range| range.contains(Some(lead.people.len() as f64)))
+            && self.pushed_within_days.is_none_or(|days| {
+                health
+                    .and_then(|h| h.pushed_at)
+                    .is_some_and(|pushed_at| (now - pushed_at).num_days() <= days)
+            })
+            && self
+                .active_within_days
+                .is_none_or(|days| health.is_some_and(|h| h.is_active(days, now)))
+            && self
+                .archived
+                .is_none_or(|archived| health.is_some_and(|h| h.archived == archived))
+    }
+}
+
+fn has(lead: &Lead, attribute: Attribute) -> bool {
+    match attribute {
+        Attribute::Email => lead.email.is_some(),
+        Attribute::GithubEmail => lead.github_email.is_some(),
+        Attribute::Linkedin => lead.linkedin.is_some(),
+        Attribute::Twitter => lead.twitter.is_some(),
+        Attribute::Website => lead.website.is_some(),
+        Attribute::Repository => lead.repository.is_some(),
+        Attribute::Phone => !lead.phones.is_empty(),
+        Attribute::Address => lead.address.is_some(),
+        Attribute::Location => lead.country_code().is_some(),
+        Attribute::People => !lead.people.is_empty(),
+        Attribute::Description => lead
+            .description
+            .as_deref()
+            .is_some_and(|description| !description.trim().is_empty()),
+    }
+}
@@ -0,0 +1,206 @@
+use super::{CommitPerson, Forge, ForgeCommit, RepoInfo, RepoRef};
+use crate::config::ForgeConfig;
+use crate::errors::{Result, ScrapingError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::debug;
+
+// Gitea/Forgejo API v1 structures (GitHub-compatible shapes)
+#[derive(Debug, Deserialize)]
+struct GiteaRepo {
+    fork: bool,
+    default_branch: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaCommit {
+    commit: CommitDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitDetails {
+    author: CommitAuthor,
+    committer: CommitAuthor,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitAuthor {
+    name: Option<String>,
+    email: String,
+    date: Option<DateTime<Utc>>,
+}
+
+impl From<CommitAuthor> for CommitPerson {
+    fn from(author: CommitAuthor) -> Self {
+        Self {
+            name: author.name,
+            email: author.email,
+            date: author.date,
+        }
+    }
+}
+
+/// Gitea / Forgejo (e.g. Codeberg) REST API v1 client
+pub struct GiteaForge {
+    client: Client,
+    config: ForgeConfig,
+}
+
+impl GiteaForge {
+    pub fn new(client: Client, config: ForgeConfig) -> Self {
+        Self { client, config }
+    }
+
+    fn auth(&self) -> Option<(&'static str, String)> {
+        self.config
+            .token
+            .as_ref()
+            .map(|token| ("Authorization", format!("token {}", token)))
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = format!("{}{}", self.config.api_base, path);
+        super::get_json(&self.client, &url, self.auth()).await
+    }
+}
+
+#[async_trait]
+impl Forge for GiteaForge {
+    fn name(&self) -> &'static str {
+        "Gitea"
+    }
+
+    fn web_base(&self) -> &str {
+        &self.config.web_base
+    }
+
+    fn file_web_url(&self, repo: &RepoRef, branch: &str, path: &str) -> String {
+        format!("{}/src/branch/{}/{}", self.repo_web_url(repo), branch, path)
+    }
+
+    async fn fetch_readme(&self, repo: &RepoRef) -> Result<String> {
+        // No README endpoint on older Gitea releases: try the usual file names
+        for file in ["README.md", "readme.md", "README.markdown", "README"] {
+            match self.fetch_file(repo, "", file).await {
+                Ok(content) => return Ok(content),
+                Err(e) => debug!("README candidate {} not found: {}", file, e),
+            }
+        }
+
+        Err(ScrapingError::NetworkError(format!(
+            "No README found for {} repository {}",
+            self.name(),
+            repo.path
+        )))
+    }
+
+    /// An empty `branch` reads from the default branch
+    async fn fetch_file(&self, repo: &RepoRef, branch: &str, path: &str) -> Result<String> {
+        let mut url = format!("{}/repos/{}/raw/{}", self.config.api_base, repo.path, path);
+        if !branch.is_empty() {
+            url.push_str(&format!("?ref={}", branch));
+        }
+        let response = super::get(&self.client, &url, self.auth()).await?;
+        response
+            .text()
+            .await
+            .map_err(|e| ScrapingError::NetworkError(format!("Failed to read '{}': {}", url, e)))
+    }
+
+    async fn repo_info(&self, repo: &RepoRef) -> Result<RepoInfo> {
+        let info: GiteaRepo = self.get_json(&format!("/repos/{}", repo.path)).await?;
+        Ok(RepoInfo {
+            fork: info.fork,
+            default_branch: info.default_branch,
+            health: None,
+        })
+    }
+
+    async fn list_files(
+        &self,
+        repo: &RepoRef,
+        branch: &str,
+        dir: &str,
+    ) -> Result<Option<Vec<String>>> {
+        let entries: Vec<GiteaEntry> = self
+            .get_json(&super::contents_route(repo, branch, dir))
+            .await?;
+        Ok(Some(
+            entries
+                .into_iter()
+                .map(|entry| match entry.kind.as_str() {
+                    "dir" => format!("{}/", entry.path),
+                    _ => entry.path,
+                })
+                .collect(),
+        ))
+    }
+
+    async fn commits(
+        &self,
+        repo: &RepoRef,
+        branch: &str,
+        page: u32,
+        per_page: usize,
+    ) -> Result<Vec<ForgeCommit>> {
+        let commits: Vec<GiteaCommit> = self
+            .get_json(&format!(
+                "/repos/{}/commits?sha={}&limit={}&page={}",
+                repo.path, branch, per_page, page
+            ))
+            .await?;
+        Ok(commits
+            .into_iter()
+            .map(|commit| ForgeCommit {
+                author: commit.commit.author.into(),
+                committer: Some(commit.commit.committer.into()),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forges::test_server;
+
+    #[tokio::test]
+    async fn test_gitea_against_local_stand_in() {
+        let base = test_server::serve(vec![
+            ("/repos/acme/tool/raw/README.md", "# Tool".to_string()),
+            (
+                "/repos/acme/tool/commits?sha=main",
+                r#"[{"commit": {
+                    "author": {"name": "Jane", "email": "jane@acme.dev", "date": "2025-05-01T10:00:00Z"},
+                    "committer": {"name": "Jane", "email": "jane@acme.dev", "date": "2025-05-01T10:00:00Z"}
+                }}]"#
+                    .to_string(),
+            ),
+            (
+                "/repos/acme/tool",
+                r#"{"fork": true, "default_branch": "main"}"#.to_string(),
+            ),
+        ])
+        .await;
+
+        let forge = GiteaForge::new(Client::new(), ForgeConfig::new(&base, &base));
+        let repo = forge
+            .parse_repo_url(&format!("{}/acme/tool", base))
+            .expect("repository URL");
+
+        assert_eq!(forge.fetch_readme(&repo).await.unwrap(), "# Tool");
+        assert!(forge.repo_info(&repo).await.unwrap().fork);
+
+        let commits = forge.commits(&repo, "main", 1, 15).await.unwrap();
+        assert_eq!(commits[0].author.email, "jane@acme.dev");
+    }
+}
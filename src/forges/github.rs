@@ -0,0 +1,389 @@
+use super::{CommitPerson, Forge, ForgeCommit, OwnerProfile, RepoInfo, RepoRef};
+use crate::config::ForgeConfig;
+use crate::errors::{Result, ScrapingError};
+use crate::models::RepoHealth;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing::debug;
+use url::Url;
+
+// GitHub API structures
+#[derive(Debug, Deserialize)]
+struct GitHubContent {
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRepo {
+    fork: bool,
+    default_branch: String,
+    #[serde(default)]
+    stargazers_count: u32,
+    #[serde(default)]
+    forks_count: u32,
+    #[serde(default)]
+    open_issues_count: u32,
+    #[serde(default)]
+    archived: bool,
+    pushed_at: Option<DateTime<Utc>>,
+    license: Option<GitHubLicense>,
+    #[serde(default)]
+    topics: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubEntry {
+    path: String,
+    #[serde(rename = "type")]
+    kind: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubLicense {
+    spdx_id: Option<String>,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    #[serde(default)]
+    draft: bool,
+    published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubCommit {
+    commit: CommitDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitDetails {
+    author: CommitAuthor,
+    committer: CommitAuthor,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitAuthor {
+    name: Option<String>,
+    email: String,
+    date: Option<DateTime<Utc>>,
+}
+
+impl From<CommitAuthor> for CommitPerson {
+    fn from(author: CommitAuthor) -> Self {
+        Self {
+            name: author.name,
+            email: author.email,
+            date: author.date,
+        }
+    }
+}
+
+impl From<GitHubCommit> for ForgeCommit {
+    fn from(commit: GitHubCommit) -> Self {
+        Self {
+            author: commit.commit.author.into(),
+            committer: Some(commit.commit.committer.into()),
+        }
+    }
+}
+
+/// GitHub (or GitHub Enterprise) REST API v3 client
+pub struct GitHubForge {
+    client: Client,
+    config: ForgeConfig,
+}
+
+impl GitHubForge {
+    pub fn new(client: Client, config: ForgeConfig) -> Self {
+        Self { client, config }
+    }
+
+    fn auth(&self) -> Option<(&'static str, String)> {
+        self.config
+            .token
+            .as_ref()
+            .map(|token| ("Authorization", format!("token {}", token)))
+    }
+
+    /// Authenticated GET against the GitHub API, relative to `api_base`
+    pub async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = format!("{}{}", self.config.api_base, path);
+        super::get_json(&self.client, &url, self.auth()).await
+    }
+
+    /// Number of contributors (anonymous ones included)
+    async fn contributor_count(&self, repo: &RepoRef) -> Result<u32> {
+        let url = format!(
+            "{}/repos/{}/contributors?per_page=1&anon=1",
+            self.config.api_base, repo.path
+        );
+        let response = super::get(&self.client, &url, self.auth()).await?;
+
+        // With one contributor per page, the last page number is the total
+        let last_page = response
+            .headers()
+            .get("link")
+            .and_then(|value| value.to_str().ok())
+            .and_then(Self::last_page);
+        if let Some(last_page) = last_page {
+            return Ok(last_page);
+        }
+
+        let contributors: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| ScrapingError::ParseError(format!("Failed to parse '{}': {}", url, e)))?;
+        Ok(contributors.len() as u32)
+    }
+
+    /// Page number of the `rel="last"` entry of a `Link` header
+    fn last_page(link_header: &str) -> Option<u32> {
+        link_header
+            .split(',')
+            .find(|link| link.contains("rel=\"last\""))
+            .and_then(|link| link.split(['<', '>']).nth(1))
+            .and_then(|url| Url::parse(url).ok())
+            .and_then(|url| {
+                url.query_pairs()
+                    .find(|(key, _)| key == "page")
+                    .and_then(|(_, page)| page.parse().ok())
+            })
+    }
+
+    /// Publication dates of the most recent non-draft releases
+    async fn release_dates(&self, repo: &RepoRef) -> Result<Vec<DateTime<Utc>>> {
+        let releases: Vec<GitHubRelease> = self
+            .get_json(&format!("/repos/{}/releases?per_page=30", repo.path))
+            .await?;
+        Ok(releases
+            .into_iter()
+            .filter(|release| !release.draft)
+            .filter_map(|release| release.published_at)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl Forge for GitHubForge {
+    fn name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    fn web_base(&self) -> &str {
+        &self.config.web_base
+    }
+
+    async fn fetch_readme(&self, repo: &RepoRef) -> Result<String> {
+        let content: GitHubContent = self
+            .get_json(&format!("/repos/{}/readme", repo.path))
+            .await?;
+        super::decode_base64_content(&content.content)
+    }
+
+    async fn repo_info(&self, repo: &RepoRef) -> Result<RepoInfo> {
+        let info: GitHubRepo = self.get_json(&format!("/repos/{}", repo.path)).await?;
+        let health = RepoHealth {
+            stars: info.stargazers_count,
+            forks: info.forks_count,
+            open_issues: info.open_issues_count,
+            archived: info.archived,
+            pushed_at: info.pushed_at,
+            license: info.license.and_then(|license| {
+                license
+                    .spdx_id
+                    .filter(|id| id != "NOASSERTION")
+                    .or(license.name)
+            }),
+            topics: info.topics,
+            ..RepoHealth::default()
+        };
+        Ok(RepoInfo {
+            fork: info.fork,
+            default_branch: info.default_branch,
+            health: Some(health),
+        })
+    }
+
+    async fn fetch_file(&self, repo: &RepoRef, branch: &str, path: &str) -> Result<String> {
+        let content: GitHubContent = self
+            .get_json(&format!(
+                "/repos/{}/contents/{}?ref={}",
+                repo.path, path, branch
+            ))
+            .await?;
+        super::decode_base64_content(&content.content)
+    }
+
+    async fn commits(
+        &self,
+        repo: &RepoRef,
+        branch: &str,
+        page: u32,
+        per_page: usize,
+    ) -> Result<Vec<ForgeCommit>> {
+        let commits: Vec<GitHubCommit> = self
+            .get_json(&format!(
+                "/repos/{}/commits?sha={}&per_page={}&page={}",
+                repo.path, branch, per_page, page
+            ))
+            .await?;
+        Ok(commits.into_iter().map(ForgeCommit::from).collect())
+    }
+
+    async fn repo_health(&self, repo: &RepoRef, info: &RepoInfo) -> Result<Option<RepoHealth>> {
+        let Some(mut health) = info.health.clone() else {
+            return Ok(None);
+        };
+
+        // Secondary signals are best effort
+        match self.contributor_count(repo).await {
+            Ok(count) => health.contributors = Some(count),
+            Err(e) => debug!("Failed to count contributors of {}: {}", repo.path, e),
+        }
+        match self.release_dates(repo).await {
+            Ok(dates) => health.record_releases(dates, Utc::now()),
+            Err(e) => debug!("Failed to fetch releases of {}: {}", repo.path, e),
+        }
+
+        Ok(Some(health))
+    }
+
+    async fn list_files(
+        &self,
+        repo: &RepoRef,
+        branch: &str,
+        dir: &str,
+    ) -> Result<Option<Vec<String>>> {
+        let entries: Vec<GitHubEntry> = self
+            .get_json(&super::contents_route(repo, branch, dir))
+            .await?;
+        Ok(Some(
+            entries
+                .into_iter()
+                .map(|entry| match entry.kind.as_str() {
+                    "dir" => format!("{}/", entry.path),
+                    _ => entry.path,
+                })
+                .collect(),
+        ))
+    }
+
+    async fn owner_profile(&self, owner: &str) -> Result<Option<OwnerProfile>> {
+        // Same endpoint for users and organizations
+        self.get_json(&format!("/users/{}", owner)).await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forges::test_server;
+
+    #[tokio::test]
+    async fn test_github_against_local_stand_in() {
+        let base = test_server::serve(vec![
+            (
+                "/repos/acme/tool/readme",
+                r#"{"content": "IyBBY21l\nVG9vbA=="}"#.to_string(),
+            ),
+            (
+                "/repos/acme/tool/commits?sha=main",
+                r#"[{"commit": {
+                    "author": {"name": "Jane", "email": "jane@acme.dev", "date": "2025-05-01T10:00:00Z"},
+                    "committer": {"name": "GitHub", "email": "noreply@github.com", "date": "2025-05-01T10:00:00Z"}
+                }}]"#
+                    .to_string(),
+            ),
+            (
+                "/repos/acme/tool/contents/.mailmap?ref=main",
+                r#"{"content": "SmFuZSA8amFuZUBhY21lLmRldj4K"}"#.to_string(),
+            ),
+            (
+                "/repos/acme/tool/contents?ref=main",
+                r#"[{"path": "package.json", "type": "file"}, {"path": ".github", "type": "dir"}]"#
+                    .to_string(),
+            ),
+            (
+                "/repos/acme/tool/contributors",
+                r#"[{"login": "jane"}, {"login": "joe"}]"#.to_string(),
+            ),
+            (
+                "/repos/acme/tool/releases",
+                r#"[{"draft": false, "published_at": "2025-05-01T00:00:00Z"},
+                    {"draft": true, "published_at": null},
+                    {"draft": false, "published_at": "2025-03-02T00:00:00Z"}]"#
+                    .to_string(),
+            ),
+            (
+                "/repos/acme/tool",
+                r#"{"fork": false, "default_branch": "main", "stargazers_count": 1200,
+                    "forks_count": 80, "open_issues_count": 14, "archived": false,
+                    "pushed_at": "2025-05-02T08:00:00Z", "topics": ["cli", "rust"],
+                    "license": {"spdx_id": "MIT", "name": "MIT License"}}"#
+                    .to_string(),
+            ),
+            (
+                "/users/acme",
+                r#"{"login": "acme", "html_url": "https://github.com/acme",
+                    "company": "Acme Inc", "public_repos": 12, "followers": 340}"#
+                    .to_string(),
+            ),
+        ])
+        .await;
+
+        let forge = GitHubForge::new(Client::new(), ForgeConfig::new(&base, &base));
+        let repo = forge
+            .parse_repo_url(&format!("{}/acme/tool", base))
+            .expect("repository URL");
+
+        assert_eq!(forge.fetch_readme(&repo).await.unwrap(), "# AcmeTool");
+
+        let info = forge.repo_info(&repo).await.unwrap();
+        assert!(!info.fork);
+        assert_eq!(info.default_branch, "main");
+        assert_eq!(info.health.as_ref().map(|health| health.stars), Some(1200));
+
+        let commits = forge.commits(&repo, "main", 1, 15).await.unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(commits[0].author.email, "jane@acme.dev");
+        assert_eq!(commits[0].author.name.as_deref(), Some("Jane"));
+
+        assert_eq!(
+            forge.fetch_file(&repo, "main", ".mailmap").await.unwrap(),
+            "Jane <jane@acme.dev>\n"
+        );
+
+        let health = forge.repo_health(&repo, &info).await.unwrap().unwrap();
+        assert_eq!(health.stars, 1200);
+        assert_eq!(health.license.as_deref(), Some("MIT"));
+        assert_eq!(health.topics, vec!["cli", "rust"]);
+        assert_eq!(health.contributors, Some(2));
+        assert_eq!(health.release_interval_days, Some(60.0));
+        assert_eq!(
+            health.last_release_at,
+            Some("2025-05-01T00:00:00Z".parse().unwrap())
+        );
+
+        assert_eq!(
+            forge.list_files(&repo, "main", "").await.unwrap(),
+            Some(vec!["package.json".to_string(), ".github/".to_string()])
+        );
+
+        let profile = forge.owner_profile(repo.owner()).await.unwrap().unwrap();
+        assert_eq!(profile.company.as_deref(), Some("Acme Inc"));
+        assert_eq!(profile.followers, Some(340));
+    }
+
+    #[test]
+    fn test_last_page_from_link_header() {
+        let header = r#"<https://api.github.com/repositories/1/contributors?per_page=1&anon=1&page=2>; rel="next", <https://api.github.com/repositories/1/contributors?per_page=1&anon=1&page=57>; rel="last""#;
+        assert_eq!(GitHubForge::last_page(header), Some(57));
+        assert_eq!(
+            GitHubForge::last_page(r#"<https://x/?page=2>; rel="next""#),
+            None
+        );
+    }
+}
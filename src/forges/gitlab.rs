@@ -0,0 +1,226 @@
+use super::{CommitPerson, Forge, ForgeCommit, RepoInfo, RepoRef};
+use crate::config::ForgeConfig;
+use crate::errors::{Result, ScrapingError};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize};
+use url::Url;
+
+// GitLab API v4 structures
+#[derive(Debug, Deserialize)]
+struct GitLabProject {
+    default_branch: Option<String>,
+    readme_url: Option<String>,
+    forked_from_project: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabCommit {
+    author_name: Option<String>,
+    author_email: String,
+    authored_date: Option<DateTime<Utc>>,
+    committer_name: Option<String>,
+    committer_email: Option<String>,
+    committed_date: Option<DateTime<Utc>>,
+}
+
+impl From<GitLabCommit> for ForgeCommit {
+    fn from(commit: GitLabCommit) -> Self {
+        Self {
+            author: CommitPerson {
+                name: commit.author_name,
+                email: commit.author_email,
+                date: commit.authored_date,
+            },
+            committer: commit.committer_email.map(|email| CommitPerson {
+                name: commit.committer_name,
+                email,
+                date: commit.committed_date,
+            }),
+        }
+    }
+}
+
+/// GitLab (gitlab.com or self-hosted) REST API v4 client
+pub struct GitLabForge {
+    client: Client,
+    config: ForgeConfig,
+}
+
+impl GitLabForge {
+    pub fn new(client: Client, config: ForgeConfig) -> Self {
+        Self { client, config }
+    }
+
+    fn auth(&self) -> Option<(&'static str, String)> {
+        self.config
+            .token
+            .as_ref()
+            .map(|token| ("PRIVATE-TOKEN", token.clone()))
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let url = format!("{}{}", self.config.api_base, path);
+        super::get_json(&self.client, &url, self.auth()).await
+    }
+
+    /// URL-encoded project path, used as the project id in API routes
+    fn project_id(repo: &RepoRef) -> String {
+        repo.path.replace('/', "%2F")
+    }
+
+    async fn project(&self, repo: &RepoRef) -> Result<GitLabProject> {
+        self.get_json(&format!("/projects/{}", Self::project_id(repo)))
+            .await
+    }
+
+    /// Repository file path of the README, from `.../-/blob/<branch>/<path>`
+    fn readme_path(readme_url: &str, branch: &str) -> Option<String> {
+        let marker = format!("/-/blob/{}/", branch);
+        readme_url
+            .find(&marker)
+            .map(|index| readme_url[index + marker.len()..].to_string())
+    }
+}
+
+#[async_trait]
+impl Forge for GitLabForge {
+    fn name(&self) -> &'static str {
+        "GitLab"
+    }
+
+    fn web_base(&self) -> &str {
+        &self.config.web_base
+    }
+
+    fn file_web_url(&self, repo: &RepoRef, branch: &str, path: &str) -> String {
+        format!("{}/-/blob/{}/{}", self.repo_web_url(repo), branch, path)
+    }
+
+    /// GitLab supports nested groups, so the project path runs until `/-/`
+    fn parse_repo_url(&self, url: &str) -> Option<RepoRef> {
+        let parsed = Url::parse(url).ok()?;
+        let base = Url::parse(self.web_base()).ok()?;
+        if parsed.host_str()? != base.host_str()? || parsed.port() != base.port() {
+            return None;
+        }
+
+        let segments: Vec<&str> = parsed
+            .path_segments()?
+            .filter(|segment| !segment.is_empty())
+            .take_while(|segment| *segment != "-")
+            .collect();
+        if segments.len() < 2 {
+            return None;
+        }
+
+        Some(RepoRef::new(&segments.join("/")))
+    }
+
+    async fn fetch_readme(&self, repo: &RepoRef) -> Result<String> {
+        let project = self.project(repo).await?;
+        let branch = project.default_branch.unwrap_or_else(|| "main".to_string());
+        let path = project
+            .readme_url
+            .as_deref()
+            .and_then(|readme_url| Self::readme_path(readme_url, &branch))
+            .ok_or_else(|| {
+                ScrapingError::ParseError(format!(
+                    "No README found for GitLab project {}",
+                    repo.path
+                ))
+            })?;
+
+        self.fetch_file(repo, &branch, &path).await
+    }
+
+    async fn fetch_file(&self, repo: &RepoRef, branch: &str, path: &str) -> Result<String> {
+        let url = format!(
+            "{}/projects/{}/repository/files/{}/raw?ref={}",
+            self.config.api_base,
+            Self::project_id(repo),
+            path.replace('/', "%2F"),
+            branch
+        );
+        let response = super::get(&self.client, &url, self.auth()).await?;
+        response
+            .text()
+            .await
+            .map_err(|e| ScrapingError::NetworkError(format!("Failed to read '{}': {}", url, e)))
+    }
+
+    async fn repo_info(&self, repo: &RepoRef) -> Result<RepoInfo> {
+        let project = self.project(repo).await?;
+        Ok(RepoInfo {
+            fork: project.forked_from_project.is_some_and(|p| !p.is_null()),
+            default_branch: project.default_branch.unwrap_or_else(|| "main".to_string()),
+            health: None,
+        })
+    }
+
+    async fn commits(
+        &self,
+        repo: &RepoRef,
+        branch: &str,
+        page: u32,
+        per_page: usize,
+    ) -> Result<Vec<ForgeCommit>> {
+        let commits: Vec<GitLabCommit> = self
+            .get_json(&format!(
+                "/projects/{}/repository/commits?ref_name={}&per_page={}&page={}",
+                Self::project_id(repo),
+                branch,
+                per_page,
+                page
+            ))
+            .await?;
+        Ok(commits.into_iter().map(ForgeCommit::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forges::test_server;
+
+    #[tokio::test]
+    async fn test_gitlab_against_local_stand_in() {
+        let base = test_server::serve(vec![
+            (
+                "/projects/group%2Fsub%2Ftool/repository/files/README.md/raw?ref=main",
+                "# Tool".to_string(),
+            ),
+            (
+                "/projects/group%2Fsub%2Ftool/repository/commits?ref_name=main",
+                r#"[{"author_name": "Jane", "author_email": "jane@acme.dev",
+                     "authored_date": "2025-05-01T10:00:00Z",
+                     "committer_name": "Jane", "committer_email": "jane@acme.dev",
+                     "committed_date": "2025-05-01T10:00:00Z"}]"#
+                    .to_string(),
+            ),
+            (
+                "/projects/group%2Fsub%2Ftool",
+                r#"{"default_branch": "main", "forked_from_project": null,
+                    "readme_url": "https://gitlab.example/group/sub/tool/-/blob/main/README.md"}"#
+                    .to_string(),
+            ),
+        ])
+        .await;
+
+        let forge = GitLabForge::new(Client::new(), ForgeConfig::new(&base, &base));
+        let repo = forge
+            .parse_repo_url(&format!("{}/group/sub/tool/-/tree/main", base))
+            .expect("repository URL");
+        assert_eq!(repo.path, "group/sub/tool");
+
+        assert_eq!(forge.fetch_readme(&repo).await.unwrap(), "# Tool");
+
+        let info = forge.repo_info(&repo).await.unwrap();
+        assert!(!info.fork);
+        assert_eq!(info.default_branch, "main");
+
+        let commits = forge.commits(&repo, "main", 1, 15).await.unwrap();
+        assert_eq!(commits[0].author.email, "jane@acme.dev");
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+/// Canonical identity from a `.mailmap` entry
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Canonical {
+    name: Option<String>,
+    email: Option<String>,
+}
+
+/// Parsed git `.mailmap`, mapping commit identities to canonical ones.
+///
+/// Supports the four forms documented in `gitmailmap(5)`:
+///
+/// ```text
+/// Proper Name <commit@email>
+/// <proper@email> <commit@email>
+/// Proper Name <proper@email> <commit@email>
+/// Proper Name <proper@email> Commit Name <commit@email>
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Mailmap {
+    /// Keyed by lowercase commit email and, for the last form, lowercase commit name
+    entries: HashMap<(String, Option<String>), Canonical>,
+}
+
+impl Mailmap {
+    pub fn parse(content: &str) -> Self {
+        let mut entries = HashMap::new();
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            // Alternating name / <email> parts
+            let mut names = Vec::new();
+            let mut emails = Vec::new();
+            let mut rest = line;
+            while let Some(start) = rest.find('<') {
+                let Some(end) = rest[start..].find('>') else {
+                    break;
+                };
+                names.push(Some(rest[..start].trim()).filter(|name| !name.is_empty()));
+                emails.push(rest[start + 1..start + end].trim().to_string());
+                rest = &rest[start + end + 1..];
+            }
+
+            let (canonical, key) = match emails.len() {
+                1 => (
+                    Canonical {
+                        name: names[0].map(str::to_string),
+                        email: None,
+                    },
+                    (emails[0].to_lowercase(), None),
+                ),
+                2 => (
+                    Canonical {
+                        name: names[0].map(str::to_string),
+                        email: Some(emails[0].clone()),
+                    },
+                    (
+                        emails[1].to_lowercase(),
+                        names[1].map(|name| name.to_lowercase()),
+                    ),
+                ),
+                _ => continue,
+            };
+            entries.insert(key, canonical);
+        }
+
+        Self { entries }
+    }
+
+    /// Canonical `(name, email)` for a commit identity
+    pub fn resolve(&self, name: Option<&str>, email: &str) -> (Option<String>, String) {
+        let email_key = email.to_lowercase();
+        let canonical = name
+            .and_then(|name| {
+                self.entries
+                    .get(&(email_key.clone(), Some(name.to_lowercase())))
+            })
+            .or_else(|| self.entries.get(&(email_key, None)));
+
+        match canonical {
+            Some(canonical) => (
+                canonical.name.clone().or_else(|| name.map(str::to_string)),
+                canonical.email.clone().unwrap_or_else(|| email.to_string()),
+            ),
+            None => (name.map(str::to_string), email.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mailmap_forms() {
+        let mailmap = Mailmap::parse(
+            r#"
+# Comment
+Jane Doe <jane@old.example>
+<jane@acme.dev> <jane@laptop.local>
+Joe Smith <joe@acme.dev> <joe@personal.example>
+Joe Smith <joe@acme.dev> joe <root@localhost>
+"#,
+        );
+
+        assert_eq!(
+            mailmap.resolve(Some("jd"), "jane@old.example"),
+            (Some("Jane Doe".to_string()), "jane@old.example".to_string())
+        );
+        assert_eq!(
+            mailmap.resolve(Some("Jane"), "JANE@laptop.local"),
+            (Some("Jane".to_string()), "jane@acme.dev".to_string())
+        );
+        assert_eq!(
+            mailmap.resolve(None, "joe@personal.example"),
+            (Some("Joe Smith".to_string()), "joe@acme.dev".to_string())
+        );
+        assert_eq!(
+            mailmap.resolve(Some("Joe"), "root@localhost").1,
+            "joe@acme.dev"
+        );
+        assert_eq!(
+            mailmap.resolve(Some("someone"), "root@localhost").1,
+            "root@localhost"
+        );
+    }
+}
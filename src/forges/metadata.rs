@@ -0,0 +1,457 @@
+use crate::models::MaintainerContact;
+use regex::Regex;
+use serde_json::Value as JsonValue;
+use serde_yaml::Value as YamlValue;
+use tracing::debug;
+
+/// Repository files that commonly declare maintainers, security contacts or sponsors
+pub const METADATA_FILES: &[&str] = &[
+    "package.json",
+    "Cargo.toml",
+    "pyproject.toml",
+    "CODEOWNERS",
+    ".github/CODEOWNERS",
+    "SECURITY.md",
+    ".github/SECURITY.md",
+    ".github/FUNDING.yml",
+    "CITATION.cff",
+];
+
+/// Contacts and sponsor links found in repository metadata files
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RepoMetadata {
+    pub contacts: Vec<MaintainerContact>,
+    pub sponsor_links: Vec<String>,
+}
+
+impl RepoMetadata {
+    /// Add another file's findings, skipping contacts and links already present
+    pub fn merge(&mut self, other: RepoMetadata) {
+        for contact in other.contacts {
+            let duplicate = self.contacts.iter().any(|known| {
+                (contact.email.is_some() && known.email == contact.email)
+                    || (contact.github.is_some() && known.github == contact.github)
+                    || (contact.email.is_none()
+                        && contact.github.is_none()
+                        && known.name == contact.name)
+            });
+            if !duplicate {
+                self.contacts.push(contact);
+            }
+        }
+        for link in other.sponsor_links {
+            if !self.sponsor_links.contains(&link) {
+                self.sponsor_links.push(link);
+            }
+        }
+    }
+}
+
+/// Parse a metadata file by its repository path
+pub fn parse_metadata_file(path: &str, content: &str) -> RepoMetadata {
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    let metadata = match file_name {
+        "package.json" => parse_package_json(path, content),
+        "Cargo.toml" => parse_cargo_toml(path, content),
+        "pyproject.toml" => parse_pyproject_toml(path, content),
+        "CODEOWNERS" => parse_codeowners(path, content),
+        "SECURITY.md" => parse_security_md(path, content),
+        "FUNDING.yml" => parse_funding_yml(content),
+        "CITATION.cff" => parse_citation_cff(path, content),
+        _ => RepoMetadata::default(),
+    };
+
+    debug!(
+        "{}: {} contacts, {} sponsor links",
+        path,
+        metadata.contacts.len(),
+        metadata.sponsor_links.len()
+    );
+    metadata
+}
+
+/// npm/Cargo/Poetry person string: `Name <email> (url)`
+fn parse_person(text: &str, source_file: &str) -> Option<MaintainerContact> {
+    let between = |open: char, close: char| {
+        let start = text.find(open)?;
+        let end = text[start..].find(close)? + start;
+        Some(text[start + 1..end].trim().to_string()).filter(|value| !value.is_empty())
+    };
+
+    let email = between('<', '>').filter(|email| email.contains('@'));
+    let url = between('(', ')').filter(|url| url.starts_with("http"));
+    let name = text
+        .split(['<', '('])
+        .next()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+
+    (name.is_some() || email.is_some()).then(|| MaintainerContact {
+        name,
+        email,
+        url,
+        source_file: source_file.to_string(),
+        ..MaintainerContact::default()
+    })
+}
+
+fn json_str(value: &JsonValue, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+fn parse_package_json(path: &str, content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+    let Ok(package) = serde_json::from_str::<JsonValue>(content) else {
+        return metadata;
+    };
+
+    // `author` is a single person, `maintainers`/`contributors` are lists
+    let person = |value: &JsonValue| match value {
+        JsonValue::String(text) => parse_person(text, path),
+        JsonValue::Object(_) => Some(MaintainerContact {
+            name: json_str(value, "name"),
+            email: json_str(value, "email"),
+            url: json_str(value, "url"),
+            source_file: path.to_string(),
+            ..MaintainerContact::default()
+        })
+        .filter(|contact| contact.name.is_some() || contact.email.is_some()),
+        _ => None,
+    };
+
+    metadata
+        .contacts
+        .extend(package.get("author").and_then(person));
+    for key in ["maintainers", "contributors"] {
+        if let Some(JsonValue::Array(people)) = package.get(key) {
+            metadata.contacts.extend(people.iter().filter_map(person));
+        }
+    }
+
+    // `funding` is a URL, an object with `url`, or a list of either
+    let funding_url = |value: &JsonValue| match value {
+        JsonValue::String(url) => Some(url.clone()),
+        JsonValue::Object(_) => json_str(value, "url"),
+        _ => None,
+    };
+    match package.get("funding") {
+        Some(JsonValue::Array(entries)) => {
+            metadata
+                .sponsor_links
+                .extend(entries.iter().filter_map(funding_url));
+        }
+        Some(entry) => metadata.sponsor_links.extend(funding_url(entry)),
+        None => {}
+    }
+
+    metadata
+}
+
+fn parse_cargo_toml(path: &str, content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return metadata;
+    };
+
+    let authors = manifest
+        .get("package")
+        .and_then(|package| package.get("authors"))
+        .and_then(toml::Value::as_array);
+    for author in authors.into_iter().flatten() {
+        metadata
+            .contacts
+            .extend(author.as_str().and_then(|text| parse_person(text, path)));
+    }
+
+    metadata
+}
+
+fn parse_pyproject_toml(path: &str, content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+    let Ok(pyproject) = content.parse::<toml::Table>() else {
+        return metadata;
+    };
+
+    // PEP 621: tables with `name`/`email`
+    if let Some(project) = pyproject.get("project") {
+        for key in ["authors", "maintainers"] {
+            let people = project.get(key).and_then(toml::Value::as_array);
+            for person in people.into_iter().flatten() {
+                let field = |name: &str| {
+                    person
+                        .get(name)
+                        .and_then(toml::Value::as_str)
+                        .map(str::to_string)
+                };
+                let contact = MaintainerContact {
+                    name: field("name"),
+                    email: field("email"),
+                    source_file: path.to_string(),
+                    ..MaintainerContact::default()
+                };
+                if contact.name.is_some() || contact.email.is_some() {
+                    metadata.contacts.push(contact);
+                }
+            }
+        }
+    }
+
+    // Poetry: `Name <email>` strings
+    if let Some(poetry) = pyproject.get("tool").and_then(|tool| tool.get("poetry")) {
+        for key in ["authors", "maintainers"] {
+            let people = poetry.get(key).and_then(toml::Value::as_array);
+            for person in people.into_iter().flatten() {
+                metadata
+                    .contacts
+                    .extend(person.as_str().and_then(|text| parse_person(text, path)));
+            }
+        }
+    }
+
+    metadata
+}
+
+fn parse_codeowners(path: &str, content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        // First token is the file pattern
+        for owner in line.split_whitespace().skip(1) {
+            let contact = if let Some(login) = owner.strip_prefix('@') {
+                // `@org/team` names a team, not a person
+                if login.contains('/') {
+                    continue;
+                }
+                MaintainerContact {
+                    github: Some(login.to_string()),
+                    source_file: path.to_string(),
+                    ..MaintainerContact::default()
+                }
+            } else if owner.contains('@') {
+                MaintainerContact {
+                    email: Some(owner.to_string()),
+                    source_file: path.to_string(),
+                    ..MaintainerContact::default()
+                }
+            } else {
+                continue;
+            };
+
+            metadata.merge(RepoMetadata {
+                contacts: vec![contact],
+                sponsor_links: Vec::new(),
+            });
+        }
+    }
+
+    metadata
+}
+
+fn parse_security_md(path: &str, content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+    let Ok(email_regex) = Regex::new(r"[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}") else {
+        return metadata;
+    };
+
+    for found in email_regex.find_iter(content) {
+        metadata.merge(RepoMetadata {
+            contacts: vec![MaintainerContact {
+                email: Some(found.as_str().to_string()),
+                source_file: path.to_string(),
+                ..MaintainerContact::default()
+            }],
+            sponsor_links: Vec::new(),
+        });
+    }
+
+    metadata
+}
+
+fn parse_funding_yml(content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+    let Ok(YamlValue::Mapping(funding)) = serde_yaml::from_str::<YamlValue>(content) else {
+        return metadata;
+    };
+
+    for (platform, value) in &funding {
+        let Some(platform) = platform.as_str() else {
+            continue;
+        };
+        let accounts: Vec<&str> = match value {
+            YamlValue::String(account) => vec![account.as_str()],
+            YamlValue::Sequence(accounts) => accounts.iter().filter_map(|a| a.as_str()).collect(),
+            _ => Vec::new(),
+        };
+
+        for account in accounts.into_iter().filter(|a| !a.trim().is_empty()) {
+            let account = account.trim();
+            let link = match platform {
+                "github" => format!("https://github.com/sponsors/{}", account),
+                "patreon" => format!("https://www.patreon.com/{}", account),
+                "open_collective" => format!("https://opencollective.com/{}", account),
+                "ko_fi" => format!("https://ko-fi.com/{}", account),
+                "liberapay" => format!("https://liberapay.com/{}", account),
+                "buy_me_a_coffee" => format!("https://www.buymeacoffee.com/{}", account),
+                "polar" => format!("https://polar.sh/{}", account),
+                "thanks_dev" => format!("https://thanks.dev/{}", account),
+                "tidelift" => format!("https://tidelift.com/funding/github/{}", account),
+                "custom" => account.to_string(),
+                _ => continue,
+            };
+            if !metadata.sponsor_links.contains(&link) {
+                metadata.sponsor_links.push(link);
+            }
+        }
+    }
+
+    metadata
+}
+
+fn parse_citation_cff(path: &str, content: &str) -> RepoMetadata {
+    let mut metadata = RepoMetadata::default();
+    let Ok(citation) = serde_yaml::from_str::<YamlValue>(content) else {
+        return metadata;
+    };
+
+    let authors = citation.get("authors").and_then(YamlValue::as_sequence);
+    for author in authors.into_iter().flatten() {
+        let field = |key: &str| {
+            author
+                .get(key)
+                .and_then(YamlValue::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(str::to_string)
+        };
+
+        // People have given/family names, entities a single `name`
+        let name = match (field("given-names"), field("family-names")) {
+            (Some(given), Some(family)) => Some(format!("{} {}", given, family)),
+            (given, family) => given.or(family).or_else(|| field("name")),
+        };
+        let contact = MaintainerContact {
+            name,
+            email: field("email"),
+            url: field("orcid").or_else(|| field("website")),
+            source_file: path.to_string(),
+            ..MaintainerContact::default()
+        };
+        if contact.name.is_some() || contact.email.is_some() {
+            metadata.contacts.push(contact);
+        }
+    }
+
+    metadata
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_package_manifests() {
+        let package = parse_metadata_file(
+            "package.json",
+            r#"{
+                "author": "Jane Doe <jane@acme.dev> (https://jane.dev)",
+                "maintainers": [{"name": "Joe", "email": "joe@acme.dev"}],
+                "funding": {"type": "opencollective", "url": "https://opencollective.com/acme"}
+            }"#,
+        );
+        assert_eq!(package.contacts.len(), 2);
+        assert_eq!(package.contacts[0].name.as_deref(), Some("Jane Doe"));
+        assert_eq!(package.contacts[0].email.as_deref(), Some("jane@acme.dev"));
+        assert_eq!(package.contacts[0].url.as_deref(), Some("https://jane.dev"));
+        assert_eq!(
+            package.sponsor_links,
+            vec!["https://opencollective.com/acme"]
+        );
+
+        let cargo = parse_metadata_file(
+            "Cargo.toml",
+            "[package]\nname = \"tool\"\nauthors = [\"Sam Lee <sam@acme.dev>\"]\n",
+        );
+        assert_eq!(cargo.contacts[0].email.as_deref(), Some("sam@acme.dev"));
+
+        let pyproject = parse_metadata_file(
+            "pyproject.toml",
+            r#"
+[project]
+authors = [{ name = "Ana Silva", email = "ana@acme.dev" }]
+
+[tool.poetry]
+maintainers = ["Bo <bo@acme.dev>"]
+"#,
+        );
+        let emails: Vec<_> = pyproject
+            .contacts
+            .iter()
+            .filter_map(|c| c.email.as_deref())
+            .collect();
+        assert_eq!(emails, vec!["ana@acme.dev", "bo@acme.dev"]);
+    }
+
+    #[test]
+    fn test_codeowners_and_security_policy() {
+        let owners = parse_metadata_file(
+            ".github/CODEOWNERS",
+            "# Owners\n* @jane @acme/core\n/docs/ docs@acme.dev @jane\n",
+        );
+        assert_eq!(owners.contacts.len(), 2);
+        assert_eq!(owners.contacts[0].github.as_deref(), Some("jane"));
+        assert_eq!(owners.contacts[1].email.as_deref(), Some("docs@acme.dev"));
+
+        let security = parse_metadata_file(
+            "SECURITY.md",
+            "Report issues to [security@acme.dev](mailto:security@acme.dev).",
+        );
+        assert_eq!(security.contacts.len(), 1);
+        assert_eq!(
+            security.contacts[0].email.as_deref(),
+            Some("security@acme.dev")
+        );
+    }
+
+    #[test]
+    fn test_funding_and_citation() {
+        let funding = parse_metadata_file(
+            ".github/FUNDING.yml",
+            "github: [jane, joe]\nko_fi: acme\ncustom: \"https://acme.dev/donate\"\nissuehunt: ~\n",
+        );
+        assert_eq!(
+            funding.sponsor_links,
+            vec![
+                "https://github.com/sponsors/jane",
+                "https://github.com/sponsors/joe",
+                "https://ko-fi.com/acme",
+                "https://acme.dev/donate",
+            ]
+        );
+
+        let citation = parse_metadata_file(
+            "CITATION.cff",
+            r#"
+cff-version: 1.2.0
+authors:
+  - given-names: Ana
+    family-names: Silva
+    email: ana@uni.edu
+    orcid: https://orcid.org/0000-0002-1825-0097
+  - name: "Acme Research Lab"
+"#,
+        );
+        assert_eq!(citation.contacts.len(), 2);
+        assert_eq!(citation.contacts[0].name.as_deref(), Some("Ana Silva"));
+        assert_eq!(
+            citation.contacts[1].name.as_deref(),
+            Some("Acme Research Lab")
+        );
+    }
+}
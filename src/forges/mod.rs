@@ -0,0 +1,396 @@
+use crate::config::ForgesConfig;
+use crate::errors::{Result, ScrapingError};
+use crate::models::RepoHealth;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::sync::Arc;
+use tracing::debug;
+use url::Url;
+
+pub mod gitea;
+pub mod github;
+pub mod gitlab;
+pub mod mailmap;
+pub mod metadata;
+
+pub use gitea::GiteaForge;
+pub use github::GitHubForge;
+pub use gitlab::GitLabForge;
+pub use mailmap::Mailmap;
+pub use metadata::{parse_metadata_file, RepoMetadata, METADATA_FILES};
+
+/// Repository on a forge, identified by its path (`owner/repo`, or `group/sub/project` on GitLab)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RepoRef {
+    pub path: String,
+}
+
+impl RepoRef {
+    pub fn new(path: &str) -> Self {
+        Self {
+            path: path.trim_matches('/').trim_end_matches(".git").to_string(),
+        }
+    }
+
+    /// First path segment: the user, organization or group owning the repository
+    pub fn owner(&self) -> &str {
+        self.path.split('/').next().unwrap_or("")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RepoInfo {
+    pub fork: bool,
+    pub default_branch: String,
+    /// Stars, activity and license when the repository response carries them
+    pub health: Option<RepoHealth>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommitPerson {
+    pub name: Option<String>,
+    pub email: String,
+    pub date: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ForgeCommit {
+    pub author: CommitPerson,
+    pub committer: Option<CommitPerson>,
+}
+
+/// Public profile of the user or organization owning a repository.
+/// Field names follow the GitHub `GET /users/{login}` response.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OwnerProfile {
+    pub login: String,
+    pub name: Option<String>,
+    pub html_url: String,
+    pub blog: Option<String>,
+    pub company: Option<String>,
+    pub email: Option<String>,
+    pub location: Option<String>,
+    pub twitter_username: Option<String>,
+    pub bio: Option<String>,
+    pub public_repos: Option<u32>,
+    pub followers: Option<u32>,
+}
+
+/// Common interface over code hosting platforms (GitHub, GitLab, Gitea/Forgejo)
+#[async_trait]
+pub trait Forge: Send + Sync {
+    /// Human-readable forge name
+    fn name(&self) -> &'static str;
+
+    /// Web base URL, e.g. `https://github.com`
+    fn web_base(&self) -> &str;
+
+    /// Fetch the repository README as text
+    async fn fetch_readme(&self, repo: &RepoRef) -> Result<String>;
+
+    /// Fetch fork status, default branch and, where available, repository health
+    async fn repo_info(&self, repo: &RepoRef) -> Result<RepoInfo>;
+
+    /// Fetch a raw file from a branch
+    async fn fetch_file(&self, repo: &RepoRef, branch: &str, path: &str) -> Result<String>;
+
+    /// Fetch one page (1-based) of commits on a branch, newest first
+    async fn commits(
+        &self,
+        repo: &RepoRef,
+        branch: &str,
+        page: u32,
+        per_page: usize,
+    ) -> Result<Vec<ForgeCommit>>;
+
+    /// Fetch the public profile of a repository owner; `None` when the
+    /// forge exposes no such profile
+    async fn owner_profile(&self, _owner: &str) -> Result<Option<OwnerProfile>> {
+        Ok(None)
+    }
+
+    /// Complete the health from `repo_info` with contributor count and
+    /// release cadence; `None` when the forge does not expose them
+    async fn repo_health(&self, _repo: &RepoRef, info: &RepoInfo) -> Result<Option<RepoHealth>> {
+        Ok(info.health.clone())
+    }
+
+    /// Paths of the entries of a repository directory (`""` for the root),
+    /// subdirectories with a trailing `/`; `None` when the forge cannot list them
+    async fn list_files(
+        &self,
+        _repo: &RepoRef,
+        _branch: &str,
+        _dir: &str,
+    ) -> Result<Option<Vec<String>>> {
+        Ok(None)
+    }
+
+    /// Parse a web URL hosted on this forge into a repository reference
+    fn parse_repo_url(&self, url: &str) -> Option<RepoRef> {
+        let parsed = Url::parse(url).ok()?;
+        let base = Url::parse(self.web_base()).ok()?;
+        if parsed.host_str()? != base.host_str()? || parsed.port() != base.port() {
+            return None;
+        }
+
+        let segments: Vec<&str> = parsed
+            .path_segments()?
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        if segments.len() < 2 {
+            return None;
+        }
+
+        Some(RepoRef::new(&format!("{}/{}", segments[0], segments[1])))
+    }
+
+    /// Web URL of a repository
+    fn repo_web_url(&self, repo: &RepoRef) -> String {
+        format!("{}/{}", self.web_base().trim_end_matches('/'), repo.path)
+    }
+
+    /// Web URL of a file (or, with an empty path, the directory base) on a branch
+    fn file_web_url(&self, repo: &RepoRef, branch: &str, path: &str) -> String {
+        format!("{}/blob/{}/{}", self.repo_web_url(repo), branch, path)
+    }
+}
+
+/// Set of configured forges used to resolve repository URLs
+#[derive(Clone)]
+pub struct ForgeRegistry {
+    forges: Vec<Arc<dyn Forge>>,
+}
+
+impl ForgeRegistry {
+    /// Build GitHub, GitLab and every configured Gitea/Forgejo instance.
+    /// `github_token` is used when `forges.github.token` is not set.
+    pub fn from_config(
+        client: Client,
+        config: &ForgesConfig,
+        github_token: Option<String>,
+    ) -> Self {
+        let mut github = config.github.clone();
+        if github.token.is_none() {
+            github.token = github_token;
+        }
+
+        let mut forges: Vec<Arc<dyn Forge>> = vec![
+            Arc::new(GitHubForge::new(client.clone(), github)),
+            Arc::new(GitLabForge::new(client.clone(), config.gitlab.clone())),
+        ];
+        for instance in &config.gitea {
+            forges.push(Arc::new(GiteaForge::new(client.clone(), instance.clone())));
+        }
+
+        Self { forges }
+    }
+
+    /// Find the forge hosting a repository URL
+    pub fn resolve(&self, url: &str) -> Option<(Arc<dyn Forge>, RepoRef)> {
+        self.forges.iter().find_map(|forge| {
+            forge
+                .parse_repo_url(url)
+                .map(|repo| (Arc::clone(forge), repo))
+        })
+    }
+
+    /// Resolve a full repository URL, or an `owner/repo` shorthand on GitHub
+    pub fn resolve_spec(&self, spec: &str) -> Option<(Arc<dyn Forge>, RepoRef)> {
+        if spec.starts_with("http://") || spec.starts_with("https://") {
+            return self.resolve(spec);
+        }
+        self.forges
+            .first()
+            .map(|github| (Arc::clone(github), RepoRef::new(spec)))
+    }
+
+    /// Whether a host name is the web host of a configured forge
+    pub fn is_forge_host(&self, host: &str) -> bool {
+        self.forges.iter().any(|forge| {
+            Url::parse(forge.web_base())
+                .ok()
+                .and_then(|base| base.host_str().map(|h| h.eq_ignore_ascii_case(host)))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Whether the URL points at a repository on a known forge
+    pub fn is_repository_url(&self, url: &str) -> bool {
+        self.resolve(url).is_some()
+    }
+}
+
+/// GET a forge API URL with an optional auth header and decode the JSON body
+pub(crate) async fn get_json<T: DeserializeOwned>(
+    client: &Client,
+    url: &str,
+    auth: Option<(&str, String)>,
+) -> Result<T> {
+    let response = get(client, url, auth).await?;
+    response
+        .json()
+        .await
+        .map_err(|e| ScrapingError::ParseError(format!("Failed to parse '{}': {}", url, e)))
+}
+
+/// GET a forge API URL with an optional auth header, failing on non-2xx statuses
+pub(crate) async fn get(
+    client: &Client,
+    url: &str,
+    auth: Option<(&str, String)>,
+) -> Result<reqwest::Response> {
+    debug!("Forge API request: {}", url);
+
+    let mut request = client.get(url).header("User-Agent", "Lead-Scraper/1.0");
+    if let Some((header, value)) = auth {
+        request = request.header(header, value);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| ScrapingError::NetworkError(format!("Request failed for '{}': {}", url, e)))?;
+
+    if !response.status().is_success() {
+        return Err(ScrapingError::NetworkError(format!(
+            "Forge API error {}: {}",
+            response.status(),
+            url
+        )));
+    }
+
+    Ok(response)
+}
+
+/// Decode base64 file content as returned by the GitHub/Gitea contents APIs
+pub(crate) fn decode_base64_content(content: &str) -> Result<String> {
+    use base64::{engine::general_purpose, Engine as _};
+
+    // Clean the base64 content - remove whitespace and newlines
+    let cleaned_content = content
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    let decoded = general_purpose::STANDARD
+        .decode(&cleaned_content)
+        .map_err(|e| {
+            ScrapingError::ParseError(format!("Failed to decode base64 content: {}", e))
+        })?;
+
+    String::from_utf8(decoded)
+        .map_err(|e| ScrapingError::ParseError(format!("Invalid UTF-8 in README: {}", e)))
+}
+
+/// GitHub-style `contents` route listing a directory (`""` for the root)
+fn contents_route(repo: &RepoRef, branch: &str, dir: &str) -> String {
+    let dir = dir.trim_matches('/');
+    if dir.is_empty() {
+        format!("/repos/{}/contents?ref={}", repo.path, branch)
+    } else {
+        format!("/repos/{}/contents/{}?ref={}", repo.path, dir, branch)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_server {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Start a local HTTP stand-in answering `GET` requests whose path and
+    /// query start with one of the given prefixes; returns its base URL.
+    pub async fn serve(routes: Vec<(&'static str, String)>) -> String {
+        let routes = routes
+            .into_iter()
+            .map(|(path, body)| {
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                (path, response)
+            })
+            .collect();
+        serve_raw(routes).await
+    }
+
+    /// Like [`serve`], with each route answered by a complete raw HTTP
+    /// response (status line, headers and body), e.g. a redirect
+    pub async fn serve_raw(routes: Vec<(&'static str, String)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind test server");
+        let addr = listener.local_addr().expect("test server address");
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let routes = routes.clone();
+                tokio::spawn(async move {
+                    let mut buffer = vec![0u8; 8192];
+                    let read = socket.read(&mut buffer).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buffer[..read]);
+                    let target = request.split_whitespace().nth(1).unwrap_or("/");
+
+                    let response = match routes.iter().find(|(path, _)| target.starts_with(path)) {
+                        Some((_, response)) => response.clone(),
+                        None => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                            .to_string(),
+                    };
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ForgeConfig;
+
+    #[test]
+    fn test_resolve_repository_urls() {
+        let config = ForgesConfig {
+            gitea: vec![ForgeConfig::new(
+                "https://codeberg.org",
+                "https://codeberg.org/api/v1",
+            )],
+            ..ForgesConfig::default()
+        };
+        let registry = ForgeRegistry::from_config(Client::new(), &config, None);
+
+        let (forge, repo) = registry
+            .resolve("https://github.com/rust-lang/rust/tree/master")
+            .expect("GitHub URL");
+        assert_eq!(forge.name(), "GitHub");
+        assert_eq!(repo.path, "rust-lang/rust");
+
+        let (forge, repo) = registry
+            .resolve("https://gitlab.com/group/sub/project/-/tree/main")
+            .expect("GitLab URL");
+        assert_eq!(forge.name(), "GitLab");
+        assert_eq!(repo.path, "group/sub/project");
+
+        let (forge, repo) = registry
+            .resolve("https://codeberg.org/forgejo/forgejo.git")
+            .expect("Codeberg URL");
+        assert_eq!(forge.name(), "Gitea");
+        assert_eq!(repo.path, "forgejo/forgejo");
+
+        assert!(!registry.is_repository_url("https://example.com/foo/bar"));
+        assert!(!registry.is_repository_url("https://github.com/rust-lang"));
+
+        let (forge, repo) = registry
+            .resolve_spec("sindresorhus/awesome")
+            .expect("shorthand");
+        assert_eq!(
+            forge.repo_web_url(&repo),
+            "https://github.com/sindresorhus/awesome"
+        );
+    }
+}
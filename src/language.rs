@@ -0,0 +1,147 @@
+use scraper::{Html, Selector};
+use whatlang::Lang;
+
+/// Below this confidence a detection is only trusted for longer texts
+const MIN_CONFIDENCE: f64 = 0.5;
+const MIN_UNRELIABLE_CHARS: usize = 40;
+
+/// Language of a text as an ISO 639-1 code (`de`, `ja`), when the detector
+/// is confident enough
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let info = whatlang::detect(text)?;
+    let long_enough = text.chars().filter(|c| c.is_alphabetic()).count() >= MIN_UNRELIABLE_CHARS;
+    (info.is_reliable() || (long_enough && info.confidence() >= MIN_CONFIDENCE))
+        .then(|| iso_639_1(info.lang()))
+}
+
+/// Language a page declares on `<html lang>` (`de-CH` -> `de`)
+pub fn declared_language(html: &str) -> Option<String> {
+    let selector = Selector::parse("html[lang]").ok()?;
+    let document = Html::parse_document(html);
+    let lang = document.select(&selector).next()?.value().attr("lang")?;
+    let primary = lang.split(['-', '_']).next()?.trim().to_lowercase();
+    (primary.len() == 2 && primary.chars().all(|c| c.is_ascii_alphabetic())).then_some(primary)
+}
+
+/// Language of a fetched page: the declared one, else detected from its text
+pub fn page_language(text: &str, html: &str) -> Option<String> {
+    declared_language(html).or_else(|| detect_language(text).map(str::to_string))
+}
+
+fn iso_639_1(lang: Lang) -> &'static str {
+    match lang {
+        Lang::Epo => "eo",
+        Lang::Eng => "en",
+        Lang::Rus => "ru",
+        Lang::Cmn => "zh",
+        Lang::Spa => "es",
+        Lang::Por => "pt",
+        Lang::Ita => "it",
+        Lang::Ben => "bn",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Ukr => "uk",
+        Lang::Kat => "ka",
+        Lang::Ara => "ar",
+        Lang::Hin => "hi",
+        Lang::Jpn => "ja",
+        Lang::Heb => "he",
+        Lang::Yid => "yi",
+        Lang::Pol => "pl",
+        Lang::Amh => "am",
+        Lang::Jav => "jv",
+        Lang::Kor => "ko",
+        Lang::Nob => "nb",
+        Lang::Dan => "da",
+        Lang::Swe => "sv",
+        Lang::Fin => "fi",
+        Lang::Tur => "tr",
+        Lang::Nld => "nl",
+        Lang::Hun => "hu",
+        Lang::Ces => "cs",
+        Lang::Ell => "el",
+        Lang::Bul => "bg",
+        Lang::Bel => "be",
+        Lang::Mar => "mr",
+        Lang::Kan => "kn",
+        Lang::Ron => "ro",
+        Lang::Slv => "sl",
+        Lang::Hrv => "hr",
+        Lang::Srp => "sr",
+        Lang::Mkd => "mk",
+        Lang::Lit => "lt",
+        Lang::Lav => "lv",
+        Lang::Est => "et",
+        Lang::Tam => "ta",
+        Lang::Vie => "vi",
+        Lang::Urd => "ur",
+        Lang::Tha => "th",
+        Lang::Guj => "gu",
+        Lang::Uzb => "uz",
+        Lang::Pan => "pa",
+        Lang::Aze => "az",
+        Lang::Ind => "id",
+        Lang::Tel => "te",
+        Lang::Pes => "fa",
+        Lang::Mal => "ml",
+        Lang::Ori => "or",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Sin => "si",
+        Lang::Khm => "km",
+        Lang::Tuk => "tk",
+        Lang::Aka => "ak",
+        Lang::Zul => "zu",
+        Lang::Sna => "sn",
+        Lang::Afr => "af",
+        Lang::Lat => "la",
+        Lang::Slk => "sk",
+        Lang::Cat => "ca",
+        Lang::Tgl => "tl",
+        Lang::Hye => "hy",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        let samples = [
+            (
+                "Wir entwickeln Software für den Mittelstand mit Sitz in München.",
+                "de",
+            ),
+            (
+                "Nous aidons les entreprises à automatiser leur comptabilité, basé à Lyon.",
+                "fr",
+            ),
+            (
+                "Somos una startup con sede en Madrid que ayuda a las tiendas online.",
+                "es",
+            ),
+            (
+                "東京を拠点とするスタートアップで、中小企業向けの会計ソフトを開発しています。",
+                "ja",
+            ),
+            (
+                "서울에 본사를 둔 스타트업으로 온라인 결제 서비스를 제공합니다.",
+                "ko",
+            ),
+            (
+                "An open source toolkit for building command line applications.",
+                "en",
+            ),
+        ];
+        for (text, expected) in samples {
+            assert_eq!(detect_language(text), Some(expected), "{}", text);
+        }
+        // Too short to tell
+        assert_eq!(detect_language("Acme"), None);
+
+        let html = r#"<html lang="de-CH"><body>Hello world</body></html>"#;
+        assert_eq!(page_language("Hello world", html).as_deref(), Some("de"));
+        assert_eq!(declared_language("<html><body></body></html>"), None);
+    }
+}
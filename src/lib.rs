@@ -1,9 +1,24 @@
 pub mod config;
+pub mod contact;
+pub mod email;
 pub mod errors;
 pub mod extractors;
+pub mod filter;
+pub mod forges;
+pub mod language;
+pub mod location;
 pub mod models;
+pub mod query;
+pub mod runs;
+pub mod scoring;
 pub mod scraper_util;
 pub mod scrapers; // NEW: Modular scrapers
+pub mod segments;
+pub mod social;
+pub mod tagging;
+pub mod technologies;
+pub mod text;
+pub mod website;
 
 pub use config::Config;
 pub use errors::{Result, ScrapingError};
@@ -12,7 +27,10 @@ pub use scraper_util::LeadScraper;
 pub use scrapers::SourceScraper; // NEW: Export trait for extensibility
 
 // Re-export commonly used types for convenience
-pub use scrapers::{BetaListScraper, GitHubAwesomeScraper, YCombinatorScraper};
+pub use scrapers::{
+    BetaListScraper, GitHubAwesomeScraper, GitHubSearchScraper, PackageRegistryScraper,
+    YCombinatorScraper,
+};
 
 use regex::Regex;
 use std::collections::HashMap;
@@ -22,14 +40,9 @@ use crate::config::PatternsConfig;
 
 /// Legacy DataExtractor for backwards compatibility - now just delegates to extractors module
 pub struct DataExtractor {
-    email_patterns: EmailPatterns,
     location_patterns: LocationPatterns,
     tld_mapping: HashMap<String, String>,
-}
-
-struct EmailPatterns {
-    mailto: Regex,
-    generic: Regex,
+    text: crate::config::TextNormalization,
 }
 
 struct LocationPatterns {
@@ -38,10 +51,10 @@ struct LocationPatterns {
 
 impl DataExtractor {
     pub fn new(patterns: &PatternsConfig) -> Result<Self> {
-        let email_patterns = EmailPatterns {
-            mailto: Regex::new(&patterns.email.mailto)?,
-            generic: Regex::new(&patterns.email.generic)?,
-        };
+        // Email extraction lives in the extractors module; the patterns are
+        // still compiled here so an invalid config fails early
+        Regex::new(&patterns.email.mailto)?;
+        Regex::new(&patterns.email.generic)?;
 
         let mut country_indicators = Vec::new();
         for pattern in &patterns.location.country_indicators {
@@ -51,9 +64,9 @@ impl DataExtractor {
         let location_patterns = LocationPatterns { country_indicators };
 
         Ok(Self {
-            email_patterns,
             location_patterns,
             tld_mapping: patterns.tld_mapping.clone(),
+            text: patterns.text.clone(),
         })
     }
 
@@ -120,14 +133,7 @@ impl DataExtractor {
     }
 
     pub fn clean_text(&self, text: &str) -> String {
-        text.lines()
-            .map(|line| line.trim())
-            .filter(|line| !line.is_empty())
-            .collect::<Vec<_>>()
-            .join(" ")
-            .chars()
-            .filter(|c| c.is_ascii() || c.is_whitespace())
-            .collect()
+        crate::text::normalize_text(text, &self.text)
     }
 }
 
@@ -146,4 +152,3 @@ mod tests {
         assert_eq!(website, Some("https://example.com".to_string()));
     }
 }
-
@@ -0,0 +1,372 @@
+use crate::models::{EvidenceKind, LocationEvidence, PostalAddress};
+use serde::Deserialize;
+use std::sync::OnceLock;
+
+const BUNDLED_GAZETTEER: &str = include_str!("gazetteer.yaml");
+
+/// Longest run of words tried as a place name, e.g. "San Francisco Bay Area"
+const MAX_PLACE_WORDS: usize = 4;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Country-code TLD without the dot; absent for generic-use ccTLDs
+    pub tld: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Region {
+    pub country: String,
+    /// Postal abbreviation (`CA`, `ON`, `NSW`), where one is in common use
+    pub code: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct City {
+    pub name: String,
+    pub country: String,
+    pub region: Option<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Place resolved from free text
+#[derive(Debug, Clone, PartialEq)]
+pub struct Place {
+    pub country_code: String,
+    pub region: Option<String>,
+    pub city: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PlaceMatch<'a> {
+    Country(&'a Country),
+    Region(&'a Region),
+    City(&'a City),
+}
+
+/// Countries, regions and cities used to normalize location clues
+#[derive(Debug, Deserialize)]
+pub struct Gazetteer {
+    pub countries: Vec<Country>,
+    pub regions: Vec<Region>,
+    pub cities: Vec<City>,
+}
+
+impl Gazetteer {
+    /// Gazetteer compiled into the binary
+    pub fn bundled() -> &'static Gazetteer {
+        static GAZETTEER: OnceLock<Gazetteer> = OnceLock::new();
+        GAZETTEER.get_or_init(|| {
+            serde_yaml::from_str(BUNDLED_GAZETTEER).expect("bundled gazetteer is valid YAML")
+        })
+    }
+
+    /// Country by ISO code, name or alias
+    pub fn country(&self, code_or_name: &str) -> Option<&Country> {
+        let wanted = code_or_name.trim();
+        if wanted.len() == 2 {
+            if let Some(country) = self
+                .countries
+                .iter()
+                .find(|country| country.code.eq_ignore_ascii_case(wanted))
+            {
+                return Some(country);
+            }
+        }
+        self.countries
+            .iter()
+            .find(|country| names_match(&country.name, &country.aliases, wanted))
+    }
+
+    /// Country of a ccTLD label (`de`, `uk`); generic-use ccTLDs have none
+    pub fn country_for_tld(&self, tld: &str) -> Option<&Country> {
+        let tld = tld.trim_start_matches('.');
+        self.countries.iter().find(|country| {
+            country
+                .tld
+                .as_deref()
+                .is_some_and(|known| known.eq_ignore_ascii_case(tld))
+        })
+    }
+
+    /// Resolve text such as "Berlin, Germany", "Austin, TX" or "London and
+    /// remote" to a place. Segments are read most specific first; each must
+    /// start with a capitalized place name, so captures like "the ground up"
+    /// are rejected.
+    pub fn resolve_place(&self, text: &str) -> Option<Place> {
+        let segments = text
+            .split([',', ';', '·', '|', '/', '(', ')', '\n'])
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty());
+
+        let mut matches: Vec<PlaceMatch> = Vec::new();
+        for segment in segments {
+            let context = matches.last().map(|found| self.match_country(*found));
+            match self.match_segment(segment, context, !matches.is_empty()) {
+                Some(found) => matches.push(found),
+                None => break,
+            }
+        }
+
+        let mut country = None;
+        let mut region = None;
+        let mut city = None;
+        for found in &matches {
+            match found {
+                PlaceMatch::City(found) if city.is_none() && region.is_none() => {
+                    city = Some(*found)
+                }
+                PlaceMatch::Region(found) if region.is_none() && country.is_none() => {
+                    region = Some(*found)
+                }
+                PlaceMatch::Country(found) if country.is_none() => country = Some(*found),
+                _ => {}
+            }
+        }
+
+        let country_code = country
+            .map(|country| country.code.clone())
+            .or_else(|| region.map(|region| region.country.clone()))
+            .or_else(|| city.map(|city| city.country.clone()))?;
+
+        let region = region
+            .filter(|region| region.country == country_code)
+            .map(|region| region.name.clone())
+            .or_else(|| {
+                city.filter(|city| city.country == country_code)
+                    .and_then(|city| city.region.clone())
+            });
+
+        Some(Place {
+            country_code,
+            region,
+            // A city of the same name elsewhere ("Paris, TX") is still the city
+            city: city.map(|city| city.name.clone()),
+        })
+    }
+
+    /// Place of a postal address: explicit country first, then the locality
+    pub fn resolve_address(&self, address: &PostalAddress) -> Option<Place> {
+        let locality = address
+            .locality
+            .as_deref()
+            .and_then(|locality| self.resolve_place(locality));
+
+        let Some(country) = address
+            .country
+            .as_deref()
+            .and_then(|country| self.country(country))
+        else {
+            let text = [address.locality.as_deref(), address.region.as_deref()]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(", ");
+            return self.resolve_place(&text);
+        };
+
+        let locality = locality.filter(|place| place.country_code == country.code);
+        let region = address
+            .region
+            .as_deref()
+            .and_then(|region| self.region(region, &country.code))
+            .map(|region| region.name.clone())
+            .or_else(|| locality.as_ref().and_then(|place| place.region.clone()));
+
+        Some(Place {
+            country_code: country.code.clone(),
+            region,
+            city: locality
+                .and_then(|place| place.city)
+                .or_else(|| address.locality.clone()),
+        })
+    }
+
+    /// Evidence for a resolved place, with the country's display name
+    pub fn evidence(&self, kind: EvidenceKind, value: &str, place: Place) -> LocationEvidence {
+        let country = self
+            .country(&place.country_code)
+            .map(|country| country.name.clone())
+            .unwrap_or_else(|| place.country_code.clone());
+        LocationEvidence {
+            kind,
+            value: value.to_string(),
+            country_code: place.country_code,
+            country,
+            region: place.region,
+            city: place.city,
+        }
+    }
+
+    fn region(&self, code_or_name: &str, country_code: &str) -> Option<&Region> {
+        self.regions.iter().find(|region| {
+            region.country == country_code
+                && (region
+                    .code
+                    .as_deref()
+                    .is_some_and(|code| code.eq_ignore_ascii_case(code_or_name))
+                    || names_match(&region.name, &region.aliases, code_or_name))
+        })
+    }
+
+    fn match_country(&self, found: PlaceMatch) -> String {
+        match found {
+            PlaceMatch::Country(country) => country.code.clone(),
+            PlaceMatch::Region(region) => region.country.clone(),
+            PlaceMatch::City(city) => city.country.clone(),
+        }
+    }
+
+    /// Longest leading run of words in `segment` naming a place
+    fn match_segment(
+        &self,
+        segment: &str,
+        context_country: Option<String>,
+        after_place: bool,
+    ) -> Option<PlaceMatch<'_>> {
+        // Place names are capitalized; scripts without case (東京, 서울) pass
+        if !segment
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_uppercase() || (c.is_alphabetic() && !c.is_lowercase()))
+        {
+            return None;
+        }
+
+        // "Austin, TX" / "Berlin, DE": abbreviations only after a place
+        if after_place
+            && (2..=3).contains(&segment.len())
+            && segment.chars().all(|c| c.is_ascii_uppercase())
+        {
+            if let Some(country) = context_country.as_deref() {
+                if let Some(region) = self.regions.iter().find(|region| {
+                    region.country == country && region.code.as_deref() == Some(segment)
+                }) {
+                    return Some(PlaceMatch::Region(region));
+                }
+            }
+            if let Some(country) = self
+                .countries
+                .iter()
+                .find(|country| country.code == segment)
+            {
+                return Some(PlaceMatch::Country(country));
+            }
+            return ["US", "CA", "AU"].iter().find_map(|country| {
+                self.regions
+                    .iter()
+                    .find(|region| {
+                        region.country == *country && region.code.as_deref() == Some(segment)
+                    })
+                    .map(PlaceMatch::Region)
+            });
+        }
+
+        let words: Vec<&str> = segment.split_whitespace().collect();
+        (1..=words.len().min(MAX_PLACE_WORDS))
+            .rev()
+            .find_map(|len| {
+                let candidate = words[..len]
+                    .join(" ")
+                    .trim_end_matches(['.', '!', '?', ':'])
+                    .to_string();
+                // Keep abbreviations with dots ("U.S.") intact
+                let dotted = words[..len].join(" ");
+                self.lookup_name(&candidate)
+                    .or_else(|| self.lookup_name(&dotted))
+            })
+    }
+
+    fn lookup_name(&self, name: &str) -> Option<PlaceMatch<'_>> {
+        if name.is_empty() {
+            return None;
+        }
+        self.countries
+            .iter()
+            .find(|country| names_match(&country.name, &country.aliases, name))
+            .map(PlaceMatch::Country)
+            .or_else(|| {
+                self.regions
+                    .iter()
+                    .find(|region| names_match(&region.name, &region.aliases, name))
+                    .map(PlaceMatch::Region)
+            })
+            .or_else(|| {
+                self.cities
+                    .iter()
+                    .find(|city| names_match(&city.name, &city.aliases, name))
+                    .map(PlaceMatch::City)
+            })
+    }
+}
+
+fn names_match(name: &str, aliases: &[String], wanted: &str) -> bool {
+    let wanted = wanted.to_lowercase();
+    name.to_lowercase() == wanted || aliases.iter().any(|alias| alias.to_lowercase() == wanted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn place(country: &str, region: Option<&str>, city: Option<&str>) -> Option<Place> {
+        Some(Place {
+            country_code: country.to_string(),
+            region: region.map(str::to_string),
+            city: city.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn test_resolve_place() {
+        let gazetteer = Gazetteer::bundled();
+
+        assert_eq!(
+            gazetteer.resolve_place("Berlin, Germany"),
+            place("DE", None, Some("Berlin"))
+        );
+        assert_eq!(
+            gazetteer.resolve_place("Austin, TX"),
+            place("US", Some("Texas"), Some("Austin"))
+        );
+        assert_eq!(
+            gazetteer.resolve_place("Toronto, ON"),
+            place("CA", Some("Ontario"), Some("Toronto"))
+        );
+        assert_eq!(
+            gazetteer.resolve_place("München and remote across Europe"),
+            place("DE", Some("Bavaria"), Some("Munich"))
+        );
+        assert_eq!(gazetteer.resolve_place("the UK. We ship worldwide"), None);
+        assert_eq!(
+            gazetteer.resolve_place("San Francisco Bay Area"),
+            place("US", Some("California"), Some("San Francisco"))
+        );
+        assert_eq!(
+            gazetteer.resolve_place("Paris, TX").unwrap().country_code,
+            "US"
+        );
+
+        // Non-place captures
+        assert_eq!(gazetteer.resolve_place("the ground up"), None);
+        assert_eq!(gazetteer.resolve_place("Scratch with love"), None);
+    }
+
+    #[test]
+    fn test_country_lookup() {
+        let gazetteer = Gazetteer::bundled();
+        assert_eq!(gazetteer.country("de").unwrap().name, "Germany");
+        assert_eq!(gazetteer.country("Deutschland").unwrap().code, "DE");
+        assert_eq!(gazetteer.country_for_tld("uk").unwrap().code, "GB");
+        assert!(gazetteer.country_for_tld("io").is_none());
+        assert!(gazetteer.country_for_tld("ai").is_none());
+    }
+}
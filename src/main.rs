@@ -1,33 +1,170 @@
 // Update src/main.rs to generate timestamped folders
 
-use chrono::{DateTime, Utc};
-use clap::Parser;
+use chrono::Utc;
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use leadscraper::errors::Result as ScrapingResult;
-use leadscraper::{Config, LeadScraper};
+use leadscraper::filter::SourceKind;
+use leadscraper::query::Query;
+use leadscraper::scoring::LeadScorer;
+use leadscraper::scraper_util::RunPlan;
+use leadscraper::{runs, Config, Lead, LeadScraper};
 use std::path::Path;
 use tracing::{info, Level};
-use tracing_subscriber;
 
 #[derive(Parser)]
 #[command(name = "leadscraper")]
 #[command(about = "Aggressive lead scraper for startup data extraction")]
 struct Cli {
-    #[arg(short, long, default_value = "config/scraper.yaml")]
+    #[arg(short, long, global = true, default_value = "config/scraper.yaml")]
     config: String,
 
-    #[arg(short, long, default_value = "results")]
+    /// Base directory for new runs; `query`, `export`, `stats`, `enrich` and
+    /// `validate` read its latest run unless given --input
+    #[arg(short, long, global = true, default_value = "results")]
     output: String,
 
-    #[arg(short, long, default_value = "true")]
+    #[arg(short, long, global = true, default_value = "true")]
     verbose: bool,
 
     /// Use custom folder name instead of timestamp (optional)
-    #[arg(long)]
+    #[arg(long, global = true)]
     folder_name: Option<String>,
 
     /// Skip timestamp folder creation and use output path directly
-    #[arg(long)]
+    #[arg(long, global = true)]
     no_timestamp: bool,
+
+    /// Without a subcommand, every enabled source is scraped
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Scrape and enrich leads into a new run
+    Scrape(ScrapeArgs),
+    /// Re-run enrichment (websites, team pages, tagging, emails, scoring) on an existing run
+    Enrich(RunArgs),
+    /// Convert a run into another format
+    Export(ExportArgs),
+    /// Recompute lead statistics for a run or any leads JSON file
+    Stats(StatsArgs),
+    /// Check the emails and websites of an existing run, then rescore it
+    Validate(ValidateArgs),
+    /// Filter the leads of a run, e.g. `query "country in [DE, AT] and score >= 4"`
+    Query(QueryArgs),
+}
+
+#[derive(Args, Default)]
+struct ScrapeArgs {
+    /// Sources to scrape (ycombinator, github_awesome, betalist, github_search,
+    /// package_registry); all enabled sources when omitted
+    #[arg(value_parser = scrapable_source)]
+    sources: Vec<SourceKind>,
+
+    /// Leave out a source
+    #[arg(long = "exclude", value_name = "SOURCE", value_parser = scrapable_source)]
+    excluded_sources: Vec<SourceKind>,
+
+    /// Only scrape these endpoints, awesome-list repositories or registry
+    /// keywords/owners, as written in the config (e.g. `/companies`, `sindresorhus/awesome`)
+    #[arg(long = "endpoint", value_name = "ENDPOINT")]
+    endpoints: Vec<String>,
+
+    /// Skip these endpoints, awesome-list repositories or registry keywords/owners
+    #[arg(long = "exclude-endpoint", value_name = "ENDPOINT")]
+    excluded_endpoints: Vec<String>,
+
+    /// Stop each source after this many leads
+    #[arg(long, value_name = "N")]
+    max_leads: Option<usize>,
+
+    /// Skip detail pages, forge lookups, website checks, team/contact pages, homepages and email validation
+    #[arg(long)]
+    no_enrich: bool,
+
+    /// Print the planned requests and estimated API calls without fetching anything
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Run directory or leads JSON file (default: latest run under --output)
+    #[arg(short, long)]
+    input: Option<String>,
+}
+
+#[derive(Args)]
+struct ExportArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    #[arg(short, long, value_enum, default_value_t = ExportFormat::Csv)]
+    format: ExportFormat,
+
+    /// Output file (default: stdout)
+    #[arg(long)]
+    to: Option<String>,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Output file (default: stdout)
+    #[arg(long)]
+    to: Option<String>,
+}
+
+#[derive(Args)]
+struct ValidateArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Only check websites
+    #[arg(long, conflicts_with = "no_websites")]
+    no_emails: bool,
+
+    /// Only validate emails
+    #[arg(long)]
+    no_websites: bool,
+}
+
+#[derive(Args)]
+struct QueryArgs {
+    /// Filter expression (see README, "Querying leads")
+    expression: String,
+
+    #[command(flatten)]
+    run: RunArgs,
+
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+
+    /// Show at most this many leads
+    #[arg(short, long)]
+    limit: Option<usize>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    /// Pretty-printed JSON array
+    Json,
+    /// One lead per line
+    Jsonl,
+    /// One row per lead, as all_leads.csv
+    Csv,
+    /// One row per person, as contacts.csv
+    Contacts,
 }
 
 #[tokio::main]
@@ -40,25 +177,41 @@ async fn main() -> ScrapingResult<()> {
     } else {
         Level::INFO
     };
-    tracing_subscriber::fmt().with_max_level(level).init();
+    // Logs go to stderr so query and export output can be piped
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(std::io::stderr)
+        .init();
 
+    match &cli.command {
+        None => run_scrape(&cli, &ScrapeArgs::default()).await,
+        Some(Command::Scrape(args)) => run_scrape(&cli, args).await,
+        Some(Command::Enrich(args)) => run_enrich(&cli, args).await,
+        Some(Command::Export(args)) => run_export(&cli, args),
+        Some(Command::Stats(args)) => run_stats(&cli, args).await,
+        Some(Command::Validate(args)) => run_validate(&cli, args).await,
+        Some(Command::Query(args)) => run_query(&cli, args),
+    }
+}
+
+/// Scrape the selected sources (all enabled ones if none) into a new run
+async fn run_scrape(cli: &Cli, args: &ScrapeArgs) -> ScrapingResult<()> {
     info!("🚀 Starting Lead Scraper");
 
     // Load configuration
-    let config = Config::load(&cli.config)?;
+    let mut config = Config::load(&cli.config)?;
     info!("📋 Configuration loaded from: {}", cli.config);
-
-    // Generate timestamped output directory
-    let output_dir = if cli.no_timestamp {
-        cli.output.clone()
-    } else {
-        generate_output_directory(&cli.output, cli.folder_name.as_deref())?
-    };
-
-    info!("📁 Output directory: {}", output_dir);
+    select_sources(&mut config, args);
 
     // Initialize scraper
     let scraper = LeadScraper::new(config).await?;
+    if args.dry_run {
+        print_plan(&scraper.plan()?);
+        return Ok(());
+    }
+
+    let output_dir = new_run_directory(cli, None)?;
+    info!("📁 Output directory: {}", output_dir);
 
     // Execute scraping
     info!("🔥 Beginning aggressive lead extraction...");
@@ -85,6 +238,260 @@ async fn main() -> ScrapingResult<()> {
     Ok(())
 }
 
+/// A source kind with its own scraper: `website` has none to select
+fn scrapable_source(value: &str) -> std::result::Result<SourceKind, String> {
+    match value.parse::<SourceKind>() {
+        Ok(SourceKind::Website) => Err("website is not a scrapable source".to_string()),
+        Ok(kind) => Ok(kind),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Apply the source, endpoint, cap and enrichment options of `scrape`
+fn select_sources(config: &mut Config, args: &ScrapeArgs) {
+    if !args.sources.is_empty() {
+        config.sources.only(&args.sources);
+    }
+    config.sources.exclude(&args.excluded_sources);
+
+    // Endpoints match as written, ignoring case and trailing slashes
+    let normalize = |endpoint: &str| endpoint.trim_end_matches('/').to_lowercase();
+    let listed = |list: &[String], endpoint: &str| {
+        list.iter()
+            .any(|listed| normalize(listed) == normalize(endpoint))
+    };
+    if !args.endpoints.is_empty() || !args.excluded_endpoints.is_empty() {
+        config.sources.retain_endpoints(|endpoint| {
+            (args.endpoints.is_empty() || listed(&args.endpoints, endpoint))
+                && !listed(&args.excluded_endpoints, endpoint)
+        });
+    }
+
+    if let Some(max_leads) = args.max_leads {
+        config.sources.cap_leads(max_leads);
+    }
+    if args.no_enrich {
+        config.skip_enrichment();
+    }
+}
+
+/// Planned requests of a dry run, source by source
+fn print_plan(plan: &RunPlan) {
+    println!("Dry run: nothing fetched");
+    for source in &plan.sources {
+        let expected = source
+            .expected_leads
+            .map_or("unknown".to_string(), |leads| format!("~{}", leads));
+        println!(
+            "\n{} ({} leads, ~{} requests)",
+            source.source, expected, source.estimated_requests
+        );
+        for url in &source.requests.urls {
+            println!("  GET {}", url);
+        }
+        if source.requests.listing_requests > source.requests.urls.len() {
+            println!(
+                "  up to {} listing requests in all (linked lists, metadata)",
+                source.requests.listing_requests
+            );
+        }
+        if source.requests.requests_per_lead > 0 {
+            println!(
+                "  then up to {} requests per lead",
+                source.requests.requests_per_lead
+            );
+        }
+    }
+    if plan.sources.is_empty() {
+        println!("\nNo sources selected");
+    }
+    println!(
+        "\nEnrichment: up to {} requests per lead",
+        plan.enrichment_requests_per_lead
+    );
+    println!("Estimated total: ~{} requests", plan.estimated_requests);
+}
+
+/// Enrich the leads of an existing run into a new run
+async fn run_enrich(cli: &Cli, args: &RunArgs) -> ScrapingResult<()> {
+    let (input, leads) = load_run(cli, args)?;
+    let scraper = LeadScraper::new(Config::load(&cli.config)?).await?;
+    let output_dir = new_run_directory(cli, Some("enrich"))?;
+    info!("✨ Enriching {} leads from {}", leads.len(), input);
+
+    let start_time = Utc::now();
+    let leads = scraper.enrich(leads).await;
+    let end_time = Utc::now();
+
+    scraper
+        .save_leads_with_metadata(&leads, &output_dir, start_time, end_time)
+        .await?;
+    print_run_summary(&output_dir, &leads, end_time - start_time);
+    Ok(())
+}
+
+/// Write the leads of a run in another format
+fn run_export(cli: &Cli, args: &ExportArgs) -> ScrapingResult<()> {
+    let (input, leads) = load_run(cli, &args.run)?;
+    let content = match args.format {
+        ExportFormat::Json => serde_json::to_string_pretty(&leads)? + "\n",
+        ExportFormat::Jsonl => {
+            let mut lines = String::new();
+            for lead in &leads {
+                lines.push_str(&serde_json::to_string(lead)?);
+                lines.push('\n');
+            }
+            lines
+        }
+        ExportFormat::Csv => LeadScraper::leads_csv(&leads),
+        ExportFormat::Contacts => LeadScraper::contacts_csv(&leads),
+    };
+    write_output(args.to.as_deref(), &content)?;
+    info!("📤 Exported {} leads from {}", leads.len(), input);
+    Ok(())
+}
+
+/// Recompute `LeadStats` (with the configured segments) for any leads file
+async fn run_stats(cli: &Cli, args: &StatsArgs) -> ScrapingResult<()> {
+    let (_, leads) = load_run(cli, &args.run)?;
+    let scraper = LeadScraper::new(Config::load(&cli.config)?).await?;
+    let stats = scraper.stats(&leads);
+    write_output(
+        args.to.as_deref(),
+        &(serde_json::to_string_pretty(&stats)? + "\n"),
+    )
+}
+
+/// Check emails and websites of an existing run into a new run
+async fn run_validate(cli: &Cli, args: &ValidateArgs) -> ScrapingResult<()> {
+    let (input, mut leads) = load_run(cli, &args.run)?;
+    let mut config = Config::load(&cli.config)?;
+    config.websites.check = !args.no_websites;
+    config.email_validation.enabled = !args.no_emails;
+    let scraper = LeadScraper::new(config).await?;
+    let output_dir = new_run_directory(cli, Some("validate"))?;
+    info!("🔍 Validating {} leads from {}", leads.len(), input);
+
+    let start_time = Utc::now();
+    scraper.validate(&mut leads).await;
+    let end_time = Utc::now();
+
+    scraper
+        .save_leads_with_metadata(&leads, &output_dir, start_time, end_time)
+        .await?;
+    print_run_summary(&output_dir, &leads, end_time - start_time);
+    Ok(())
+}
+
+/// The run given with --input, else the latest run under --output
+fn load_run(cli: &Cli, args: &RunArgs) -> ScrapingResult<(String, Vec<Lead>)> {
+    let input = match &args.input {
+        Some(input) => input.clone(),
+        None => runs::latest_run(&cli.output)
+            .ok_or_else(|| {
+                leadscraper::ScrapingError::IoError(format!("No runs found under '{}'", cli.output))
+            })?
+            .to_string_lossy()
+            .to_string(),
+    };
+    let leads = runs::load_leads(&input)?;
+    Ok((input, leads))
+}
+
+/// Write to a file, or stdout without one
+fn write_output(path: Option<&str>, content: &str) -> ScrapingResult<()> {
+    match path {
+        Some(path) => std::fs::write(path, content).map_err(|e| {
+            leadscraper::ScrapingError::IoError(format!("Failed to write '{}': {}", path, e))
+        }),
+        None => {
+            print!("{}", content);
+            Ok(())
+        }
+    }
+}
+
+/// Output directory of a new run, honouring --folder-name and --no-timestamp
+fn new_run_directory(cli: &Cli, stage: Option<&str>) -> ScrapingResult<String> {
+    if cli.no_timestamp {
+        return Ok(cli.output.clone());
+    }
+    generate_output_directory(&cli.output, cli.folder_name.as_deref().or(stage))
+}
+
+/// Print the leads of a run matching a query expression
+fn run_query(cli: &Cli, args: &QueryArgs) -> ScrapingResult<()> {
+    let mut query = Query::parse(&args.expression)?;
+    // Role mailboxes as the scoring model and segments define them
+    let config = Config::load(&cli.config)?;
+    if config.scoring.enabled {
+        let scorer = LeadScorer::load(&config.scoring.model)?;
+        query = query.with_role_mailboxes(scorer.role_mailboxes());
+    }
+    let (input, leads) = load_run(cli, &args.run)?;
+    let mut matched = query.filter(&leads);
+    info!(
+        "🔎 {} of {} leads match in {}",
+        matched.len(),
+        leads.len(),
+        input
+    );
+    if let Some(limit) = args.limit {
+        matched.truncate(limit);
+    }
+
+    match args.format {
+        OutputFormat::Table => print_table(&matched),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&matched)?),
+        OutputFormat::Csv => print!("{}", LeadScraper::leads_csv(matched)),
+    }
+    Ok(())
+}
+
+/// Fixed-width overview of leads for the terminal
+fn print_table(leads: &[&Lead]) {
+    fn cell(value: &str, width: usize) -> String {
+        if value.chars().count() <= width {
+            return format!("{:<width$}", value, width = width);
+        }
+        let truncated: String = value.chars().take(width - 1).collect();
+        format!("{}…", truncated)
+    }
+
+    println!(
+        "{} {} {} {} {} Industries",
+        cell("Name", 28),
+        cell("Country", 7),
+        cell("Score", 6),
+        cell("Email", 32),
+        cell("Website", 32)
+    );
+    for lead in leads {
+        let industries: Vec<&str> = lead.industries.iter().map(|t| t.name.as_str()).collect();
+        println!(
+            "{} {} {} {} {} {}",
+            cell(&lead.name, 28),
+            cell(lead.country_code().unwrap_or("-"), 7),
+            cell(
+                &lead
+                    .score
+                    .as_ref()
+                    .map_or("-".to_string(), |s| format!("{:.1}", s.total)),
+                6
+            ),
+            cell(
+                lead.email
+                    .as_deref()
+                    .or(lead.github_email.as_deref())
+                    .unwrap_or("-"),
+                32
+            ),
+            cell(lead.website.as_deref().unwrap_or("-"), 32),
+            industries.join(", ")
+        );
+    }
+}
+
 /// Generate timestamped output directory
 fn generate_output_directory(base_path: &str, custom_name: Option<&str>) -> ScrapingResult<String> {
     let timestamp = Utc::now();
@@ -141,6 +548,8 @@ fn print_run_summary(output_dir: &str, leads: &[leadscraper::Lead], duration: ch
             leadscraper::Source::YCombinator => "YC",
             leadscraper::Source::GitHubAwesome { .. } => "GitHub",
             leadscraper::Source::BetaList => "BetaList",
+            leadscraper::Source::GitHubSearch { .. } => "GitHub Search",
+            leadscraper::Source::PackageRegistry { .. } => "Registry",
             leadscraper::Source::Website { .. } => "Website",
         };
         *source_counts.entry(source_name).or_insert(0) += 1;
@@ -148,4 +557,3 @@ fn print_run_summary(output_dir: &str, leads: &[leadscraper::Lead], duration: ch
 
     info!("   🔍 Sources: {:?}", source_counts);
 }
-
@@ -1,26 +1,527 @@
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Lead {
     pub name: String,
     pub website: Option<String>,
+    /// Registrable domain of the website (`acme.co.uk`), the dedup key
+    #[serde(default)]
+    pub canonical_domain: Option<String>,
+    /// Outcome of fetching the website, when checked
+    #[serde(default)]
+    pub website_status: Option<WebsiteStatus>,
+    #[serde(default)]
+    pub repository: Option<String>,
     pub email: Option<String>,
     pub github_email: Option<String>,
+    /// Deliverability verdicts by lowercased email address
+    #[serde(default)]
+    pub email_verdicts: BTreeMap<String, EmailVerdict>,
     pub linkedin: Option<String>,
     pub twitter: Option<String>,
+    /// Social profiles by network, canonical URLs
+    #[serde(default)]
+    pub socials: BTreeMap<SocialNetwork, String>,
+    /// Phone numbers in E.164 form, best first
+    #[serde(default)]
+    pub phones: Vec<PhoneNumber>,
+    /// Postal address from structured data or the site footer/imprint
+    #[serde(default)]
+    pub address: Option<PostalAddress>,
     pub source: Source,
-    pub country: Option<String>,
+    /// Every source that found the lead, `source` first; duplicates merged
+    /// by deduplication add theirs
+    #[serde(default)]
+    pub sources: Vec<Source>,
+    /// Where the lead is based, resolved from ranked evidence
+    #[serde(default)]
+    pub location: Option<Location>,
     pub description: Option<String>,
+    /// Language of the description or website, ISO 639-1 (`de`, `ja`)
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Company of the repository owner, from the forge profile
+    #[serde(default)]
+    pub company: Option<String>,
+    /// Followers of the repository owner
+    #[serde(default)]
+    pub owner_followers: Option<u32>,
+    /// Public repositories of the repository owner
+    #[serde(default)]
+    pub owner_public_repos: Option<u32>,
+    /// Founders, team members and maintainers behind the lead
+    #[serde(default)]
+    pub people: Vec<Person>,
+    /// Repository contributors, best outreach contact first
+    #[serde(default)]
+    pub contributors: Vec<Contributor>,
+    /// Maintainers declared in repository metadata files
+    #[serde(default)]
+    pub maintainers: Vec<MaintainerContact>,
+    /// Sponsorship pages (GitHub Sponsors, Open Collective, ...)
+    #[serde(default)]
+    pub sponsor_links: Vec<String>,
+    /// Activity and popularity of the source repository
+    #[serde(default)]
+    pub repo_health: Option<RepoHealth>,
+    /// Title, description and keywords from the website's `<head>`
+    #[serde(default)]
+    pub website_meta: Option<WebsiteMeta>,
+    /// Industry tags from the taxonomy, highest score first
+    #[serde(default)]
+    pub industries: Vec<IndustryTag>,
+    /// Technologies detected on the website, e.g. Stripe, Next.js
+    #[serde(default)]
+    pub technologies: Vec<Technology>,
+    /// Score from the scoring model, with the rules that fired
+    #[serde(default)]
+    pub score: Option<LeadScore>,
+    /// Awesome-list section path, e.g. `["Databases", "SQL"]`, or BetaList topics
+    #[serde(default)]
+    pub categories: Vec<String>,
+    /// Awesome lists the lead was found through, outermost first
+    #[serde(default)]
+    pub list_path: Vec<String>,
     pub scraped_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Social network a profile link belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SocialNetwork {
+    Twitter,
+    #[serde(rename = "linkedin_company")]
+    LinkedInCompany,
+    #[serde(rename = "linkedin_person")]
+    LinkedInPerson,
+    #[serde(rename = "github")]
+    GitHub,
+    Mastodon,
+    Bluesky,
+    #[serde(rename = "youtube")]
+    YouTube,
+    Discord,
+    Facebook,
+    Instagram,
+    Crunchbase,
+}
+
+/// Individual behind a lead, with personal (not company) profiles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Person {
+    pub name: String,
+    /// Role or title, e.g. `Co-founder & CEO`
+    pub role: Option<String>,
+    pub email: Option<String>,
+    pub linkedin: Option<String>,
+    pub twitter: Option<String>,
+    /// GitHub login
+    pub github: Option<String>,
+    /// Where the person was found, e.g. `team page`, `Y Combinator`, `commits`
+    pub origin: String,
+}
+
+impl Person {
+    pub fn new(name: &str, origin: &str) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            origin: origin.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Same email, GitHub login, LinkedIn profile or (case-insensitive) name
+    fn is_same_as(&self, other: &Person) -> bool {
+        let same = |a: &Option<String>, b: &Option<String>| match (a, b) {
+            (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+            _ => false,
+        };
+        same(&self.email, &other.email)
+            || same(&self.github, &other.github)
+            || same(&self.linkedin, &other.linkedin)
+            || self.name.eq_ignore_ascii_case(&other.name)
+    }
+
+    /// Fill fields missing here from another record of the same person
+    fn absorb(&mut self, other: Person) {
+        self.role = self.role.take().or(other.role);
+        self.email = self.email.take().or(other.email);
+        self.linkedin = self.linkedin.take().or(other.linkedin);
+        self.twitter = self.twitter.take().or(other.twitter);
+        self.github = self.github.take().or(other.github);
+    }
+}
+
+/// Commit author aggregated over the sampled repository history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Contributor {
+    pub name: Option<String>,
+    pub email: String,
+    pub commits: u32,
+    pub last_commit_at: Option<DateTime<Utc>>,
+    /// Email domain matches the lead's website domain
+    pub domain_match: bool,
+}
+
+/// Country, region and city of a lead with the evidence behind them
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    /// ISO 3166-1 alpha-2 code, e.g. `DE`
+    pub country_code: Option<String>,
+    /// English short name, e.g. `Germany`
+    pub country: Option<String>,
+    pub region: Option<String>,
+    pub city: Option<String>,
+    /// Clues, strongest first
+    pub evidence: Vec<LocationEvidence>,
+}
+
+/// A single location clue, already resolved against the gazetteer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocationEvidence {
+    pub kind: EvidenceKind,
+    /// Text the clue was read from, e.g. `based in Berlin` or `.de`
+    pub value: String,
+    pub country_code: String,
+    pub country: String,
+    pub region: Option<String>,
+    pub city: Option<String>,
+}
+
+/// Where a location clue came from, in decreasing order of reliability
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceKind {
+    /// Postal address (JSON-LD or imprint)
+    Address,
+    /// Location field of the forge (GitHub) owner profile
+    ForgeProfile,
+    /// "based in ..." style mention in page text
+    TextMention,
+    /// Calling code of a listed phone number
+    PhonePrefix,
+    /// Country-code top-level domain of the website
+    CcTld,
+    /// Free-text `country` of a lead saved before locations existed
+    LegacyCountry,
+}
+
+impl EvidenceKind {
+    pub fn weight(self) -> u32 {
+        match self {
+            EvidenceKind::Address => 5,
+            EvidenceKind::ForgeProfile => 4,
+            EvidenceKind::TextMention => 3,
+            EvidenceKind::PhonePrefix => 2,
+            EvidenceKind::CcTld => 1,
+            EvidenceKind::LegacyCountry => 1,
+        }
+    }
+}
+
+impl Location {
+    /// Add a clue and re-resolve: the country with the highest total weight
+    /// wins (ties go to the country with more clues, then to the strongest
+    /// single clue), region and city come from the strongest clue for that
+    /// country that has them.
+    pub fn add_evidence(&mut self, evidence: LocationEvidence) {
+        if self
+            .evidence
+            .iter()
+            .any(|known| known.kind == evidence.kind && known.value == evidence.value)
+        {
+            return;
+        }
+        self.evidence.push(evidence);
+        // Stable: clues of equal kind keep their discovery order
+        self.evidence.sort_by_key(|evidence| evidence.kind);
+
+        // Total weight and number of clues per country
+        let mut totals: Vec<(&str, u32, usize)> = Vec::new();
+        for evidence in &self.evidence {
+            match totals
+                .iter_mut()
+                .find(|(code, _, _)| *code == evidence.country_code)
+            {
+                Some((_, total, clues)) => {
+                    *total += evidence.kind.weight();
+                    *clues += 1;
+                }
+                None => totals.push((&evidence.country_code, evidence.kind.weight(), 1)),
+            }
+        }
+        // `max_by_key` keeps the last maximum; iterate in reverse so the
+        // country of the strongest clue wins remaining ties
+        let Some(winner) = totals
+            .iter()
+            .rev()
+            .max_by_key(|(_, total, clues)| (*total, *clues))
+            .map(|(code, _, _)| code.to_string())
+        else {
+            return;
+        };
+
+        let supporting = || {
+            self.evidence
+                .iter()
+                .filter(|evidence| evidence.country_code == winner)
+        };
+        self.country = supporting().next().map(|evidence| evidence.country.clone());
+        self.region = supporting().find_map(|evidence| evidence.region.clone());
+        self.city = supporting().find_map(|evidence| evidence.city.clone());
+        self.country_code = Some(winner);
+    }
+}
+
+/// Result of fetching a lead's website, after redirects
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebsiteStatus {
+    /// URL the redirects ended at, tracking parameters stripped
+    pub final_url: Option<String>,
+    pub http_status: Option<u16>,
+    pub redirected: bool,
+    pub state: WebsiteState,
+    /// Connection or HTTP error, when not live
+    pub error: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebsiteState {
+    /// Answered with a 2xx page of its own
+    Live,
+    /// Domain parking or for-sale page
+    Parked,
+    /// Domain does not resolve, refuses connections, or answers 404/410
+    Dead,
+    /// Timed out or blocked the request (401/403/429/5xx)
+    Unknown,
+}
+
+impl std::fmt::Display for WebsiteState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebsiteState::Live => write!(f, "live"),
+            WebsiteState::Parked => write!(f, "parked"),
+            WebsiteState::Dead => write!(f, "dead"),
+            WebsiteState::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Metadata from a website's `<head>`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebsiteMeta {
+    pub title: Option<String>,
+    /// `description` or `og:description`
+    pub description: Option<String>,
+    pub keywords: Vec<String>,
+}
+
+/// Industry assigned from the taxonomy, with the terms that matched
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndustryTag {
+    /// Tag id, e.g. `devtools`
+    pub name: String,
+    /// Display label, e.g. `Developer Tools`
+    pub label: String,
+    pub score: f32,
+    pub matched: Vec<String>,
+}
+
+/// Technology fingerprinted on a lead's website
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Technology {
+    pub name: String,
+    /// e.g. `Payments`, `JavaScript frameworks`
+    pub categories: Vec<String>,
+}
+
+/// Weighted rule score of a lead
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LeadScore {
+    pub total: f32,
+    /// Rules that fired, in model order
+    pub reasons: Vec<ScoreReason>,
+}
+
+/// A scoring rule that fired for a lead
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreReason {
+    pub rule: String,
+    pub points: f32,
+    pub description: Option<String>,
+}
+
+impl LeadScore {
+    /// `direct_email +3, role_email -1`
+    pub fn explanation(&self) -> String {
+        self.reasons
+            .iter()
+            .map(|reason| format!("{} {:+}", reason.rule, reason.points))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Outcome of the deliverability checks on one email address
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmailVerdict {
+    pub email: String,
+    pub status: EmailStatus,
+    pub syntax_valid: bool,
+    /// Domain has MX records, or an A/AAAA record to fall back to
+    pub has_mail_server: Option<bool>,
+    pub disposable: bool,
+    /// Webmail provider (gmail.com, outlook.com, ...)
+    pub free_provider: bool,
+    /// Mailbox accepted by `RCPT TO`, when probed
+    pub smtp_accepted: Option<bool>,
+    /// Domain accepts any mailbox, when probed
+    pub catch_all: Option<bool>,
+    /// Why the address is not deliverable, or why the check was inconclusive
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmailStatus {
+    /// Mailbox accepted by its mail server
+    Deliverable,
+    /// Domain accepts mail but the mailbox can't be confirmed (catch-all)
+    Risky,
+    /// Bad syntax, no mail server, disposable domain or rejected mailbox
+    Undeliverable,
+    /// Mail domain exists; mailbox not probed or the probe was inconclusive
+    Unknown,
+}
+
+impl std::fmt::Display for EmailStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmailStatus::Deliverable => write!(f, "deliverable"),
+            EmailStatus::Risky => write!(f, "risky"),
+            EmailStatus::Undeliverable => write!(f, "undeliverable"),
+            EmailStatus::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Phone number normalized to E.164
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhoneNumber {
+    /// E.164 form, e.g. `+493012345678`
+    pub e164: String,
+    /// ISO 3166-1 alpha-2 code inferred from the calling code
+    pub country: Option<String>,
+    /// Number as written on the page
+    pub raw: String,
+}
+
+/// Postal address, fields as found (schema.org `PostalAddress` naming)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PostalAddress {
+    pub street: Option<String>,
+    pub postal_code: Option<String>,
+    pub locality: Option<String>,
+    pub region: Option<String>,
+    pub country: Option<String>,
+}
+
+impl PostalAddress {
+    pub fn is_empty(&self) -> bool {
+        self.street.is_none()
+            && self.postal_code.is_none()
+            && self.locality.is_none()
+            && self.region.is_none()
+            && self.country.is_none()
+    }
+
+    /// Single-line form, e.g. `Torstraße 12, 10119 Berlin, Germany`
+    pub fn one_line(&self) -> String {
+        let city = [self.postal_code.as_deref(), self.locality.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+        [
+            self.street.as_deref(),
+            Some(city.as_str()).filter(|city| !city.is_empty()),
+            self.region.as_deref(),
+            self.country.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// Person or address declared in a repository metadata file
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MaintainerContact {
+    pub name: Option<String>,
+    pub email: Option<String>,
+    /// GitHub login, e.g. from `CODEOWNERS`
+    pub github: Option<String>,
+    pub url: Option<String>,
+    /// File the contact was found in, e.g. `package.json`
+    pub source_file: String,
+}
+
+/// Activity and popularity signals of a repository
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepoHealth {
+    pub stars: u32,
+    pub forks: u32,
+    pub open_issues: u32,
+    pub archived: bool,
+    pub pushed_at: Option<DateTime<Utc>>,
+    /// SPDX identifier (or name) of the detected license
+    pub license: Option<String>,
+    pub topics: Vec<String>,
+    pub contributors: Option<u32>,
+    /// Releases published in the 365 days before the check
+    pub releases_last_year: u32,
+    pub last_release_at: Option<DateTime<Utc>>,
+    /// Mean number of days between consecutive recent releases
+    pub release_interval_days: Option<f32>,
+}
+
+impl RepoHealth {
+    /// Derive release cadence from release publication dates
+    pub fn record_releases(&mut self, mut published: Vec<DateTime<Utc>>, now: DateTime<Utc>) {
+        published.sort_unstable_by(|a, b| b.cmp(a));
+
+        let year_ago = now - chrono::Duration::days(365);
+        self.releases_last_year = published.iter().filter(|date| **date >= year_ago).count() as u32;
+        self.last_release_at = published.first().copied();
+        self.release_interval_days = (published.len() >= 2).then(|| {
+            let span = *published.first().unwrap() - *published.last().unwrap();
+            span.num_hours() as f32 / 24.0 / (published.len() - 1) as f32
+        });
+    }
+
+    /// Not archived and pushed to within the last `max_idle_days` days
+    pub fn is_active(&self, max_idle_days: i64, now: DateTime<Utc>) -> bool {
+        !self.archived
+            && self
+                .pushed_at
+                .is_some_and(|pushed| now - pushed <= chrono::Duration::days(max_idle_days))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Source {
     YCombinator,
     GitHubAwesome { repository: String },
     BetaList,
+    GitHubSearch { query: String },
+    PackageRegistry { registry: String },
     Website { url: String },
 }
 
@@ -32,10 +533,53 @@ pub struct LeadStats {
     pub contact_rate: f32,
     pub sources_breakdown: HashMap<String, SourceStats>,
     pub countries_breakdown: HashMap<String, usize>,
+    /// Leads per industry tag
+    #[serde(default)]
+    pub industries_breakdown: HashMap<String, usize>,
     pub email_types: EmailTypeStats,
+    /// Stats per configured segment
+    #[serde(default)]
+    pub segments: BTreeMap<String, SegmentStats>,
     pub generated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SegmentStats {
+    pub description: Option<String>,
+    pub total: usize,
+    pub with_contact: usize,
+    pub contact_rate: f32,
+    pub average_score: f32,
+    pub countries_breakdown: HashMap<String, usize>,
+}
+
+impl SegmentStats {
+    pub fn new(description: Option<String>, leads: &[&Lead]) -> Self {
+        let total = leads.len();
+        let with_contact = leads.iter().filter(|lead| lead.has_contact()).count();
+        let mut countries_breakdown = HashMap::new();
+        for country in leads.iter().filter_map(|lead| lead.country()) {
+            *countries_breakdown.entry(country.to_string()).or_insert(0) += 1;
+        }
+        let (contact_rate, average_score) = if total > 0 {
+            (
+                (with_contact as f32 / total as f32) * 100.0,
+                leads.iter().map(|lead| lead.score_total()).sum::<f32>() / total as f32,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        Self {
+            description,
+            total,
+            with_contact,
+            contact_rate,
+            average_score,
+            countries_breakdown,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SourceStats {
     pub total: usize,
@@ -48,6 +592,9 @@ pub struct EmailTypeStats {
     pub direct_emails: usize,
     pub github_emails: usize,
     pub no_emails: usize,
+    /// Validated emails per verdict status
+    #[serde(default)]
+    pub verdicts: HashMap<String, usize>,
 }
 
 // NEW: Run metadata to track scraping sessions
@@ -86,6 +633,8 @@ impl std::fmt::Display for Source {
             Source::YCombinator => write!(f, "Y Combinator"),
             Source::GitHubAwesome { repository } => write!(f, "GitHub/{}", repository),
             Source::BetaList => write!(f, "BetaList"),
+            Source::GitHubSearch { query } => write!(f, "GitHub Search: {}", query),
+            Source::PackageRegistry { registry } => write!(f, "Registry/{}", registry),
             Source::Website { url } => write!(f, "Website: {}", url),
         }
     }
@@ -99,18 +648,77 @@ pub struct ScrapedData {
     pub html: String,
 }
 
+impl Serialize for Lead {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        Lead::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Lead {
+    /// Also reads run files from older versions: their free-text `country`
+    /// becomes location evidence and `source` starts `sources`
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Stored {
+            #[serde(flatten, deserialize_with = "Lead::deserialize")]
+            lead: Lead,
+            #[serde(default)]
+            country: Option<String>,
+        }
+
+        let Stored { mut lead, country } = Stored::deserialize(deserializer)?;
+        if lead.sources.is_empty() {
+            lead.sources.push(lead.source.clone());
+        }
+        if let Some(country) = country.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+            let gazetteer = crate::location::Gazetteer::bundled();
+            if let Some(place) = gazetteer.resolve_place(country) {
+                lead.add_location_evidence(gazetteer.evidence(
+                    EvidenceKind::LegacyCountry,
+                    country,
+                    place,
+                ));
+            }
+        }
+        Ok(lead)
+    }
+}
+
 impl Lead {
     pub fn new(name: String, source: Source) -> Self {
         Self {
             name,
             website: None,
+            canonical_domain: None,
+            website_status: None,
+            repository: None,
             email: None,
             github_email: None,
+            email_verdicts: BTreeMap::new(),
             linkedin: None,
             twitter: None,
+            socials: BTreeMap::new(),
+            phones: Vec::new(),
+            address: None,
+            sources: vec![source.clone()],
             source,
-            country: None,
+            location: None,
             description: None,
+            language: None,
+            company: None,
+            owner_followers: None,
+            owner_public_repos: None,
+            people: Vec::new(),
+            contributors: Vec::new(),
+            maintainers: Vec::new(),
+            sponsor_links: Vec::new(),
+            repo_health: None,
+            website_meta: None,
+            industries: Vec::new(),
+            technologies: Vec::new(),
+            score: None,
+            categories: Vec::new(),
+            list_path: Vec::new(),
             scraped_at: Utc::now(),
         }
     }
@@ -120,6 +728,11 @@ impl Lead {
         self
     }
 
+    pub fn with_repository(mut self, repository: Option<String>) -> Self {
+        self.repository = repository;
+        self
+    }
+
     pub fn with_email(mut self, email: Option<String>) -> Self {
         self.email = email;
         self
@@ -140,8 +753,23 @@ impl Lead {
         self
     }
 
-    pub fn with_country(mut self, country: Option<String>) -> Self {
-        self.country = country;
+    pub fn with_socials(mut self, socials: BTreeMap<SocialNetwork, String>) -> Self {
+        self.socials = socials;
+        self
+    }
+
+    pub fn with_phones(mut self, phones: Vec<PhoneNumber>) -> Self {
+        self.phones = phones;
+        self
+    }
+
+    pub fn with_address(mut self, address: Option<PostalAddress>) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn with_location(mut self, location: Option<Location>) -> Self {
+        self.location = location;
         self
     }
 
@@ -150,26 +778,171 @@ impl Lead {
         self
     }
 
-    // Contact scoring methods
-    pub fn has_contact(&self) -> bool {
-        self.email.is_some() || self.github_email.is_some()
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+
+    pub fn with_categories(mut self, categories: Vec<String>) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    pub fn with_list_path(mut self, list_path: Vec<String>) -> Self {
+        self.list_path = list_path;
+        self
+    }
+
+    /// ISO 3166-1 alpha-2 code of the lead's country
+    pub fn country_code(&self) -> Option<&str> {
+        self.location.as_ref()?.country_code.as_deref()
+    }
+
+    /// English short name of the lead's country
+    pub fn country(&self) -> Option<&str> {
+        self.location.as_ref()?.country.as_deref()
+    }
+
+    /// Whether a technology (case-insensitive name) was detected on the website
+    pub fn uses_technology(&self, name: &str) -> bool {
+        self.technologies
+            .iter()
+            .any(|technology| technology.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Deliverability status of an email on this lead, once validated
+    pub fn email_status(&self, email: &str) -> Option<EmailStatus> {
+        self.email_verdicts
+            .get(&email.trim().to_lowercase())
+            .map(|verdict| verdict.status)
     }
 
-    pub fn contact_score(&self) -> u8 {
-        let mut score = 0;
-        if self.email.is_some() {
-            score += 3;
+    /// Record a location clue and re-resolve where the lead is based
+    pub fn add_location_evidence(&mut self, evidence: LocationEvidence) {
+        self.location
+            .get_or_insert_with(Location::default)
+            .add_evidence(evidence);
+    }
+
+    /// Add a person, merging with an existing record of the same person
+    pub fn add_person(&mut self, person: Person) {
+        if person.name.is_empty() {
+            return;
         }
-        if self.github_email.is_some() {
-            score += 2;
+        match self
+            .people
+            .iter_mut()
+            .find(|known| known.is_same_as(&person))
+        {
+            Some(known) => known.absorb(person),
+            None => self.people.push(person),
         }
-        if self.linkedin.is_some() {
-            score += 1;
+    }
+
+    /// Key identifying the same company across sources: the canonical
+    /// domain, else the repository, else the website URL
+    pub fn dedup_key(&self) -> Option<String> {
+        if let Some(ref domain) = self.canonical_domain {
+            return Some(domain.clone());
         }
-        if self.twitter.is_some() {
-            score += 1;
+        let url = self.repository.as_deref().or(self.website.as_deref())?;
+        let url = url.trim().to_lowercase();
+        let url = url
+            .trim_start_matches("https://")
+            .trim_start_matches("http://")
+            .trim_start_matches("www.")
+            .trim_end_matches('/');
+        let url = url.strip_suffix(".git").unwrap_or(url);
+        (!url.is_empty()).then(|| url.to_string())
+    }
+
+    /// Fold a duplicate of this lead into it: this lead's values win,
+    /// gaps are filled and lists are unioned from the other
+    pub fn merge(&mut self, other: Lead) {
+        fn fill<T>(slot: &mut Option<T>, value: Option<T>) {
+            if slot.is_none() {
+                *slot = value;
+            }
         }
-        score
+        fn union<T>(list: &mut Vec<T>, other: Vec<T>, same: impl Fn(&T, &T) -> bool) {
+            for item in other {
+                if !list.iter().any(|known| same(known, &item)) {
+                    list.push(item);
+                }
+            }
+        }
+
+        // Leads saved before `sources` existed only carry `source`
+        if self.sources.is_empty() {
+            self.sources.push(self.source.clone());
+        }
+        let other_sources = if other.sources.is_empty() {
+            vec![other.source]
+        } else {
+            other.sources
+        };
+        union(&mut self.sources, other_sources, |a, b| a == b);
+
+        fill(&mut self.website, other.website);
+        fill(&mut self.canonical_domain, other.canonical_domain);
+        fill(&mut self.website_status, other.website_status);
+        fill(&mut self.repository, other.repository);
+        fill(&mut self.email, other.email);
+        fill(&mut self.github_email, other.github_email);
+        fill(&mut self.linkedin, other.linkedin);
+        fill(&mut self.twitter, other.twitter);
+        fill(&mut self.address, other.address);
+        fill(&mut self.description, other.description);
+        fill(&mut self.language, other.language);
+        fill(&mut self.company, other.company);
+        fill(&mut self.owner_followers, other.owner_followers);
+        fill(&mut self.owner_public_repos, other.owner_public_repos);
+        fill(&mut self.repo_health, other.repo_health);
+        fill(&mut self.website_meta, other.website_meta);
+        // Attributes changed; the pipeline re-scores after merging
+        self.score = None;
+
+        for (email, verdict) in other.email_verdicts {
+            self.email_verdicts.entry(email).or_insert(verdict);
+        }
+        for (network, url) in other.socials {
+            self.socials.entry(network).or_insert(url);
+        }
+        union(&mut self.phones, other.phones, |a, b| a.e164 == b.e164);
+        for evidence in other.location.into_iter().flat_map(|l| l.evidence) {
+            self.add_location_evidence(evidence);
+        }
+        for person in other.people {
+            self.add_person(person);
+        }
+        union(&mut self.contributors, other.contributors, |a, b| {
+            a.email.eq_ignore_ascii_case(&b.email)
+        });
+        union(&mut self.maintainers, other.maintainers, |a, b| a == b);
+        union(&mut self.sponsor_links, other.sponsor_links, |a, b| a == b);
+        union(&mut self.industries, other.industries, |a, b| {
+            a.name == b.name
+        });
+        union(&mut self.technologies, other.technologies, |a, b| {
+            a.name == b.name
+        });
+        if self.categories.is_empty() {
+            self.categories = other.categories;
+        }
+        if self.list_path.is_empty() {
+            self.list_path = other.list_path;
+        }
+        self.scraped_at = self.scraped_at.min(other.scraped_at);
+    }
+
+    /// Whether the lead has a direct or commit email
+    pub fn has_contact(&self) -> bool {
+        self.email.is_some() || self.github_email.is_some()
+    }
+
+    /// Total from the scoring model, 0 when unscored
+    pub fn score_total(&self) -> f32 {
+        self.score.as_ref().map_or(0.0, |score| score.total)
     }
 }
 
@@ -198,6 +971,8 @@ impl LeadStats {
                 Source::YCombinator => "Y Combinator".to_string(),
                 Source::GitHubAwesome { repository } => format!("GitHub/{}", repository),
                 Source::BetaList => "BetaList".to_string(),
+                Source::GitHubSearch { .. } => "GitHub Search".to_string(),
+                Source::PackageRegistry { registry } => format!("Registry/{}", registry),
                 Source::Website { url } => {
                     if url.contains("github.com") {
                         "GitHub Project".to_string()
@@ -231,8 +1006,18 @@ impl LeadStats {
         // Countries breakdown
         let mut countries_breakdown = HashMap::new();
         for lead in &all_leads {
-            if let Some(ref country) = lead.country {
-                *countries_breakdown.entry(country.clone()).or_insert(0) += 1;
+            if let Some(country) = lead.country() {
+                *countries_breakdown.entry(country.to_string()).or_insert(0) += 1;
+            }
+        }
+
+        // Industries breakdown
+        let mut industries_breakdown = HashMap::new();
+        for lead in &all_leads {
+            for industry in &lead.industries {
+                *industries_breakdown
+                    .entry(industry.name.clone())
+                    .or_insert(0) += 1;
             }
         }
 
@@ -240,6 +1025,7 @@ impl LeadStats {
         let mut direct_emails = 0;
         let mut github_emails = 0;
         let mut no_emails = 0;
+        let mut verdicts = HashMap::new();
 
         for lead in &all_leads {
             match (&lead.email, &lead.github_email) {
@@ -247,6 +1033,9 @@ impl LeadStats {
                 (None, Some(_)) => github_emails += 1,
                 (None, None) => no_emails += 1,
             }
+            for verdict in lead.email_verdicts.values() {
+                *verdicts.entry(verdict.status.to_string()).or_insert(0) += 1;
+            }
         }
 
         Self {
@@ -256,11 +1045,14 @@ impl LeadStats {
             contact_rate,
             sources_breakdown,
             countries_breakdown,
+            industries_breakdown,
             email_types: EmailTypeStats {
                 direct_emails,
                 github_emails,
                 no_emails,
+                verdicts,
             },
+            segments: BTreeMap::new(),
             generated_at: Utc::now(),
         }
     }
@@ -304,3 +1096,36 @@ impl RunMetadata {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_country_becomes_location() {
+        let stored = r#"{
+            "name": "Acme",
+            "website": "https://acme.io",
+            "email": null,
+            "github_email": null,
+            "linkedin": null,
+            "twitter": null,
+            "source": "BetaList",
+            "country": "Germany",
+            "description": null,
+            "scraped_at": "2025-05-29T08:31:59.405559Z"
+        }"#;
+
+        let lead: Lead = serde_json::from_str(stored).unwrap();
+        assert_eq!(lead.country_code(), Some("DE"));
+        let evidence = &lead.location.as_ref().unwrap().evidence;
+        assert_eq!(evidence[0].kind, EvidenceKind::LegacyCountry);
+
+        // Saved again in the current format, the location survives
+        let json = serde_json::to_string(&lead).unwrap();
+        let reloaded: Lead = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded.country_code(), Some("DE"));
+        assert_eq!(reloaded.location.unwrap().evidence.len(), 1);
+        assert_eq!(reloaded.name, "Acme");
+        assert_eq!(reloaded.sources, vec![Source::BetaList]);
+    }
+}
@@ -0,0 +1,682 @@
+use crate::errors::{Result, ScrapingError};
+use crate::filter::{default_role_mailboxes, is_role_address_in};
+use crate::models::{Lead, Source};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use regex::Regex;
+use std::str::FromStr;
+
+/// Lead filter expression, e.g.
+/// `contactable and industry = devtools and country in [DE, AT] and scraped_at >= 2025-05-01`
+///
+/// - comparisons: `=`, `!=`, `<`, `<=`, `>`, `>=`, `contains`, `~` (regex),
+///   `in [a, b]`, `between a and b`
+/// - `exists field`, `and`, `or`, `not`, parentheses
+/// - text compares case-insensitively; list fields (`industry`, `technology`,
+///   `category`, `person`) match when any element does
+/// - dates are `YYYY-MM-DD` or relative, `30d` meaning 30 days ago
+/// - boolean fields can stand alone: `contactable`, `archived`
+#[derive(Debug, Clone)]
+pub struct Query {
+    expr: Expr,
+    /// Mailboxes `email_kind` counts as role addresses
+    role_mailboxes: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Exists(Field),
+    Compare(Field, Op, Literal),
+    In(Field, Vec<Literal>),
+    Between(Field, Literal, Literal),
+    Matches(Field, Regex),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+}
+
+/// A literal as written; interpreted by the type of the field it meets
+#[derive(Debug, Clone)]
+struct Literal(String);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Name,
+    Website,
+    Domain,
+    Repository,
+    Email,
+    GithubEmail,
+    EmailStatus,
+    EmailKind,
+    Linkedin,
+    Twitter,
+    Phone,
+    Description,
+    Language,
+    Company,
+    Country,
+    CountryName,
+    Region,
+    City,
+    Source,
+    Industry,
+    Technology,
+    Category,
+    Person,
+    WebsiteState,
+    Stars,
+    Forks,
+    Followers,
+    People,
+    Score,
+    Contactable,
+    Archived,
+    ScrapedAt,
+    PushedAt,
+    LastReleaseAt,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name.to_lowercase().as_str() {
+            "name" => Field::Name,
+            "website" => Field::Website,
+            "domain" | "canonical_domain" => Field::Domain,
+            "repository" | "repo" => Field::Repository,
+            "email" => Field::Email,
+            "github_email" => Field::GithubEmail,
+            "email_status" => Field::EmailStatus,
+            "email_kind" => Field::EmailKind,
+            "linkedin" => Field::Linkedin,
+            "twitter" => Field::Twitter,
+            "phone" => Field::Phone,
+            "description" => Field::Description,
+            "language" => Field::Language,
+            "company" => Field::Company,
+            "country" => Field::Country,
+            "country_name" => Field::CountryName,
+            "region" => Field::Region,
+            "city" => Field::City,
+            "source" => Field::Source,
+            "industry" | "industries" => Field::Industry,
+            "technology" | "technologies" => Field::Technology,
+            "category" | "categories" => Field::Category,
+            "person" | "people_names" => Field::Person,
+            "website_state" => Field::WebsiteState,
+            "stars" => Field::Stars,
+            "forks" => Field::Forks,
+            "followers" => Field::Followers,
+            "people" => Field::People,
+            "score" => Field::Score,
+            "contactable" => Field::Contactable,
+            "archived" => Field::Archived,
+            "scraped_at" | "seen" => Field::ScrapedAt,
+            "pushed_at" | "last_push" => Field::PushedAt,
+            "last_release_at" => Field::LastReleaseAt,
+            _ => return None,
+        })
+    }
+
+    fn value(self, lead: &Lead, role_mailboxes: &[String]) -> Value {
+        let text = |value: Option<&str>| match value {
+            Some(value) if !value.is_empty() => Value::Text(vec![value.to_string()]),
+            _ => Value::Missing,
+        };
+        let list = |values: Vec<String>| {
+            if values.is_empty() {
+                Value::Missing
+            } else {
+                Value::Text(values)
+            }
+        };
+        let location = lead.location.as_ref();
+        let health = lead.repo_health.as_ref();
+        let email = lead.email.as_deref().or(lead.github_email.as_deref());
+
+        match self {
+            Field::Name => text(Some(&lead.name)),
+            Field::Website => text(lead.website.as_deref()),
+            Field::Domain => text(lead.canonical_domain.as_deref()),
+            Field::Repository => text(lead.repository.as_deref()),
+            Field::Email => text(lead.email.as_deref()),
+            Field::GithubEmail => text(lead.github_email.as_deref()),
+            Field::EmailStatus => text(
+                email
+                    .and_then(|email| lead.email_status(email))
+                    .map(|status| status.to_string())
+                    .as_deref(),
+            ),
+            Field::EmailKind => text(email.map(|email| {
+                if is_role_address_in(email, role_mailboxes) {
+                    "role"
+                } else {
+                    "personal"
+                }
+            })),
+            Field::Linkedin => text(lead.linkedin.as_deref()),
+            Field::Twitter => text(lead.twitter.as_deref()),
+            Field::Phone => list(lead.phones.iter().map(|p| p.e164.clone()).collect()),
+            Field::Description => text(lead.description.as_deref()),
+            Field::Language => text(lead.language.as_deref()),
+            Field::Company => text(lead.company.as_deref()),
+            Field::Country => text(lead.country_code()),
+            Field::CountryName => text(lead.country()),
+            Field::Region => text(location.and_then(|l| l.region.as_deref())),
+            Field::City => text(location.and_then(|l| l.city.as_deref())),
+            Field::Source => text(Some(source_kind(&lead.source))),
+            Field::Industry => list(
+                lead.industries
+                    .iter()
+                    .flat_map(|tag| [tag.name.clone(), tag.label.clone()])
+                    .collect(),
+            ),
+            Field::Technology => list(lead.technologies.iter().map(|t| t.name.clone()).collect()),
+            Field::Category => list(lead.categories.clone()),
+            Field::Person => list(lead.people.iter().map(|p| p.name.clone()).collect()),
+            Field::WebsiteState => text(
+                lead.website_status
+                    .as_ref()
+                    .map(|status| status.state.to_string())
+                    .as_deref(),
+            ),
+            Field::Stars => health.map_or(Value::Missing, |h| Value::Number(h.stars as f64)),
+            Field::Forks => health.map_or(Value::Missing, |h| Value::Number(h.forks as f64)),
+            Field::Followers => lead
+                .owner_followers
+                .map_or(Value::Missing, |f| Value::Number(f as f64)),
+            Field::People => Value::Number(lead.people.len() as f64),
+            Field::Score => lead
+                .score
+                .as_ref()
+                .map_or(Value::Missing, |score| Value::Number(score.total as f64)),
+            Field::Contactable => Value::Bool(lead.has_contact()),
+            Field::Archived => health.map_or(Value::Missing, |h| Value::Bool(h.archived)),
+            Field::ScrapedAt => Value::Date(lead.scraped_at),
+            Field::PushedAt => health
+                .and_then(|h| h.pushed_at)
+                .map_or(Value::Missing, Value::Date),
+            Field::LastReleaseAt => health
+                .and_then(|h| h.last_release_at)
+                .map_or(Value::Missing, Value::Date),
+        }
+    }
+}
+
+/// Source as named in queries and scoring rules (`betalist`, `github_awesome`)
+fn source_kind(source: &Source) -> &'static str {
+    match source {
+        Source::YCombinator => "ycombinator",
+        Source::GitHubAwesome { .. } => "github_awesome",
+        Source::BetaList => "betalist",
+        Source::GitHubSearch { .. } => "github_search",
+        Source::PackageRegistry { .. } => "package_registry",
+        Source::Website { .. } => "website",
+    }
+}
+
+/// Field value of a lead; text fields may hold several values
+#[derive(Debug, Clone)]
+enum Value {
+    Missing,
+    Text(Vec<String>),
+    Number(f64),
+    Date(DateTime<Utc>),
+    Bool(bool),
+}
+
+impl Literal {
+    fn number(&self) -> Option<f64> {
+        self.0.parse().ok()
+    }
+
+    fn date(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if let Some(days) = self.0.strip_suffix('d').and_then(|d| d.parse::<i64>().ok()) {
+            return Some(now - Duration::days(days));
+        }
+        NaiveDate::parse_from_str(&self.0, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .map(|date| date.and_utc())
+    }
+
+    fn bool(&self) -> Option<bool> {
+        match self.0.to_lowercase().as_str() {
+            "true" | "yes" => Some(true),
+            "false" | "no" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl Value {
+    fn compare(&self, op: Op, literal: &Literal, now: DateTime<Utc>) -> bool {
+        use std::cmp::Ordering;
+        let ordered = |ordering: Option<Ordering>| {
+            ordering.is_some_and(|ordering| match op {
+                Op::Eq => ordering == Ordering::Equal,
+                Op::Ne => ordering != Ordering::Equal,
+                Op::Lt => ordering == Ordering::Less,
+                Op::Le => ordering != Ordering::Greater,
+                Op::Gt => ordering == Ordering::Greater,
+                Op::Ge => ordering != Ordering::Less,
+                Op::Contains => false,
+            })
+        };
+        match self {
+            Value::Missing => false,
+            Value::Text(values) => {
+                let wanted = literal.0.to_lowercase();
+                let any = |test: &dyn Fn(&str) -> bool| {
+                    values.iter().any(|value| test(&value.to_lowercase()))
+                };
+                match op {
+                    Op::Eq => any(&|value| value == wanted),
+                    Op::Ne => !any(&|value| value == wanted),
+                    Op::Contains => any(&|value| value.contains(&wanted)),
+                    _ => any(&|value| ordered(Some(value.cmp(wanted.as_str())))),
+                }
+            }
+            Value::Number(number) => ordered(
+                literal
+                    .number()
+                    .and_then(|wanted| number.partial_cmp(&wanted)),
+            ),
+            Value::Date(date) => {
+                // Whole days: `scraped_at = 2025-05-01` means that day
+                let day = date.date_naive();
+                ordered(
+                    literal
+                        .date(now)
+                        .map(|wanted| day.cmp(&wanted.date_naive())),
+                )
+            }
+            Value::Bool(value) => ordered(literal.bool().map(|wanted| value.cmp(&wanted))),
+        }
+    }
+
+    fn exists(&self) -> bool {
+        match self {
+            Value::Missing => false,
+            Value::Text(values) => values.iter().any(|value| !value.is_empty()),
+            Value::Bool(value) => *value,
+            Value::Number(_) | Value::Date(_) => true,
+        }
+    }
+}
+
+impl Query {
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+        };
+        let expr = parser.or()?;
+        match parser.peek() {
+            None => Ok(Self {
+                expr,
+                role_mailboxes: default_role_mailboxes(),
+            }),
+            Some(token) => Err(query_error(format!("unexpected '{}'", token.text()))),
+        }
+    }
+
+    /// Classify `email_kind` with the scoring model's mailboxes rather
+    /// than the built-in list
+    pub fn with_role_mailboxes(mut self, role_mailboxes: &[String]) -> Self {
+        self.role_mailboxes = role_mailboxes.to_vec();
+        self
+    }
+
+    pub fn matches(&self, lead: &Lead) -> bool {
+        self.evaluate(&self.expr, lead, Utc::now())
+    }
+
+    /// Matching leads, in order
+    pub fn filter<'a>(&self, leads: &'a [Lead]) -> Vec<&'a Lead> {
+        let now = Utc::now();
+        leads
+            .iter()
+            .filter(|lead| self.evaluate(&self.expr, lead, now))
+            .collect()
+    }
+
+    fn evaluate(&self, expr: &Expr, lead: &Lead, now: DateTime<Utc>) -> bool {
+        let value = |field: &Field| field.value(lead, &self.role_mailboxes);
+        match expr {
+            Expr::And(left, right) => {
+                self.evaluate(left, lead, now) && self.evaluate(right, lead, now)
+            }
+            Expr::Or(left, right) => {
+                self.evaluate(left, lead, now) || self.evaluate(right, lead, now)
+            }
+            Expr::Not(inner) => !self.evaluate(inner, lead, now),
+            Expr::Exists(field) => value(field).exists(),
+            Expr::Compare(field, op, literal) => value(field).compare(*op, literal, now),
+            Expr::In(field, literals) => {
+                let value = value(field);
+                literals
+                    .iter()
+                    .any(|literal| value.compare(Op::Eq, literal, now))
+            }
+            Expr::Between(field, low, high) => {
+                let value = value(field);
+                value.compare(Op::Ge, low, now) && value.compare(Op::Le, high, now)
+            }
+            Expr::Matches(field, regex) => match value(field) {
+                Value::Text(values) => values.iter().any(|value| regex.is_match(value)),
+                _ => false,
+            },
+        }
+    }
+}
+
+impl FromStr for Query {
+    type Err = ScrapingError;
+
+    fn from_str(input: &str) -> Result<Self> {
+        Self::parse(input)
+    }
+}
+
+fn query_error(message: String) -> ScrapingError {
+    ScrapingError::ParseError(format!("Invalid query: {}", message))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Symbol(&'static str),
+}
+
+impl Token {
+    fn text(&self) -> &str {
+        match self {
+            Token::Word(text) | Token::Quoted(text) => text,
+            Token::Symbol(symbol) => symbol,
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Token::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    const SYMBOLS: &[&str] = &[
+        "!=", ">=", "<=", "=", ">", "<", "~", "(", ")", "[", "]", ",",
+    ];
+    let mut tokens = Vec::new();
+    let mut rest = input.trim_start();
+    while !rest.is_empty() {
+        if let Some(quote) = rest.chars().next().filter(|c| *c == '"' || *c == '\'') {
+            let body = &rest[1..];
+            let end = body
+                .find(quote)
+                .ok_or_else(|| query_error(format!("unterminated string {}", rest)))?;
+            tokens.push(Token::Quoted(body[..end].to_string()));
+            rest = &body[end + 1..];
+        } else if let Some(symbol) = SYMBOLS.iter().find(|symbol| rest.starts_with(**symbol)) {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || "!=<>~()[],\"'".contains(c))
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return Err(query_error(format!("unexpected character in '{}'", rest)));
+            }
+            tokens.push(Token::Word(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or_else(|| query_error("unexpected end of query".to_string()))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek().is_some_and(|token| token.is_keyword(keyword));
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<()> {
+        match self.next()? {
+            Token::Symbol(found) if found == symbol => Ok(()),
+            token => Err(query_error(format!(
+                "expected '{}', found '{}'",
+                symbol,
+                token.text()
+            ))),
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.eat_keyword("or") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.not()?;
+        while self.eat_keyword("and") {
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr> {
+        if self.eat_keyword("not") {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        if self.peek() == Some(&Token::Symbol("(")) {
+            self.position += 1;
+            let expr = self.or()?;
+            self.expect_symbol(")")?;
+            return Ok(expr);
+        }
+        if self.eat_keyword("exists") {
+            return Ok(Expr::Exists(self.field()?));
+        }
+
+        let field = self.field()?;
+        let Some(token) = self.peek().cloned() else {
+            return Ok(Expr::Exists(field));
+        };
+        let op = match token {
+            Token::Symbol("=") => Op::Eq,
+            Token::Symbol("!=") => Op::Ne,
+            Token::Symbol("<") => Op::Lt,
+            Token::Symbol("<=") => Op::Le,
+            Token::Symbol(">") => Op::Gt,
+            Token::Symbol(">=") => Op::Ge,
+            Token::Symbol("~") => {
+                self.position += 1;
+                return self.regex(field);
+            }
+            Token::Word(ref word) if word.eq_ignore_ascii_case("matches") => {
+                self.position += 1;
+                return self.regex(field);
+            }
+            Token::Word(ref word) if word.eq_ignore_ascii_case("contains") => Op::Contains,
+            Token::Word(ref word) if word.eq_ignore_ascii_case("in") => {
+                self.position += 1;
+                return self.list(field);
+            }
+            Token::Word(ref word) if word.eq_ignore_ascii_case("between") => {
+                self.position += 1;
+                let low = self.literal()?;
+                if !self.eat_keyword("and") {
+                    return Err(query_error("expected 'and' after 'between'".to_string()));
+                }
+                return Ok(Expr::Between(field, low, self.literal()?));
+            }
+            // A bare boolean field, e.g. `contactable and ...`
+            _ => return Ok(Expr::Exists(field)),
+        };
+        self.position += 1;
+        Ok(Expr::Compare(field, op, self.literal()?))
+    }
+
+    fn field(&mut self) -> Result<Field> {
+        match self.next()? {
+            Token::Word(name) => {
+                Field::parse(&name).ok_or_else(|| query_error(format!("unknown field '{}'", name)))
+            }
+            token => Err(query_error(format!(
+                "expected a field, found '{}'",
+                token.text()
+            ))),
+        }
+    }
+
+    fn literal(&mut self) -> Result<Literal> {
+        match self.next()? {
+            Token::Word(text) | Token::Quoted(text) => Ok(Literal(text)),
+            Token::Symbol(symbol) => {
+                Err(query_error(format!("expected a value, found '{}'", symbol)))
+            }
+        }
+    }
+
+    fn list(&mut self, field: Field) -> Result<Expr> {
+        self.expect_symbol("[")?;
+        let mut literals = vec![self.literal()?];
+        while self.peek() == Some(&Token::Symbol(",")) {
+            self.position += 1;
+            literals.push(self.literal()?);
+        }
+        self.expect_symbol("]")?;
+        Ok(Expr::In(field, literals))
+    }
+
+    fn regex(&mut self, field: Field) -> Result<Expr> {
+        let Literal(pattern) = self.literal()?;
+        let regex = Regex::new(&pattern).map_err(|e| {
+            ScrapingError::RegexError(format!("Invalid query regex '{}': {}", pattern, e))
+        })?;
+        Ok(Expr::Matches(field, regex))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IndustryTag, Location, RepoHealth};
+
+    fn lead() -> Lead {
+        let mut lead = Lead::new("Acme Cloud".to_string(), Source::BetaList)
+            .with_email(Some("jane@acme.de".to_string()))
+            .with_website(Some("https://acme.de".to_string()));
+        lead.location = Some(Location {
+            country_code: Some("DE".to_string()),
+            country: Some("Germany".to_string()),
+            city: Some("Berlin".to_string()),
+            ..Location::default()
+        });
+        lead.industries = vec![IndustryTag {
+            name: "devtools".to_string(),
+            label: "Developer Tools".to_string(),
+            score: 4.0,
+            matched: Vec::new(),
+        }];
+        lead.repo_health = Some(RepoHealth {
+            stars: 420,
+            ..RepoHealth::default()
+        });
+        lead.scraped_at = "2025-06-15T10:00:00Z".parse().unwrap();
+        lead
+    }
+
+    #[test]
+    fn test_query_language() {
+        let lead = lead();
+        let matches = |query: &str| Query::parse(query).unwrap().matches(&lead);
+
+        assert!(matches(
+            "contactable and industry = devtools and country = de and scraped_at >= 2025-05-01"
+        ));
+        assert!(matches(
+            "country in [AT, DE, CH] and stars between 100 and 500"
+        ));
+        assert!(matches("name contains cloud and website ~ '\\.de$'"));
+        assert!(matches(
+            "industry = 'Developer Tools' and email_kind = personal"
+        ));
+        assert!(matches("exists email and not exists twitter"));
+        assert!(matches(
+            "(country = FR or city = berlin) and source = betalist"
+        ));
+        assert!(matches("scraped_at = 2025-06-15 and scraped_at < 1d"));
+        assert!(!matches("stars > 500 or country != DE"));
+        assert!(!matches("archived"));
+        assert!(!matches("score >= 1"));
+
+        for invalid in [
+            "",
+            "country =",
+            "nonsense = 1",
+            "country in [DE",
+            "(country = DE",
+            "name ~ '('",
+            "country = DE extra",
+            "name = 'unterminated",
+        ] {
+            assert!(Query::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_email_kind_uses_configured_role_mailboxes() {
+        let mut lead = lead();
+        lead.email = Some("founders@acme.de".to_string());
+        let query = Query::parse("email_kind = role").unwrap();
+        assert!(!query.matches(&lead));
+
+        let query = query.with_role_mailboxes(&["founders".to_string()]);
+        assert!(query.matches(&lead));
+
+        lead.email = Some("info@acme.de".to_string());
+        assert!(!query.matches(&lead));
+    }
+}
@@ -0,0 +1,35 @@
+use crate::errors::{Result, ScrapingError};
+use crate::models::Lead;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File holding every lead of a run
+pub const ALL_LEADS_FILE: &str = "all_leads.json";
+
+/// Leads from a run directory (its `all_leads.json`) or a leads JSON file
+pub fn load_leads(path: &str) -> Result<Vec<Lead>> {
+    let path = Path::new(path);
+    let file = if path.is_dir() {
+        path.join(ALL_LEADS_FILE)
+    } else {
+        path.to_path_buf()
+    };
+    let content = fs::read_to_string(&file).map_err(|e| {
+        ScrapingError::IoError(format!("Failed to read leads '{}': {}", file.display(), e))
+    })?;
+    serde_json::from_str(&content).map_err(|e| {
+        ScrapingError::ParseError(format!("Invalid leads file '{}': {}", file.display(), e))
+    })
+}
+
+/// Most recent run under an output directory. Run folders start with their
+/// `%Y%m%d_%H%M%S` timestamp, so the newest sorts last.
+pub fn latest_run(output_dir: &str) -> Option<PathBuf> {
+    let mut runs: Vec<PathBuf> = fs::read_dir(output_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.join(ALL_LEADS_FILE).is_file())
+        .collect();
+    runs.sort();
+    runs.pop()
+}
@@ -0,0 +1,213 @@
+use crate::errors::{Result, ScrapingError};
+use crate::filter::{default_role_mailboxes, LeadFilter};
+use crate::models::{Lead, LeadScore, ScoreReason};
+use serde::Deserialize;
+use std::fs;
+
+/// Scoring model as written in `config/scoring.yaml`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Model {
+    /// Local parts treated as shared mailboxes by `email_kind`
+    #[serde(default = "default_role_mailboxes")]
+    role_mailboxes: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Rule {
+    name: String,
+    description: Option<String>,
+    points: f32,
+    /// All conditions must hold; no conditions always fires
+    #[serde(default)]
+    when: LeadFilter,
+}
+
+/// Scores leads with weighted rules over their attributes
+#[derive(Debug, Clone)]
+pub struct LeadScorer {
+    role_mailboxes: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+impl LeadScorer {
+    /// Load a scoring model file
+    pub fn load(path: &str) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ScrapingError::ConfigError(format!("Failed to read scoring model '{}': {}", path, e))
+        })?;
+        Self::from_yaml(&content).map_err(|e| {
+            ScrapingError::ConfigError(format!("Invalid scoring model '{}': {}", path, e))
+        })
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let model: Model = serde_yaml::from_str(content)?;
+        let rules = model
+            .rules
+            .into_iter()
+            .map(|rule| Rule {
+                when: rule.when.with_role_mailboxes(&model.role_mailboxes),
+                ..rule
+            })
+            .collect();
+        Ok(Self {
+            role_mailboxes: model.role_mailboxes,
+            rules,
+        })
+    }
+
+    /// Shared mailboxes of the model, for segments to agree with scoring
+    pub fn role_mailboxes(&self) -> &[String] {
+        &self.role_mailboxes
+    }
+
+    /// Total of the rules a lead matches, with the rules that fired
+    pub fn score(&self, lead: &Lead) -> LeadScore {
+        let reasons: Vec<ScoreReason> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.when.matches(lead))
+            .map(|rule| ScoreReason {
+                rule: rule.name.clone(),
+                points: rule.points,
+                description: rule.description.clone(),
+            })
+            .collect();
+        LeadScore {
+            total: reasons.iter().map(|reason| reason.points).sum(),
+            reasons,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{EmailStatus, EmailVerdict, RepoHealth, Source, WebsiteState};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn test_score_with_shipped_model() {
+        let scorer = LeadScorer::from_yaml(include_str!("../config/scoring.yaml"))
+            .expect("shipped scoring model is valid");
+
+        let mut lead = Lead::new("Acme".to_string(), Source::BetaList)
+            .with_email(Some("jane@acme.dev".to_string()))
+            .with_linkedin(Some("https://linkedin.com/company/acme".to_string()));
+        lead.email_verdicts.insert(
+            "jane@acme.dev".to_string(),
+            EmailVerdict {
+                email: "jane@acme.dev".to_string(),
+                status: EmailStatus::Deliverable,
+                syntax_valid: true,
+                has_mail_server: Some(true),
+                disposable: false,
+                free_provider: false,
+                smtp_accepted: Some(true),
+                catch_all: Some(false),
+                reason: None,
+            },
+        );
+        lead.repo_health = Some(RepoHealth {
+            stars: 1200,
+            pushed_at: Some(Utc::now() - Duration::days(3)),
+            ..RepoHealth::default()
+        });
+
+        let score = scorer.score(&lead);
+        let fired: Vec<&str> = score.reasons.iter().map(|r| r.rule.as_str()).collect();
+        for rule in [
+            "direct_email",
+            "personal_email",
+            "deliverable_email",
+            "linkedin",
+            "popular_repository",
+            "recently_active",
+        ] {
+            assert!(fired.contains(&rule), "{} not in {:?}", rule, fired);
+        }
+        assert!(!fired.contains(&"role_email"));
+        assert_eq!(
+            score.total,
+            score.reasons.iter().map(|r| r.points).sum::<f32>()
+        );
+
+        // Shared inbox on a dead website
+        let mut lead = Lead::new("Gone".to_string(), Source::BetaList)
+            .with_email(Some("Sales+Leads@gone.io".to_string()));
+        lead.website_status = Some(crate::models::WebsiteStatus {
+            final_url: None,
+            http_status: None,
+            redirected: false,
+            state: WebsiteState::Dead,
+            error: Some("dns error".to_string()),
+            checked_at: Utc::now(),
+        });
+        let fired: Vec<String> = scorer
+            .score(&lead)
+            .reasons
+            .into_iter()
+            .map(|r| r.rule)
+            .collect();
+        assert!(fired.contains(&"role_email".to_string()));
+        assert!(fired.contains(&"dead_website".to_string()));
+        assert!(!fired.contains(&"personal_email".to_string()));
+    }
+
+    #[test]
+    fn test_rule_conditions() {
+        let scorer = LeadScorer::from_yaml(
+            r#"
+rules:
+  - name: german_devtools
+    points: 2.5
+    when: { country: [de], industry: [devtools], stars: { min: 100, max: 5000 } }
+  - name: no_contact
+    points: -3
+    when: { missing: [email, github_email] }
+  - name: yc
+    points: 1
+    when: { source: [ycombinator] }
+"#,
+        )
+        .unwrap();
+        assert!(LeadScorer::from_yaml(
+            "rules:\n  - name: typo\n    points: 1\n    when: { contry: [de] }\n"
+        )
+        .is_err());
+
+        let lead = Lead::new("Nobody".to_string(), Source::YCombinator);
+        let score = scorer.score(&lead);
+        assert_eq!(score.total, -2.0);
+        assert_eq!(score.reasons.len(), 2);
+        assert_eq!(score.reasons[0].rule, "no_contact");
+        // Configured shared mailboxes replace the defaults
+        let scorer = LeadScorer::from_yaml(
+            "role_mailboxes: [founders]\nrules:\n  - name: role\n    points: -1\n    when: { email_kind: [role] }\n",
+        )
+        .unwrap();
+        assert_eq!(scorer.role_mailboxes(), ["founders"]);
+        let lead = |email: &str| {
+            Lead::new("Acme".to_string(), Source::BetaList).with_email(Some(email.to_string()))
+        };
+        assert_eq!(scorer.score(&lead("founders@acme.dev")).total, -1.0);
+        assert_eq!(scorer.score(&lead("info@acme.dev")).total, 0.0);
+
+        // Archived repositories are not active, however recent their last push
+        let scorer = LeadScorer::from_yaml(
+            "rules:\n  - name: active\n    points: 1\n    when: { active_within_days: 30 }\n",
+        )
+        .unwrap();
+        let mut lead = lead("jane@acme.dev");
+        lead.repo_health = Some(RepoHealth {
+            pushed_at: Some(Utc::now() - Duration::days(3)),
+            ..RepoHealth::default()
+        });
+        assert_eq!(scorer.score(&lead).total, 1.0);
+        lead.repo_health.as_mut().unwrap().archived = true;
+        assert_eq!(scorer.score(&lead).total, 0.0);
+    }
+}
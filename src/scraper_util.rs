@@ -1,52 +1,226 @@
 use crate::{
     config::Config,
+    email::EmailValidator,
     errors::{Result, ScrapingError},
     extractors::DataExtractor,
-    models::{Lead, LeadStats, RunMetadata},
-    scrapers::create_scrapers,
+    forges::ForgeRegistry,
+    language::page_language,
+    models::{EmailStatus, Lead, LeadStats, RunMetadata, SegmentStats, WebsiteState},
+    scoring::LeadScorer,
+    scrapers::{create_scrapers, BaseScraper, RequestPlan},
+    segments::segment_leads,
+    tagging::Tagger,
+    technologies::{PageSnapshot, TechnologyDetector},
+    website::{self, WebsiteChecker},
 };
-use reqwest::Client;
+use reqwest::{redirect::Policy, Client};
+use scraper::Html;
+use serde::Serialize;
+use std::collections::HashMap;
 use std::time::Duration;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
+use url::Url;
+
+/// Pages commonly listing founders and team members, tried in order
+const TEAM_PAGE_PATHS: &[&str] = &["/team", "/about", "/about-us", "/company"];
+
+/// Redirects the HTTP client follows before giving up
+const MAX_REDIRECTS: usize = 10;
+
+/// Visible text of a fetched page
+fn page_text(html: &str) -> String {
+    Html::parse_document(html)
+        .root_element()
+        .text()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Requests a run would make, source by source (`--dry-run`)
+#[derive(Debug, Clone, Serialize)]
+pub struct RunPlan {
+    pub sources: Vec<SourcePlan>,
+    /// Website checks, team/contact pages, homepages and email validation
+    pub enrichment_requests_per_lead: usize,
+    /// Sources and enrichment together, over the expected leads
+    pub estimated_requests: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SourcePlan {
+    pub source: &'static str,
+    pub expected_leads: Option<usize>,
+    pub requests: RequestPlan,
+    pub estimated_requests: usize,
+}
 
 /// Main lead scraper orchestrator - now simplified and using modular scrapers
 pub struct LeadScraper {
     client: Client,
     config: Config,
     extractor: DataExtractor,
+    technologies: Option<TechnologyDetector>,
+    tagger: Option<Tagger>,
+    email_validator: Option<EmailValidator>,
+    websites: WebsiteChecker,
+    scorer: Option<LeadScorer>,
 }
 
 impl LeadScraper {
-    pub async fn new(config: Config) -> Result<Self> {
+    pub async fn new(mut config: Config) -> Result<Self> {
         let client = Client::builder()
             .user_agent(&config.scraper.user_agent)
             .timeout(Duration::from_secs(config.scraper.timeout_seconds))
+            .redirect(Policy::limited(MAX_REDIRECTS))
             .build()
             .map_err(|e| {
                 ScrapingError::NetworkError(format!("Failed to create HTTP client: {}", e))
             })?;
 
         let github_token = config.scraper.github_token.clone();
+        let forges =
+            ForgeRegistry::from_config(client.clone(), &config.forges, github_token.clone());
         let extractor = DataExtractor::new(&config.patterns, client.clone(), github_token)
             .map_err(|e| {
                 ScrapingError::ExtractionError(format!(
                     "Failed to initialize data extractor: {}",
                     e
                 ))
-            })?;
+            })?
+            .with_forges(forges)
+            .with_commit_mining(config.forges.commits.clone())
+            .with_metadata_files(config.forges.metadata_files)
+            .with_repository_enrichment(config.forges.enrich_repositories);
+
+        let technologies = if config.technologies.enabled {
+            Some(TechnologyDetector::load(&config.technologies.rules)?)
+        } else {
+            None
+        };
+
+        let tagger = if config.tagging.enabled {
+            Some(Tagger::load(&config.tagging.taxonomy)?)
+        } else {
+            None
+        };
+
+        let email_validator = if config.email_validation.enabled {
+            Some(EmailValidator::new(&config.email_validation)?)
+        } else {
+            None
+        };
+
+        let websites = WebsiteChecker::new(client.clone(), &config.websites);
+
+        let scorer = if config.scoring.enabled {
+            Some(LeadScorer::load(&config.scoring.model)?)
+        } else {
+            None
+        };
+        // Segments tell shared mailboxes apart the way the scoring model does
+        if let Some(ref scorer) = scorer {
+            for segment in &mut config.segments {
+                segment.when.role_mailboxes = Some(scorer.role_mailboxes().to_vec());
+            }
+        }
 
         Ok(Self {
             client,
             config,
             extractor,
+            technologies,
+            tagger,
+            email_validator,
+            websites,
+            scorer,
         })
     }
 
-    /// Scrape all enabled sources using the modular scraper architecture
+    /// Scrape all enabled sources and enrich the leads
     pub async fn scrape_all_sources(&self) -> Result<Vec<Lead>> {
+        let leads = self.collect_leads().await?;
+        let leads = self.enrich(leads).await;
+
+        info!("   📊 Total leads extracted: {}", leads.len());
+        if leads.is_empty() {
+            warn!(
+                "🚨 No leads extracted from any source! Check your config and network connection."
+            );
+        } else {
+            // Log some quick stats
+            let contactable = leads.iter().filter(|l| self.has_contact_info(l)).count();
+            let contact_rate = (contactable as f32 / leads.len() as f32) * 100.0;
+            info!(
+                "   📧 Contactable leads: {} ({:.1}%)",
+                contactable, contact_rate
+            );
+        }
+
+        Ok(leads)
+    }
+
+    /// What a run would fetch, without fetching anything
+    pub fn plan(&self) -> Result<RunPlan> {
+        let sources: Vec<SourcePlan> =
+            create_scrapers(&self.config, &self.client, &self.extractor)?
+                .iter()
+                .map(|scraper| {
+                    let expected_leads = scraper.expected_leads_count();
+                    let requests = scraper.request_plan();
+                    SourcePlan {
+                        source: scraper.source_name(),
+                        estimated_requests: requests
+                            .estimated_requests(expected_leads.unwrap_or_default()),
+                        expected_leads,
+                        requests,
+                    }
+                })
+                .collect();
+
+        let enrichment_requests_per_lead = self.enrichment_requests_per_lead();
+        let expected_leads: usize = sources
+            .iter()
+            .filter_map(|source| source.expected_leads)
+            .sum();
+        let estimated_requests = sources
+            .iter()
+            .map(|source| source.estimated_requests)
+            .sum::<usize>()
+            + expected_leads * enrichment_requests_per_lead;
+        Ok(RunPlan {
+            sources,
+            enrichment_requests_per_lead,
+            estimated_requests,
+        })
+    }
+
+    /// Requests `enrich` makes per lead at most, not counting redirects
+    fn enrichment_requests_per_lead(&self) -> usize {
+        let mut requests = 0;
+        if self.config.websites.check {
+            // The website, then every redirect hop
+            requests += 1 + MAX_REDIRECTS;
+        }
+        if self.config.scraper.team_pages {
+            // Homepage, English paths, then the contact pages of the site's
+            // language; the largest localized set bounds any lead
+            requests += 1 + TEAM_PAGE_PATHS.len() + self.extractor.max_contact_pages();
+        }
+        if self.technologies.is_some() || self.config.tagging.website_metadata {
+            requests += 1;
+        }
+        if self.email_validator.is_some() {
+            // MX lookup, then an SMTP probe
+            requests += 1 + usize::from(self.config.email_validation.smtp.enabled);
+        }
+        requests
+    }
+
+    /// Raw leads of every enabled source, without enrichment
+    pub async fn collect_leads(&self) -> Result<Vec<Lead>> {
         info!("🚀 Starting lead extraction from all enabled sources...");
 
-        let scrapers = create_scrapers(&self.config, &self.client, &self.extractor);
+        let scrapers = create_scrapers(&self.config, &self.client, &self.extractor)?;
 
         if scrapers.is_empty() {
             warn!("⚠️  No scrapers enabled in configuration!");
@@ -85,36 +259,298 @@ impl LeadScraper {
             }
         }
 
-        // Summary
         info!("🎯 Scraping Summary:");
         info!("   ✅ Successful scrapers: {}", successful_scrapers);
         if failed_scrapers > 0 {
             info!("   ❌ Failed scrapers: {}", failed_scrapers);
         }
-        info!("   📊 Total leads extracted: {}", all_leads.len());
 
-        if all_leads.is_empty() {
-            warn!(
-                "🚨 No leads extracted from any source! Check your config and network connection."
+        Ok(all_leads)
+    }
+
+    /// Canonicalize, dedupe, enrich, tag, validate and score leads, either
+    /// freshly scraped or loaded from an earlier run
+    pub async fn enrich(&self, mut leads: Vec<Lead>) -> Vec<Lead> {
+        for lead in leads.iter_mut() {
+            self.websites.canonicalize(lead, self.extractor.forges());
+        }
+        if self.config.websites.check {
+            self.check_websites(&mut leads).await;
+        }
+        if self.config.websites.dedupe {
+            let before = leads.len();
+            leads = website::dedupe(leads);
+            info!(
+                "🔗 Merged {} duplicate leads ({} unique)",
+                before - leads.len(),
+                leads.len()
             );
-        } else {
-            // Log some quick stats
-            let contactable = all_leads
-                .iter()
-                .filter(|l| self.has_contact_info(l))
-                .count();
-            let contact_rate = if all_leads.len() > 0 {
-                (contactable as f32 / all_leads.len() as f32) * 100.0
-            } else {
-                0.0
-            };
+        }
+
+        if self.config.scraper.team_pages {
+            self.enrich_team_pages(&mut leads).await;
+        }
+        if self.technologies.is_some() || self.config.tagging.website_metadata {
+            self.enrich_homepages(&mut leads).await;
+        }
+        if let Some(ref tagger) = self.tagger {
+            for lead in leads.iter_mut() {
+                lead.industries = tagger.tag(lead);
+            }
+            let tagged = leads.iter().filter(|l| !l.industries.is_empty()).count();
             info!(
-                "   📧 Contactable leads: {} ({:.1}%)",
-                contactable, contact_rate
+                "🏷️  Tagged {} of {} leads with industries",
+                tagged,
+                leads.len()
             );
         }
 
-        Ok(all_leads)
+        if let Some(ref validator) = self.email_validator {
+            self.validate_emails(validator, &mut leads).await;
+        }
+        self.score_leads(&mut leads);
+
+        leads
+    }
+
+    /// Re-check the websites and emails of existing leads, as enabled in
+    /// `websites.check` and `email_validation`, and rescore them
+    pub async fn validate(&self, leads: &mut [Lead]) {
+        if self.config.websites.check {
+            for lead in leads.iter_mut() {
+                self.websites.canonicalize(lead, self.extractor.forges());
+            }
+            self.check_websites(leads).await;
+        }
+        if let Some(ref validator) = self.email_validator {
+            // Earlier verdicts may be stale: check every address again
+            for lead in leads.iter_mut() {
+                lead.email_verdicts.clear();
+            }
+            self.validate_emails(validator, leads).await;
+        }
+        self.score_leads(leads);
+    }
+
+    /// Score leads with the configured model, best leads first
+    fn score_leads(&self, leads: &mut [Lead]) {
+        let Some(ref scorer) = self.scorer else {
+            return;
+        };
+        for lead in leads.iter_mut() {
+            lead.score = Some(scorer.score(lead));
+        }
+        leads.sort_by(|a, b| b.score_total().total_cmp(&a.score_total()));
+        if let Some(top) = leads.first() {
+            info!(
+                "🏅 Scored {} leads (top: {} at {})",
+                leads.len(),
+                top.name,
+                top.score_total()
+            );
+        }
+    }
+
+    /// Follow each lead website's redirects and record where it ended up
+    async fn check_websites(&self, leads: &mut [Lead]) {
+        info!("🌐 Checking {} lead websites...", leads.len());
+        let base = BaseScraper::new(self.client.clone(), self.extractor.clone());
+        for lead in leads.iter_mut() {
+            if lead.canonical_domain.is_none() {
+                continue;
+            }
+            self.websites
+                .check_lead(lead, self.extractor.forges())
+                .await;
+            base.rate_limit(250).await;
+        }
+
+        let mut counts: HashMap<WebsiteState, usize> = HashMap::new();
+        for status in leads.iter().filter_map(|lead| lead.website_status.as_ref()) {
+            *counts.entry(status.state).or_insert(0) += 1;
+        }
+        let count = |state| counts.get(&state).copied().unwrap_or(0);
+        info!(
+            "🌐 Checked {} websites: {} live, {} parked, {} dead, {} unknown",
+            counts.values().sum::<usize>(),
+            count(WebsiteState::Live),
+            count(WebsiteState::Parked),
+            count(WebsiteState::Dead),
+            count(WebsiteState::Unknown)
+        );
+    }
+
+    /// Unchecked websites are assumed up; parked and dead ones are skipped
+    fn website_reachable(lead: &Lead) -> bool {
+        !matches!(
+            lead.website_status.as_ref().map(|status| status.state),
+            Some(WebsiteState::Parked | WebsiteState::Dead)
+        )
+    }
+
+    /// Collect people from the team/about pages of lead websites, and
+    /// contact details from their homepage and localized contact pages
+    async fn enrich_team_pages(&self, leads: &mut [Lead]) {
+        info!(
+            "👥 Looking for team pages on {} lead websites...",
+            leads.len()
+        );
+        let base = BaseScraper::new(self.client.clone(), self.extractor.clone());
+
+        for lead in leads
+            .iter_mut()
+            .filter(|lead| Self::website_reachable(lead))
+        {
+            let Some(root) = lead.website.as_deref().and_then(|w| Url::parse(w).ok()) else {
+                continue;
+            };
+            // Forge-hosted projects have no team page of their own
+            if root
+                .host_str()
+                .is_none_or(|host| self.extractor.forges().is_forge_host(host))
+            {
+                continue;
+            }
+
+            // The site's own language picks the localized contact pages;
+            // listing descriptions are mostly English whatever the site
+            let site_language = match base.fetch_html(root.as_str()).await {
+                Ok(html) => {
+                    let text = page_text(&html);
+                    self.extractor.apply_contact_details(lead, &text, &html);
+                    page_language(&text, &html)
+                }
+                Err(e) => {
+                    debug!("Failed to fetch homepage {}: {}", root, e);
+                    None
+                }
+            };
+
+            for path in TEAM_PAGE_PATHS {
+                let Ok(url) = root.join(path) else {
+                    continue;
+                };
+                match base.fetch_html(url.as_str()).await {
+                    Ok(html) => {
+                        // About pages usually carry the imprint/footer contact details
+                        let text = page_text(&html);
+                        self.extractor.apply_contact_details(lead, &text, &html);
+
+                        let people = self.extractor.extract_people(&html, "team page");
+                        if !people.is_empty() {
+                            debug!("Found {} people on {}", people.len(), url);
+                            for person in people {
+                                lead.add_person(person);
+                            }
+                            break;
+                        }
+                    }
+                    Err(e) => debug!("No team page at {}: {}", url, e),
+                }
+            }
+
+            // Localized contact and imprint pages ("/kontakt", "/impressum")
+            let contact_pages = self
+                .extractor
+                .contact_pages(site_language.as_deref().or(lead.language.as_deref()))
+                .to_vec();
+            for path in contact_pages {
+                let Ok(url) = root.join(&path) else {
+                    continue;
+                };
+                match base.fetch_html(url.as_str()).await {
+                    Ok(html) => {
+                        let text = page_text(&html);
+                        if lead.email.is_none() {
+                            lead.email = self.extractor.extract_email(&text, &html);
+                        }
+                        self.extractor.apply_contact_details(lead, &text, &html);
+                    }
+                    Err(e) => debug!("No contact page at {}: {}", url, e),
+                }
+            }
+
+            base.rate_limit(250).await;
+        }
+    }
+
+    /// Fetch the homepage of each lead website for technology fingerprints,
+    /// `<head>` metadata and footer contact details
+    async fn enrich_homepages(&self, leads: &mut [Lead]) {
+        info!("🧩 Fetching {} lead homepages...", leads.len());
+        let base = BaseScraper::new(self.client.clone(), self.extractor.clone());
+
+        for lead in leads
+            .iter_mut()
+            .filter(|lead| Self::website_reachable(lead))
+        {
+            let Some(website) = lead.website.clone() else {
+                continue;
+            };
+            if Url::parse(&website)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string))
+                .is_none_or(|host| self.extractor.forges().is_forge_host(&host))
+            {
+                continue;
+            }
+
+            let response = match self.client.get(&website).send().await {
+                Ok(response) if response.status().is_success() => response,
+                Ok(response) => {
+                    debug!("HTTP {} fingerprinting {}", response.status(), website);
+                    continue;
+                }
+                Err(e) => {
+                    debug!("Failed to fetch {} for fingerprinting: {}", website, e);
+                    continue;
+                }
+            };
+            match PageSnapshot::from_response(response).await {
+                Ok(page) => {
+                    if let Some(ref detector) = self.technologies {
+                        lead.technologies = detector.detect(&page);
+                        debug!(
+                            "Detected {} technologies on {}",
+                            lead.technologies.len(),
+                            website
+                        );
+                    }
+                    if self.config.tagging.website_metadata {
+                        lead.website_meta = self.extractor.extract_website_meta(&page.html);
+                    }
+                    // Footers often carry the phone number and address
+                    let text = page_text(&page.html);
+                    self.extractor
+                        .apply_contact_details(lead, &text, &page.html);
+                }
+                Err(e) => debug!("Failed to read {}: {}", website, e),
+            }
+
+            base.rate_limit(250).await;
+        }
+    }
+
+    /// Attach a deliverability verdict to every email found on the leads
+    async fn validate_emails(&self, validator: &EmailValidator, leads: &mut [Lead]) {
+        info!("📬 Validating lead emails...");
+        for lead in leads.iter_mut() {
+            validator.validate_lead(lead).await;
+        }
+
+        let mut counts: HashMap<EmailStatus, usize> = HashMap::new();
+        for verdict in leads.iter().flat_map(|lead| lead.email_verdicts.values()) {
+            *counts.entry(verdict.status).or_insert(0) += 1;
+        }
+        let count = |status| counts.get(&status).copied().unwrap_or(0);
+        info!(
+            "📬 Validated {} emails: {} deliverable, {} risky, {} undeliverable, {} unknown",
+            counts.values().sum::<usize>(),
+            count(EmailStatus::Deliverable),
+            count(EmailStatus::Risky),
+            count(EmailStatus::Undeliverable),
+            count(EmailStatus::Unknown)
+        );
     }
 
     /// Save leads to output directory with categorization and stats
@@ -166,8 +602,48 @@ impl LeadScraper {
             ScrapingError::IoError(format!("Failed to write research leads file: {}", e))
         })?;
 
+        // Per-segment outputs
+        if !self.config.segments.is_empty() {
+            let segments_dir = format!("{}/segments", output_path);
+            fs::create_dir_all(&segments_dir).map_err(|e| {
+                ScrapingError::IoError(format!(
+                    "Failed to create segments directory '{}': {}",
+                    segments_dir, e
+                ))
+            })?;
+            for (segment, members) in segment_leads(&self.config.segments, leads) {
+                let stem = segment.file_stem();
+                let segment_json = serde_json::to_string_pretty(&members).map_err(|e| {
+                    ScrapingError::IoError(format!(
+                        "Failed to serialize segment '{}': {}",
+                        segment.name, e
+                    ))
+                })?;
+                fs::write(format!("{}/{}.json", segments_dir, stem), segment_json).map_err(
+                    |e| {
+                        ScrapingError::IoError(format!(
+                            "Failed to write segment '{}': {}",
+                            segment.name, e
+                        ))
+                    },
+                )?;
+                self.save_csv_format(
+                    members.iter().copied(),
+                    &format!("{}/{}.csv", segments_dir, stem),
+                )
+                .await?;
+                info!(
+                    "   🎯 Segment {}: {} leads -> {}/{}.json",
+                    segment.name,
+                    members.len(),
+                    segments_dir,
+                    stem
+                );
+            }
+        }
+
         // Create comprehensive stats with run metadata
-        let stats = LeadStats::new(&contactable_leads, &research_leads);
+        let stats = self.stats(leads);
         let run_metadata = RunMetadata::new(start_time, end_time, &stats);
 
         let stats_output = format!("{}/stats.json", output_path);
@@ -192,7 +668,11 @@ impl LeadScraper {
         })?;
 
         // Save CSV for spreadsheet users
-        self.save_csv_format(leads, output_path).await?;
+        self.save_csv_format(leads, &format!("{}/all_leads.csv", output_path))
+            .await?;
+
+        // One row per person for outreach tools
+        self.save_contacts_csv(leads, output_path).await?;
 
         // Print detailed summary
         info!("✅ Results saved successfully:");
@@ -209,6 +689,7 @@ impl LeadScraper {
         info!("   📊 Detailed stats: {}", stats_output);
         info!("   📋 All leads (JSON): {}", all_leads_output);
         info!("   📄 CSV export: {}/all_leads.csv", output_path);
+        info!("   👥 Contacts export: {}/contacts.csv", output_path);
         info!("   ⏱️  Run metadata: {}", metadata_output);
 
         // Print top-level stats
@@ -227,20 +708,43 @@ impl LeadScraper {
         Ok(())
     }
 
+    /// Statistics over leads, including the configured segments
+    pub fn stats(&self, leads: &[Lead]) -> LeadStats {
+        let (contactable_leads, research_leads): (Vec<_>, Vec<_>) =
+            leads.iter().partition(|lead| self.has_contact_info(lead));
+        let mut stats = LeadStats::new(&contactable_leads, &research_leads);
+        stats.segments = segment_leads(&self.config.segments, leads)
+            .into_iter()
+            .map(|(segment, members)| {
+                (
+                    segment.name.clone(),
+                    SegmentStats::new(segment.description.clone(), &members),
+                )
+            })
+            .collect();
+        stats
+    }
+
     /// Save leads in CSV format for spreadsheet applications
-    async fn save_csv_format(&self, leads: &[Lead], output_path: &str) -> Result<()> {
-        use std::fs::File;
-        use std::io::Write;
+    async fn save_csv_format<'a>(
+        &self,
+        leads: impl IntoIterator<Item = &'a Lead>,
+        csv_path: &str,
+    ) -> Result<()> {
+        std::fs::write(csv_path, Self::leads_csv(leads))
+            .map_err(|e| ScrapingError::IoError(format!("Failed to write CSV file: {}", e)))
+    }
 
-        let csv_path = format!("{}/all_leads.csv", output_path);
-        let mut file = File::create(&csv_path)
-            .map_err(|e| ScrapingError::IoError(format!("Failed to create CSV file: {}", e)))?;
+    /// Leads as CSV, one row per lead
+    pub fn leads_csv<'a>(leads: impl IntoIterator<Item = &'a Lead>) -> String {
+        use std::fmt::Write;
 
-        // Write CSV header
-        writeln!(
-            file,
-            "Name,Website,Email,GitHub Email,LinkedIn,Twitter,Source,Country,Description,Scraped At,Contact Score"
-        ).map_err(|e| ScrapingError::IoError(format!("Failed to write CSV header: {}", e)))?;
+        // Writing to a String cannot fail
+        let mut csv = String::new();
+        let _ = writeln!(
+            csv,
+            "Name,Website,Domain,Website Status,Repository,Email,Email Status,GitHub Email,LinkedIn,Twitter,Phone,Source,Country,City,Address,Category,Industries,Technologies,Stars,Last Push,Description,Scraped At,Score,Score Reasons"
+        );
 
         // Write data rows
         for lead in leads {
@@ -250,6 +754,10 @@ impl LeadScraper {
                     format!("GitHub/{}", repository)
                 }
                 crate::models::Source::BetaList => "BetaList".to_string(),
+                crate::models::Source::GitHubSearch { .. } => "GitHub Search".to_string(),
+                crate::models::Source::PackageRegistry { registry } => {
+                    format!("Registry/{}", registry)
+                }
                 crate::models::Source::Website { url } => {
                     if url.contains("github.com") {
                         "GitHub Project".to_string()
@@ -259,25 +767,119 @@ impl LeadScraper {
                 }
             };
 
-            writeln!(
-                file,
-                "{},{},{},{},{},{},{},{},{},{},{}",
+            let health = lead.repo_health.as_ref();
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
                 Self::csv_escape(&lead.name),
-                lead.website.as_deref().unwrap_or(""),
+                Self::csv_escape(lead.website.as_deref().unwrap_or("")),
+                lead.canonical_domain.as_deref().unwrap_or(""),
+                lead.website_status
+                    .as_ref()
+                    .map(|status| status.state.to_string())
+                    .unwrap_or_default(),
+                lead.repository.as_deref().unwrap_or(""),
                 lead.email.as_deref().unwrap_or(""),
+                lead.email
+                    .as_deref()
+                    .and_then(|email| lead.email_status(email))
+                    .map(|status| status.to_string())
+                    .unwrap_or_default(),
                 lead.github_email.as_deref().unwrap_or(""),
                 lead.linkedin.as_deref().unwrap_or(""),
                 lead.twitter.as_deref().unwrap_or(""),
+                lead.phones
+                    .first()
+                    .map(|phone| phone.e164.as_str())
+                    .unwrap_or(""),
                 Self::csv_escape(&source_str),
-                lead.country.as_deref().unwrap_or(""),
+                lead.country_code().unwrap_or(""),
+                Self::csv_escape(
+                    lead.location
+                        .as_ref()
+                        .and_then(|location| location.city.as_deref())
+                        .unwrap_or("")
+                ),
+                Self::csv_escape(
+                    &lead
+                        .address
+                        .as_ref()
+                        .map(|address| address.one_line())
+                        .unwrap_or_default()
+                ),
+                Self::csv_escape(&lead.categories.join(" > ")),
+                Self::csv_escape(
+                    &lead
+                        .industries
+                        .iter()
+                        .map(|industry| industry.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+                Self::csv_escape(
+                    &lead
+                        .technologies
+                        .iter()
+                        .map(|technology| technology.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                ),
+                health.map(|h| h.stars.to_string()).unwrap_or_default(),
+                health
+                    .and_then(|h| h.pushed_at)
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_default(),
                 Self::csv_escape(&lead.description.as_deref().unwrap_or("").replace('\n', " ")),
                 lead.scraped_at.format("%Y-%m-%d %H:%M:%S UTC"),
-                lead.contact_score()
-            )
-            .map_err(|e| ScrapingError::IoError(format!("Failed to write CSV row: {}", e)))?;
+                lead.score
+                    .as_ref()
+                    .map(|score| score.total.to_string())
+                    .unwrap_or_default(),
+                Self::csv_escape(
+                    &lead
+                        .score
+                        .as_ref()
+                        .map(|score| score.explanation())
+                        .unwrap_or_default()
+                )
+            );
         }
+        csv
+    }
 
-        Ok(())
+    /// Save people behind the leads in CSV format, one row per person
+    async fn save_contacts_csv(&self, leads: &[Lead], output_path: &str) -> Result<()> {
+        let csv_path = format!("{}/contacts.csv", output_path);
+        std::fs::write(&csv_path, Self::contacts_csv(leads)).map_err(|e| {
+            ScrapingError::IoError(format!("Failed to write contacts CSV file: {}", e))
+        })
+    }
+
+    /// People behind the leads as CSV, one row per person
+    pub fn contacts_csv(leads: &[Lead]) -> String {
+        let mut csv = String::from(
+            "Company,Website,Person,Role,Email,LinkedIn,Twitter,GitHub,Found In,Source\n",
+        );
+        for lead in leads {
+            for person in &lead.people {
+                let fields = [
+                    lead.name.as_str(),
+                    lead.website.as_deref().unwrap_or(""),
+                    person.name.as_str(),
+                    person.role.as_deref().unwrap_or(""),
+                    person.email.as_deref().unwrap_or(""),
+                    person.linkedin.as_deref().unwrap_or(""),
+                    person.twitter.as_deref().unwrap_or(""),
+                    person.github.as_deref().unwrap_or(""),
+                    person.origin.as_str(),
+                    &lead.source.to_string(),
+                ]
+                .map(Self::csv_escape);
+                csv.push_str(&fields.join(","));
+                csv.push('\n');
+            }
+        }
+        csv
     }
 
     /// Escape CSV field content
@@ -299,53 +901,183 @@ impl LeadScraper {
 mod tests {
     use super::*;
     use crate::models::Source;
-    use chrono::Utc;
 
-    #[test]
-    fn test_has_contact_info() {
-        let config = Config::default();
+    /// Scraper over `config` with technologies, tagging, email validation
+    /// and scoring off
+    fn test_scraper(config: Config) -> LeadScraper {
         let client = reqwest::Client::new();
-        let github_token = None;
-        let extractor = DataExtractor::new(&config.patterns, client.clone(), github_token)
+        let extractor = DataExtractor::new(&config.patterns, client.clone(), None)
             .expect("Failed to create extractor");
-
-        let scraper = LeadScraper {
+        LeadScraper {
+            websites: WebsiteChecker::new(client.clone(), &config.websites),
             client,
             config,
             extractor,
-        };
+            technologies: None,
+            tagger: None,
+            email_validator: None,
+            scorer: None,
+        }
+    }
+
+    #[test]
+    fn test_has_contact_info() {
+        let scraper = test_scraper(Config::default());
 
         // Lead with email
-        let lead_with_email = Lead {
-            name: "Test Company".to_string(),
-            website: None,
-            email: Some("test@example.com".to_string()),
-            github_email: None,
-            linkedin: None,
-            twitter: None,
-            source: Source::BetaList,
-            country: None,
-            description: None,
-            scraped_at: Utc::now(),
-        };
+        let lead_with_email = Lead::new("Test Company".to_string(), Source::BetaList)
+            .with_email(Some("test@example.com".to_string()));
         assert!(scraper.has_contact_info(&lead_with_email));
 
         // Lead without contact
-        let lead_no_contact = Lead {
-            name: "Test Company 2".to_string(),
-            website: Some("https://example.com".to_string()),
-            email: None,
-            github_email: None,
-            linkedin: None,
-            twitter: None,
-            source: Source::BetaList,
-            country: None,
-            description: None,
-            scraped_at: Utc::now(),
-        };
+        let lead_no_contact = Lead::new("Test Company 2".to_string(), Source::BetaList)
+            .with_website(Some("https://example.com".to_string()));
         assert!(!scraper.has_contact_info(&lead_no_contact));
     }
 
+    #[tokio::test]
+    async fn test_validate_rescores_and_stats_cover_segments() {
+        let config = Config {
+            segments: serde_yaml::from_str(
+                "- name: reachable\n  when: { has: [email] }\n  min_score: 1\n",
+            )
+            .unwrap(),
+            ..Config::default()
+        };
+        let scraper = LeadScraper {
+            scorer: Some(LeadScorer::from_yaml(include_str!("../config/scoring.yaml")).unwrap()),
+            ..test_scraper(config)
+        };
+
+        let mut leads = vec![
+            Lead::new("Quiet".to_string(), Source::BetaList),
+            Lead::new("Acme".to_string(), Source::BetaList)
+                .with_email(Some("jane@acme.dev".to_string())),
+        ];
+        // Website checks and email validation are off: only rescoring runs
+        scraper.validate(&mut leads).await;
+        assert_eq!(leads[0].name, "Acme");
+        assert!(leads.iter().all(|lead| lead.score.is_some()));
+
+        let stats = scraper.stats(&leads);
+        assert_eq!(stats.total_leads, 2);
+        assert_eq!(stats.contactable_leads, 1);
+        assert_eq!(stats.segments["reachable"].total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_validate_replaces_existing_email_verdicts() {
+        use crate::config::EmailValidationConfig;
+        use crate::email::DomainLists;
+        use crate::models::{EmailStatus, EmailVerdict};
+
+        let lists = DomainLists::from_yaml(include_str!("../config/email_domains.yaml"))
+            .expect("shipped domain lists are valid");
+        let validator =
+            EmailValidator::with_lists(&EmailValidationConfig::default(), lists).unwrap();
+        let scraper = LeadScraper {
+            email_validator: Some(validator),
+            ..test_scraper(Config::default())
+        };
+
+        let email = "throwaway@mailinator.com".to_string();
+        let mut lead =
+            Lead::new("Acme".to_string(), Source::BetaList).with_email(Some(email.clone()));
+        lead.email_verdicts.insert(
+            email.clone(),
+            EmailVerdict {
+                email: email.clone(),
+                status: EmailStatus::Deliverable,
+                syntax_valid: true,
+                has_mail_server: Some(true),
+                disposable: false,
+                free_provider: false,
+                smtp_accepted: Some(true),
+                catch_all: Some(false),
+                reason: None,
+            },
+        );
+
+        let mut leads = vec![lead];
+        scraper.validate(&mut leads).await;
+        let verdict = &leads[0].email_verdicts[&email];
+        assert_eq!(verdict.status, EmailStatus::Undeliverable);
+        assert_eq!(verdict.reason.as_deref(), Some("disposable domain"));
+    }
+
+    fn ok_html(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
+    }
+
+    #[tokio::test]
+    async fn test_team_pages_follow_homepage_language() {
+        let site = crate::forges::test_server::serve_raw(vec![
+            (
+                "/impressum",
+                ok_html(
+                    "<html lang=\"de\"><body><footer><p>Acme GmbH</p><p>Torstraße 12</p>\
+                     <p>10119 Berlin</p><p>Telefon: 030 987 654 32</p></footer></body></html>",
+                ),
+            ),
+            (
+                "/",
+                ok_html("<html lang=\"de\"><body><h1>Rechnungen ohne Aufwand</h1></body></html>"),
+            ),
+        ])
+        .await;
+
+        let scraper = test_scraper(Config::default());
+
+        // The listing description was English
+        let mut lead = Lead::new("Acme".to_string(), Source::BetaList)
+            .with_website(Some(format!("{}/", site)));
+        lead.language = Some("en".to_string());
+        let mut leads = vec![lead];
+        scraper.enrich_team_pages(&mut leads).await;
+
+        let address = leads[0].address.as_ref().expect("address from /impressum");
+        assert_eq!(address.one_line(), "Torstraße 12, 10119 Berlin");
+        assert_eq!(leads[0].phones[0].e164, "+493098765432");
+    }
+
+    #[tokio::test]
+    async fn test_no_enrich_plans_no_requests_per_lead() {
+        let mut config = Config::default();
+        config.sources.ycombinator.fetch_founders = true;
+        config.sources.package_registries.enabled = true;
+        config.sources.package_registries.npm.enabled = true;
+        config.sources.package_registries.npm.keywords = vec!["sdk".to_string()];
+        config.websites.check = true;
+        config.scraper.team_pages = true;
+        config.skip_enrichment();
+
+        let plan = LeadScraper::new(config).await.unwrap().plan().unwrap();
+        assert!(plan.sources.len() >= 4);
+        for source in &plan.sources {
+            assert_eq!(source.requests.requests_per_lead, 0, "{}", source.source);
+        }
+        assert_eq!(plan.enrichment_requests_per_lead, 0);
+    }
+
+    #[test]
+    fn test_enrichment_estimate_bounds_redirects_and_contact_pages() {
+        let mut config = Config::default();
+        config.websites.check = true;
+        config.scraper.team_pages = true;
+        let scraper = test_scraper(config);
+
+        // German adds /kontakt and /impressum, the most of any default language
+        assert_eq!(scraper.extractor.max_contact_pages(), 2);
+        assert_eq!(
+            scraper.enrichment_requests_per_lead(),
+            (1 + MAX_REDIRECTS) + (1 + TEAM_PAGE_PATHS.len() + 2)
+        );
+    }
+
     #[test]
     fn test_csv_escape() {
         assert_eq!(LeadScraper::csv_escape("simple"), "simple");
@@ -356,5 +1088,28 @@ mod tests {
             "\"with\nnewline\""
         );
     }
-}
 
+    #[test]
+    fn test_contacts_csv_one_row_per_person() {
+        use crate::models::Person;
+
+        let mut lead = Lead::new("Acme".to_string(), Source::YCombinator)
+            .with_website(Some("https://acme.dev".to_string()));
+        let mut ceo = Person::new("Jane Doe", "Y Combinator");
+        ceo.role = Some("Co-founder, CEO".to_string());
+        lead.add_person(ceo);
+        let mut same_ceo = Person::new("jane doe", "team page");
+        same_ceo.linkedin = Some("https://linkedin.com/in/janedoe".to_string());
+        lead.add_person(same_ceo);
+        lead.add_person(Person::new("Joe Smith", "commits"));
+
+        let csv = LeadScraper::contacts_csv(&[lead]);
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[1],
+            "Acme,https://acme.dev,Jane Doe,\"Co-founder, CEO\",,https://linkedin.com/in/janedoe,,,Y Combinator,Y Combinator"
+        );
+        assert!(rows[2].starts_with("Acme,https://acme.dev,Joe Smith,"));
+    }
+}
@@ -0,0 +1,358 @@
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag, TagEnd};
+use tracing::debug;
+use url::Url;
+
+/// A project entry found in an awesome list
+#[derive(Debug, Clone, PartialEq)]
+pub struct AwesomeEntry {
+    pub name: String,
+    pub url: String,
+    pub description: Option<String>,
+    /// Heading path of the section the entry was found in (list title excluded)
+    pub categories: Vec<String>,
+    /// Secondary "Source Code"-style link found in the description
+    pub repository: Option<String>,
+}
+
+/// Inline content of a list item, in document order
+#[derive(Debug)]
+enum Inline {
+    Text(String),
+    Link {
+        url: String,
+        text: String,
+        image_only: bool,
+    },
+}
+
+#[derive(Debug, Default)]
+struct ItemBuffer {
+    inlines: Vec<Inline>,
+}
+
+impl ItemBuffer {
+    fn has_link(&self) -> bool {
+        self.inlines
+            .iter()
+            .any(|inline| matches!(inline, Inline::Link { .. }))
+    }
+
+    fn plain_text(&self) -> String {
+        self.inlines
+            .iter()
+            .map(|inline| match inline {
+                Inline::Text(text) => text.as_str(),
+                Inline::Link { text, .. } => text.as_str(),
+            })
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+}
+
+#[derive(Debug, Default)]
+struct LinkBuffer {
+    url: String,
+    text: String,
+    has_image: bool,
+}
+
+/// Markdown parser for awesome-list READMEs.
+///
+/// Entries are taken from list items only; headings give each entry its
+/// category, intra-document (`#anchor`) links are never entries, so
+/// table-of-contents lists are skipped structurally.
+pub struct AwesomeListParser {
+    base_url: Option<Url>,
+}
+
+impl AwesomeListParser {
+    /// `base_url` is used to resolve relative links, e.g.
+    /// `https://github.com/owner/repo/blob/main/`
+    pub fn new(base_url: Option<&str>) -> Self {
+        Self {
+            base_url: base_url.and_then(|url| Url::parse(url).ok()),
+        }
+    }
+
+    pub fn parse(&self, content: &str) -> Vec<AwesomeEntry> {
+        let mut entries = Vec::new();
+
+        let mut headings: Vec<(HeadingLevel, String)> = Vec::new();
+        let mut heading_text: Option<String> = None;
+        // Category labels contributed by link-less parent list items ("- Frameworks")
+        let mut list_labels: Vec<Option<String>> = Vec::new();
+        let mut items: Vec<ItemBuffer> = Vec::new();
+        let mut link: Option<LinkBuffer> = None;
+        let mut image_depth = 0usize;
+
+        for event in Parser::new(content) {
+            match event {
+                Event::Start(Tag::Heading { .. }) => heading_text = Some(String::new()),
+                Event::End(TagEnd::Heading(level)) => {
+                    let text = heading_text.take().unwrap_or_default().trim().to_string();
+                    while headings.last().is_some_and(|(l, _)| *l >= level) {
+                        headings.pop();
+                    }
+                    headings.push((level, text));
+                }
+                Event::Start(Tag::List(_)) => {
+                    let label = items
+                        .last()
+                        .filter(|item| !item.has_link())
+                        .map(ItemBuffer::plain_text)
+                        .filter(|text| !text.is_empty());
+                    list_labels.push(label);
+                }
+                Event::End(TagEnd::List(_)) => {
+                    list_labels.pop();
+                }
+                Event::Start(Tag::Item) => items.push(ItemBuffer::default()),
+                Event::End(TagEnd::Item) => {
+                    if let Some(item) = items.pop() {
+                        let categories = Self::categories(&headings, &list_labels);
+                        entries.extend(self.entries_from_item(item, &categories));
+                    }
+                }
+                Event::Start(Tag::Link { dest_url, .. }) => {
+                    link = Some(LinkBuffer {
+                        url: dest_url.to_string(),
+                        ..LinkBuffer::default()
+                    });
+                }
+                Event::End(TagEnd::Link) => {
+                    if let (Some(done), Some(item)) = (link.take(), items.last_mut()) {
+                        let text = done.text.trim().to_string();
+                        item.inlines.push(Inline::Link {
+                            url: done.url,
+                            image_only: done.has_image && text.is_empty(),
+                            text,
+                        });
+                    }
+                }
+                Event::Start(Tag::Image { .. }) => {
+                    image_depth += 1;
+                    if let Some(ref mut link) = link {
+                        link.has_image = true;
+                    }
+                }
+                Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+                Event::Text(text) | Event::Code(text) => {
+                    if image_depth > 0 {
+                        // Image alt text (badges) is not content
+                    } else if let Some(ref mut heading) = heading_text {
+                        heading.push_str(&text);
+                    } else if let Some(ref mut link) = link {
+                        link.text.push_str(&text);
+                    } else if let Some(item) = items.last_mut() {
+                        item.inlines.push(Inline::Text(text.to_string()));
+                    }
+                }
+                Event::SoftBreak | Event::HardBreak => {
+                    if let Some(item) = items.last_mut() {
+                        item.inlines.push(Inline::Text(" ".to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        debug!("Awesome list parsing complete: {} entries", entries.len());
+        entries
+    }
+
+    /// Section path: headings below the list title, then parent item labels
+    fn categories(
+        headings: &[(HeadingLevel, String)],
+        list_labels: &[Option<String>],
+    ) -> Vec<String> {
+        let skip_title = headings
+            .first()
+            .is_some_and(|(level, _)| *level == HeadingLevel::H1);
+
+        headings
+            .iter()
+            .skip(usize::from(skip_title))
+            .map(|(_, text)| text.clone())
+            .chain(list_labels.iter().flatten().cloned())
+            .filter(|text| !text.is_empty())
+            .collect()
+    }
+
+    /// Split a list item into entries: links before the description are
+    /// entries, links after it belong to the description.
+    fn entries_from_item(&self, item: ItemBuffer, categories: &[String]) -> Vec<AwesomeEntry> {
+        let mut heads: Vec<(String, String)> = Vec::new();
+        let mut description = String::new();
+        let mut repository = None;
+        let mut in_description = false;
+
+        for inline in item.inlines {
+            match inline {
+                Inline::Link {
+                    url,
+                    text,
+                    image_only,
+                } => {
+                    if in_description {
+                        description.push_str(&text);
+                        if repository.is_none() && Self::is_repository_label(&text) {
+                            repository = self.resolve(&url);
+                        }
+                    } else if !image_only && !text.is_empty() {
+                        if let Some(url) = self.resolve(&url) {
+                            heads.push((text, url));
+                        }
+                    }
+                }
+                Inline::Text(text) => {
+                    if in_description {
+                        description.push_str(&text);
+                    } else if !heads.is_empty() && !Self::is_link_separator(&text) {
+                        in_description = true;
+                        description.push_str(Self::strip_separator(&text));
+                    }
+                }
+            }
+        }
+
+        let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+        let description = Some(description).filter(|text| !text.is_empty());
+
+        heads
+            .into_iter()
+            .map(|(name, url)| AwesomeEntry {
+                name,
+                url,
+                description: description.clone(),
+                categories: categories.to_vec(),
+                repository: repository.clone(),
+            })
+            .collect()
+    }
+
+    /// Text between two head links, e.g. `, ` or ` / `
+    fn is_link_separator(text: &str) -> bool {
+        text.trim()
+            .chars()
+            .all(|c| matches!(c, ',' | '/' | '|' | '&' | '+'))
+    }
+
+    fn strip_separator(text: &str) -> &str {
+        text.trim_start()
+            .trim_start_matches(['-', '–', '—', ':'])
+            .trim_start()
+    }
+
+    fn is_repository_label(text: &str) -> bool {
+        let text = text.to_lowercase();
+        [
+            "source code",
+            "source",
+            "repo",
+            "repository",
+            "github",
+            "gitlab",
+            "codeberg",
+        ]
+        .iter()
+        .any(|label| text == *label)
+    }
+
+    /// Absolute http(s) URL for a link target; anchors and other schemes are dropped
+    fn resolve(&self, url: &str) -> Option<String> {
+        let url = url.trim();
+        if url.is_empty() || url.starts_with('#') {
+            return None;
+        }
+
+        match Url::parse(url) {
+            Ok(absolute) => matches!(absolute.scheme(), "http" | "https").then(|| url.to_string()),
+            // Repository-relative link, rendered by forges relative to the README
+            Err(url::ParseError::RelativeUrlWithoutBase) => self
+                .base_url
+                .as_ref()?
+                .join(url.trim_start_matches('/'))
+                .ok()
+                .map(|resolved| resolved.to_string()),
+            Err(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections_and_nesting() {
+        let content = r#"
+# Awesome Tools
+
+## Contents
+
+- [Databases](#databases)
+  - [SQL](#sql)
+
+## Databases
+
+### SQL
+
+- [Postgres](https://postgresql.org) - The database.
+- Embedded
+  - [SQLite](https://sqlite.org) - Small and fast.
+
+## Contributing
+
+See [the guide](contributing.md).
+"#;
+
+        let entries = AwesomeListParser::new(None).parse(content);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "Postgres");
+        assert_eq!(entries[0].categories, vec!["Databases", "SQL"]);
+        assert_eq!(entries[0].description.as_deref(), Some("The database."));
+        assert_eq!(entries[1].name, "SQLite");
+        assert_eq!(entries[1].categories, vec!["Databases", "SQL", "Embedded"]);
+    }
+
+    #[test]
+    fn test_multiple_and_reference_links() {
+        let content = r#"
+## Editors
+
+- [Vim][vim], [Neovim](https://neovim.io) - Modal editors. ([Source Code](https://github.com/neovim/neovim))
+- [![badge](https://img.shields.io/x.svg)](https://ci.example.com) [Helix](https://helix-editor.com)
+
+[vim]: https://www.vim.org
+"#;
+
+        let entries = AwesomeListParser::new(None).parse(content);
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Vim", "Neovim", "Helix"]);
+        assert_eq!(entries[0].url, "https://www.vim.org");
+        assert_eq!(
+            entries[1].description.as_deref(),
+            Some("Modal editors. (Source Code)")
+        );
+        assert_eq!(
+            entries[1].repository.as_deref(),
+            Some("https://github.com/neovim/neovim")
+        );
+    }
+
+    #[test]
+    fn test_relative_links() {
+        let parser = AwesomeListParser::new(Some("https://github.com/owner/list/blob/main/"));
+        let entries = parser.parse("- [Guide](docs/guide.md) - Local page\n- [Mail](mailto:a@b.c)");
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(
+            entries[0].url,
+            "https://github.com/owner/list/blob/main/docs/guide.md"
+        );
+        assert!(AwesomeListParser::new(None)
+            .parse("- [Guide](docs/guide.md)")
+            .is_empty());
+    }
+}
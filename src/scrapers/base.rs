@@ -1,11 +1,12 @@
 use crate::errors::{Result, ScrapingError};
 use crate::extractors::DataExtractor;
-use crate::models::{Lead, ScrapedData, Source};
+use crate::language::detect_language;
+use crate::models::{Lead, ScrapedData, SocialNetwork, Source};
 use reqwest::Client;
-use scraper::{ElementRef, Html, Selector};
+use scraper::{ElementRef, Selector};
 use std::time::Duration;
 use tokio::time::sleep;
-use tracing::{debug, warn};
+use tracing::debug;
 
 /// Base scraper with common functionality shared across all scrapers
 #[derive(Clone)]
@@ -46,64 +47,31 @@ impl BaseScraper {
         Ok(html)
     }
 
-    /// Fetch GitHub README content via API
-    pub async fn fetch_github_readme(&self, url: &str) -> Result<String> {
-        #[derive(serde::Deserialize)]
-        struct GitHubContent {
-            content: String,
-        }
-
-        debug!("Fetching GitHub README from: {}", url);
+    /// Fetch and decode a JSON document from a URL with error handling
+    pub async fn fetch_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T> {
+        debug!("Fetching JSON from: {}", url);
 
         let response = self
             .client
             .get(url)
-            .header("Accept", "application/vnd.github.v3+json")
-            .header("User-Agent", "Lead-Scraper/1.0")
+            .header("Accept", "application/json")
             .send()
             .await
             .map_err(|e| {
-                ScrapingError::NetworkError(format!(
-                    "GitHub API request failed for '{}': {}",
-                    url, e
-                ))
+                ScrapingError::NetworkError(format!("HTTP request failed for '{}': {}", url, e))
             })?;
 
         if !response.status().is_success() {
             return Err(ScrapingError::NetworkError(format!(
-                "GitHub API error {}: {}",
+                "HTTP {}: {}",
                 response.status(),
                 url
             )));
         }
 
-        let github_content: GitHubContent = response.json().await.map_err(|e| {
-            ScrapingError::ParseError(format!("Failed to parse GitHub API response: {}", e))
-        })?;
-
-        use base64::{engine::general_purpose, Engine as _};
-
-        // Clean the base64 content - remove whitespace and newlines
-        let cleaned_content = github_content
-            .content
-            .chars()
-            .filter(|c| !c.is_whitespace())
-            .collect::<String>();
-
-        let decoded = general_purpose::STANDARD
-            .decode(&cleaned_content)
-            .map_err(|e| {
-                ScrapingError::ParseError(format!("Failed to decode base64 content: {}", e))
-            })?;
-
-        let content = String::from_utf8(decoded)
-            .map_err(|e| ScrapingError::ParseError(format!("Invalid UTF-8 in README: {}", e)))?;
-
-        debug!(
-            "Successfully decoded GitHub README: {} bytes",
-            content.len()
-        );
-        Ok(content)
+        response.json().await.map_err(|e| {
+            ScrapingError::ParseError(format!("Failed to parse JSON from '{}': {}", url, e))
+        })
     }
 
     /// Extract company data from HTML element using common selectors
@@ -146,28 +114,35 @@ impl BaseScraper {
     /// Create a Lead from scraped data with all extracted information
     pub async fn create_lead_from_scraped_data(&self, data: ScrapedData, source: Source) -> Lead {
         let email = self.extractor.extract_email(&data.raw_text, &data.html);
-        let country = self
+        let language = detect_language(&data.raw_text);
+        let location =
+            self.extractor
+                .extract_location(&data.raw_text, data.website.as_deref(), language);
+        let brands = DataExtractor::brand_hints(&data.name, data.website.as_deref());
+        let socials = self
             .extractor
-            .extract_country(&data.raw_text, data.website.as_deref());
-        let (linkedin, twitter) = self
-            .extractor
-            .extract_social_media(&data.raw_text, &data.html);
+            .extract_socials(&data.raw_text, &data.html, &brands);
+        let linkedin = socials.get(&SocialNetwork::LinkedInCompany).cloned();
+        let twitter = socials.get(&SocialNetwork::Twitter).cloned();
 
         let mut lead = Lead::new(data.name, source)
             .with_website(data.website)
             .with_email(email)
             .with_linkedin(linkedin)
             .with_twitter(twitter)
-            .with_country(country)
-            .with_description(Some(data.raw_text));
+            .with_socials(socials)
+            .with_location(location)
+            .with_description(Some(data.raw_text.clone()))
+            .with_language(language.map(str::to_string));
+
+        self.extractor
+            .apply_contact_details(&mut lead, &data.raw_text, &data.html);
 
-        // Extract GitHub commit emails if it's a GitHub project
+        // Extract commit emails if the project is hosted on a known forge
         if let Some(ref website) = lead.website {
-            if website.contains("github.com") {
-                let commit_emails = self.extractor.extract_github_commit_emails(website).await;
-                if !commit_emails.is_empty() {
-                    lead.github_email = commit_emails.into_iter().next();
-                }
+            if self.extractor.forges().is_repository_url(website) {
+                lead.repository = Some(website.clone());
+                self.extractor.enrich_repository_lead(&mut lead).await;
             }
         }
 
@@ -204,46 +179,6 @@ impl BaseScraper {
             return false;
         }
 
-        // Skip obvious navigation/category links by name
-        let skip_names = [
-            "platforms",
-            "programming languages",
-            "front-end development",
-            "back-end development",
-            "computer science",
-            "big data",
-            "theory",
-            "books",
-            "editors",
-            "gaming",
-            "development environment",
-            "entertainment",
-            "databases",
-            "media",
-            "learn",
-            "security",
-            "content management systems",
-            "hardware",
-            "business",
-            "work",
-            "networking",
-            "decentralized systems",
-            "health and social science",
-            "events",
-            "testing",
-            "miscellaneous",
-            "related",
-            "contents",
-        ];
-
-        let name_lower = name.to_lowercase();
-        for skip in &skip_names {
-            if name_lower == *skip || name_lower.contains(&format!("{} ", skip)) {
-                debug!("Skipping navigation link: {}", name);
-                return false;
-            }
-        }
-
         // Skip if URL is just a relative link or too short
         if url_str.len() < 10 {
             debug!("Skipping short URL: {}", url_str);
@@ -303,7 +238,6 @@ mod tests {
         assert!(base.is_valid_project_link("CoolApp", &Some("https://example.com".to_string())));
 
         // Invalid links
-        assert!(!base.is_valid_project_link("contents", &Some("https://example.com".to_string())));
         assert!(!base.is_valid_project_link("MyProject", &Some("#anchor".to_string())));
         assert!(!base.is_valid_project_link("MyProject", &Some("x".to_string())));
         assert!(!base.is_valid_project_link("", &Some("https://example.com".to_string())));
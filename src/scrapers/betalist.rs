@@ -1,27 +1,61 @@
-use super::{base::BaseScraper, SourceScraper};
+use super::{base::BaseScraper, capped_estimate, lead_cap_reached, RequestPlan, SourceScraper};
 use crate::{
-    config::BetaListConfig,
-    errors::Result,
+    config::{BetaListConfig, ScraperConfig},
+    errors::{Result, ScrapingError},
     extractors::DataExtractor,
-    models::{Lead, ScrapedData, Source},
+    models::{Lead, Person, ScrapedData, Source},
 };
 use async_trait::async_trait;
-use reqwest::Client;
+use reqwest::{header::LOCATION, redirect::Policy, Client};
 use scraper::{ElementRef, Html, Selector};
+use std::time::Duration;
 use tracing::{debug, info, warn};
+use url::Url;
+
+/// BetaList-internal redirects followed before giving up on a visit link
+const MAX_VISIT_HOPS: usize = 5;
+
+/// What a startup's BetaList page adds to its listing card
+#[derive(Debug, Clone, Default, PartialEq)]
+struct StartupDetails {
+    /// "Visit website" link, usually a BetaList redirect
+    visit_url: Option<String>,
+    /// The company's own website, once the visit link is resolved
+    website: Option<String>,
+    description: Option<String>,
+    topics: Vec<String>,
+    makers: Vec<Person>,
+}
 
 /// BetaList scraper implementation
 pub struct BetaListScraper {
     config: BetaListConfig,
     base: BaseScraper,
+    /// Client that stops at redirects, to read visit links' `Location`
+    /// without fetching the company site
+    no_redirects: Client,
 }
 
 impl BetaListScraper {
-    pub fn new(config: BetaListConfig, client: Client, extractor: DataExtractor) -> Self {
-        Self {
+    pub fn new(
+        config: BetaListConfig,
+        scraper: &ScraperConfig,
+        client: Client,
+        extractor: DataExtractor,
+    ) -> Result<Self> {
+        let no_redirects = Client::builder()
+            .user_agent(&scraper.user_agent)
+            .timeout(Duration::from_secs(scraper.timeout_seconds))
+            .redirect(Policy::none())
+            .build()
+            .map_err(|e| {
+                ScrapingError::NetworkError(format!("Failed to create HTTP client: {}", e))
+            })?;
+        Ok(Self {
             config,
             base: BaseScraper::new(client, extractor),
-        }
+            no_redirects,
+        })
     }
 
     /// Parse BetaList page and extract startup data
@@ -138,6 +172,167 @@ impl BetaListScraper {
     }
 }
 
+impl BetaListScraper {
+    /// Link to the startup's BetaList page (`/startups/<slug>`) in a listing card
+    fn detail_page_url(&self, html: &str) -> Option<String> {
+        let fragment = Html::parse_fragment(html);
+        let selector = Selector::parse("a[href*='/startups/']").ok()?;
+        let base = Url::parse(&self.config.base_url).ok()?;
+
+        fragment.select(&selector).find_map(|link| {
+            let mut url = base.join(link.value().attr("href")?).ok()?;
+            url.set_query(None);
+            url.set_fragment(None);
+            let slug = url.path().strip_prefix("/startups/")?;
+            (!slug.is_empty() && !slug.contains('/')).then(|| url.to_string())
+        })
+    }
+
+    /// Visit link, description, topics and makers from a startup page
+    fn parse_detail_page(&self, html: &str, page_url: &str) -> StartupDetails {
+        let document = Html::parse_document(html);
+        let select = |selector: &str| Selector::parse(selector).ok();
+        let text_of = |element: ElementRef| {
+            self.base
+                .extractor
+                .clean_text(&element.text().collect::<Vec<_>>().join(" "))
+        };
+        let mut details = StartupDetails::default();
+
+        let page = Url::parse(page_url).ok();
+        let resolve = |href: &str| match page {
+            Some(ref page) => page.join(href).ok().map(|url| url.to_string()),
+            None => Some(href.to_string()),
+        };
+        if let Some(links) = select("a[href]") {
+            let links: Vec<ElementRef> = document.select(&links).collect();
+            details.visit_url = links
+                .iter()
+                .find(|link| {
+                    link.value()
+                        .attr("href")
+                        .is_some_and(|href| href.trim_end_matches('/').ends_with("/visit"))
+                })
+                .or_else(|| {
+                    links
+                        .iter()
+                        .find(|link| text_of(**link).to_lowercase().starts_with("visit"))
+                })
+                .and_then(|link| resolve(link.value().attr("href")?));
+        }
+
+        // Longest of the meta description and on-page description blocks
+        let mut descriptions: Vec<String> = Vec::new();
+        if let Some(meta) = select("meta[property='og:description'], meta[name='description']") {
+            descriptions.extend(
+                document
+                    .select(&meta)
+                    .filter_map(|meta| meta.value().attr("content"))
+                    .map(|content| self.base.extractor.clean_text(content)),
+            );
+        }
+        if let Some(blocks) = select("[itemprop='description'], [class*='description']") {
+            descriptions.extend(document.select(&blocks).map(text_of));
+        }
+        details.description = descriptions
+            .into_iter()
+            .filter(|description| !description.is_empty())
+            .max_by_key(|description| description.chars().count());
+
+        if let Some(topics) = select("a[href*='/topics/'], a[href*='/markets/']") {
+            for topic in document.select(&topics).map(text_of) {
+                if !topic.is_empty() && !details.topics.contains(&topic) {
+                    details.topics.push(topic);
+                }
+            }
+        }
+
+        // Maker profiles live at `/@handle`; upvoters and commenters link
+        // there too, so only the makers block counts
+        let blocks = select("[class*='maker'], [id*='maker']")
+            .map(|blocks| document.select(&blocks).collect::<Vec<_>>())
+            .unwrap_or_default();
+        if let Some(profiles) = select("a[href^='/@'], a[href*='betalist.com/@']") {
+            for maker in blocks.iter().flat_map(|block| block.select(&profiles)) {
+                let name = text_of(maker);
+                if name.is_empty()
+                    || name.starts_with('@')
+                    || details.makers.iter().any(|known| known.name == name)
+                {
+                    continue;
+                }
+                let mut person = Person::new(&name, "BetaList");
+                person.role = Some("Maker".to_string());
+                details.makers.push(person);
+            }
+        }
+        for block in &blocks {
+            for person in self
+                .base
+                .extractor
+                .extract_people(&block.html(), "BetaList")
+            {
+                if !details.makers.iter().any(|known| known.name == person.name) {
+                    details.makers.push(person);
+                }
+            }
+        }
+
+        details
+    }
+
+    /// Fetch a startup page and follow its "Visit website" link to the
+    /// company's own domain
+    async fn fetch_details(&self, page_url: &str) -> Option<StartupDetails> {
+        let html = match self.base.fetch_html(page_url).await {
+            Ok(html) => html,
+            Err(e) => {
+                warn!("Failed to fetch BetaList startup page {}: {}", page_url, e);
+                return None;
+            }
+        };
+        let mut details = self.parse_detail_page(&html, page_url);
+        if let Some(ref visit_url) = details.visit_url {
+            details.website = self.resolve_visit_url(visit_url).await;
+        }
+        Some(details)
+    }
+
+    /// First redirect of a visit link that leaves BetaList. The company
+    /// site itself is never fetched, so a dead site still gives its URL.
+    async fn resolve_visit_url(&self, visit_url: &str) -> Option<String> {
+        let betalist = Url::parse(&self.config.base_url).ok()?;
+        let on_betalist = |url: &Url| url.origin() == betalist.origin();
+
+        let mut url = Url::parse(visit_url).ok()?;
+        for _ in 0..MAX_VISIT_HOPS {
+            if !on_betalist(&url) {
+                return Some(url.to_string());
+            }
+            let response = match self.no_redirects.get(url.as_str()).send().await {
+                Ok(response) => response,
+                Err(e) => {
+                    debug!("Failed to follow {}: {}", visit_url, e);
+                    return None;
+                }
+            };
+            let location = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .filter(|_| response.status().is_redirection());
+            match location.and_then(|location| url.join(location).ok()) {
+                Some(next) => url = next,
+                None => {
+                    debug!("{} did not leave BetaList ({})", visit_url, url);
+                    return None;
+                }
+            }
+        }
+        None
+    }
+}
+
 #[async_trait]
 impl SourceScraper for BetaListScraper {
     async fn scrape(&self) -> Result<Vec<Lead>> {
@@ -145,17 +340,53 @@ impl SourceScraper for BetaListScraper {
         let mut leads = Vec::new();
 
         for endpoint in &self.config.endpoints {
+            if lead_cap_reached(self.config.max_leads, leads.len()) {
+                break;
+            }
             let url = format!("{}{}", self.config.base_url, endpoint);
             debug!("Fetching BetaList endpoint: {}", url);
 
             match self.base.fetch_html(&url).await {
                 Ok(html) => {
                     let scraped_data = self.parse_betalist_page(&html)?;
-                    for data in scraped_data {
-                        let lead = self
+                    for mut data in scraped_data {
+                        if lead_cap_reached(self.config.max_leads, leads.len()) {
+                            break;
+                        }
+                        let details = match self.detail_page_url(&data.html) {
+                            Some(page) if self.config.fetch_details => {
+                                let details = self.fetch_details(&page).await;
+                                self.base.rate_limit(250).await;
+                                details
+                            }
+                            _ => None,
+                        };
+
+                        // Resolve before building the lead: the website feeds
+                        // location (ccTLD) and forge enrichment
+                        if let Some(ref details) = details {
+                            if details.website.is_some() {
+                                data.website = details.website.clone();
+                            }
+                            if let Some(ref description) = details.description {
+                                if description.chars().count() > data.raw_text.chars().count() {
+                                    data.raw_text = description.clone();
+                                }
+                            }
+                        }
+
+                        let mut lead = self
                             .base
                             .create_lead_from_scraped_data(data, Source::BetaList)
                             .await;
+                        if let Some(details) = details {
+                            if lead.categories.is_empty() {
+                                lead.categories = details.topics;
+                            }
+                            for maker in details.makers {
+                                lead.add_person(maker);
+                            }
+                        }
                         leads.push(lead);
                     }
                     debug!(
@@ -188,14 +419,34 @@ impl SourceScraper for BetaListScraper {
 
     fn expected_leads_count(&self) -> Option<usize> {
         // Rough estimate: 20-50 startups per page
-        Some(self.config.endpoints.len() * 30)
+        Some(capped_estimate(
+            self.config.endpoints.len() * 30,
+            self.config.max_leads,
+        ))
+    }
+
+    fn request_plan(&self) -> RequestPlan {
+        let urls = self
+            .config
+            .endpoints
+            .iter()
+            .map(|endpoint| format!("{}{}", self.config.base_url, endpoint))
+            .collect();
+        let requests_per_lead = if self.config.fetch_details {
+            // Startup page, then its visit link
+            2
+        } else {
+            0
+        };
+        RequestPlan::new(urls, requests_per_lead)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::PatternsConfig;
+    use crate::config::{Config, PatternsConfig};
+    use crate::forges::test_server;
     use std::collections::HashMap;
 
     fn create_test_scraper() -> BetaListScraper {
@@ -204,12 +455,15 @@ mod tests {
             base_url: "https://betalist.com".to_string(),
             endpoints: vec!["/startups".to_string()],
             selectors: HashMap::new(),
+            fetch_details: false,
+            max_leads: None,
         };
         let client = reqwest::Client::new();
         let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
             .expect("Failed to create extractor");
 
-        BetaListScraper::new(config, client, extractor)
+        BetaListScraper::new(config, &Config::default().scraper, client, extractor)
+            .expect("Failed to create scraper")
     }
 
     #[test]
@@ -261,15 +515,130 @@ mod tests {
         "#,
         );
 
-        if let Some(element) = html.root_element().first_child() {
-            if let Some(element_ref) = scraper::ElementRef::wrap(element) {
-                let result = scraper.extract_betalist_data(&element_ref);
-                assert!(result.is_some());
+        let selector = Selector::parse("#startup-123").expect("valid selector");
+        let element_ref = html.select(&selector).next().expect("startup element");
+        let result = scraper.extract_betalist_data(&element_ref);
+        assert!(result.is_some());
 
-                let data = result.unwrap();
-                assert_eq!(data.name, "Cool App");
-                assert!(data.website.is_some());
-            }
-        }
+        let data = result.unwrap();
+        assert_eq!(data.name, "Cool App");
+        assert!(data.website.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_detail_page_resolves_company_website() {
+        // Nothing listens on the company site: its URL is taken from the
+        // redirect without fetching it
+        let company = "http://127.0.0.1:9";
+        let detail = r#"
+            <html><head>
+              <meta property="og:description" content="Acme - invoicing">
+            </head><body>
+              <h1>Acme</h1>
+              <div class="startup-description">Acme turns timesheets into invoices for
+                freelancers and small agencies, with Stripe payouts built in.</div>
+              <a href="/topics/fintech">Fintech</a> <a href="/topics/saas">SaaS</a>
+              <a href="/topics/saas">SaaS</a>
+              <section class="makers">
+                <a href="/@janedoe">Jane Doe</a> <a href="/@janedoe">@janedoe</a>
+              </section>
+              <div class="upvotes"><a href="/@bobsmith">Bob Smith</a></div>
+              <div class="comment"><a href="/@carol">Carol Jones</a> Looks great!</div>
+              <a href="/startups/acme/visit" rel="nofollow">Visit website</a>
+            </body></html>
+        "#;
+        let listing = r#"
+            <div id="startup-1">
+              <a href="/startups/acme?ref=home"><div class="font-medium">Acme</div></a>
+              <p>Invoices from timesheets</p>
+            </div>
+        "#;
+        let betalist = test_server::serve_raw(vec![
+            (
+                "/startups/acme/visit",
+                "HTTP/1.1 302 Found\r\nLocation: /out/acme\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            ),
+            (
+                "/out/acme",
+                format!(
+                    "HTTP/1.1 302 Found\r\nLocation: {}/?ref=betalist\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    company
+                ),
+            ),
+            ("/startups/acme", ok(detail)),
+            ("/startups", ok(listing)),
+        ])
+        .await;
+
+        let mut scraper = create_test_scraper();
+        scraper.config.base_url = betalist.clone();
+        scraper.config.fetch_details = true;
+
+        assert_eq!(
+            scraper.detail_page_url(listing).as_deref(),
+            Some(format!("{}/startups/acme", betalist).as_str())
+        );
+
+        let leads = scraper.scrape().await.unwrap();
+        assert_eq!(leads.len(), 1);
+        let lead = &leads[0];
+        assert_eq!(
+            lead.website.as_deref(),
+            Some(format!("{}/?ref=betalist", company).as_str())
+        );
+        assert!(lead
+            .description
+            .as_deref()
+            .is_some_and(|description| description.contains("Stripe payouts")));
+        assert_eq!(lead.categories, vec!["Fintech", "SaaS"]);
+        assert_eq!(lead.people.len(), 1);
+        assert_eq!(lead.people[0].name, "Jane Doe");
+        assert_eq!(lead.people[0].role.as_deref(), Some("Maker"));
+    }
+
+    #[tokio::test]
+    async fn test_max_leads_and_request_plan() {
+        let listing: String = ["Acme", "Bolt", "Cask"]
+            .iter()
+            .map(|name| {
+                format!(
+                    r#"<div id="startup-{0}"><a href="/startups/{0}"><div class="font-medium">{0}</div></a>
+                       <p>{0} builds things</p></div>"#,
+                    name
+                )
+            })
+            .collect();
+        let betalist = test_server::serve_raw(vec![("/startups", ok(&listing))]).await;
+
+        let mut scraper = create_test_scraper();
+        scraper.config.base_url = betalist.clone();
+        scraper.config.endpoints = vec!["/startups".to_string(), "/startups?page=2".to_string()];
+        scraper.config.max_leads = Some(2);
+
+        let leads = scraper.scrape().await.unwrap();
+        let names: Vec<&str> = leads.iter().map(|lead| lead.name.as_str()).collect();
+        assert_eq!(names, vec!["Acme", "Bolt"]);
+        assert_eq!(scraper.expected_leads_count(), Some(2));
+
+        scraper.config.fetch_details = true;
+        let plan = scraper.request_plan();
+        assert_eq!(
+            plan.urls,
+            vec![
+                format!("{}/startups", betalist),
+                format!("{}/startups?page=2", betalist)
+            ]
+        );
+        assert_eq!(plan.requests_per_lead, 2);
+        assert_eq!(plan.estimated_requests(2), 6);
+    }
+
+    fn ok(body: &str) -> String {
+        format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        )
     }
 }
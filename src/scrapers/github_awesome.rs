@@ -1,12 +1,18 @@
-use super::{base::BaseScraper, SourceScraper};
+use super::{
+    awesome_list::{AwesomeEntry, AwesomeListParser},
+    base::BaseScraper,
+    capped_estimate, lead_cap_reached, RequestPlan, SourceScraper,
+};
 use crate::{
     config::GitHubAwesomeConfig,
-    errors::{Result, ScrapingError},
+    errors::Result,
     extractors::DataExtractor,
-    models::{Lead, ScrapedData, Source},
+    forges::RepoRef,
+    models::{Lead, ScrapedData},
 };
 use async_trait::async_trait;
 use reqwest::Client;
+use std::collections::{HashSet, VecDeque};
 use tracing::{debug, info, warn};
 
 /// GitHub Awesome lists scraper implementation
@@ -23,150 +29,150 @@ impl GitHubAwesomeScraper {
         }
     }
 
-    /// Parse GitHub awesome list README content and extract project links
-    fn parse_github_awesome_content(&self, content: &str) -> Result<Vec<ScrapedData>> {
-        let mut scraped_data = Vec::new();
+    /// Parse awesome list README content into project entries; relative
+    /// links are resolved against `base_url`
+    fn parse_awesome_content(&self, content: &str, base_url: Option<&str>) -> Vec<AwesomeEntry> {
         debug!(
-            "Parsing GitHub awesome content, total lines: {}",
+            "Parsing awesome list content, total lines: {}",
             content.lines().count()
         );
 
-        // Enhanced regex to handle various markdown link formats
-        let link_patterns = [
-            // Standard: [Name](URL) - Description
-            r"\[([^\]]+)\]\(([^)]+)\)(?:\s*[-–—]\s*(.+))?",
-            // With emoji: - 🚀 [Name](URL) - Description
-            r"[-*]\s*(?:[^\[\]]*\s+)?\[([^\]]+)\]\(([^)]+)\)(?:\s*[-–—]\s*(.+))?",
-            // Simple: [Name](URL)
-            r"\[([^\]]+)\]\(([^)]+)\)",
-        ];
-
-        let mut processed_count = 0;
-        let mut valid_count = 0;
-
-        for pattern in &link_patterns {
-            let link_regex = regex::Regex::new(pattern).map_err(|e| {
-                ScrapingError::RegexError(format!("Failed to compile GitHub regex: {}", e))
-            })?;
-
-            for (line_num, line) in content.lines().enumerate() {
-                // Skip navigation links, headers, and non-project links
-                if line.trim().starts_with('#')
-                    || line.contains("contents")
-                    || line.contains("awesome-")
-                    || line.contains("github.com/sindresorhus")
-                {
-                    continue;
+        let entries: Vec<AwesomeEntry> = AwesomeListParser::new(base_url)
+            .parse(content)
+            .into_iter()
+            .filter(|entry| {
+                let valid = self
+                    .base
+                    .is_valid_project_link(&entry.name, &Some(entry.url.clone()));
+                if !valid {
+                    debug!("❌ Filtered out: {}", entry.name);
                 }
+                valid
+            })
+            .collect();
 
-                if let Some(caps) = link_regex.captures(line) {
-                    processed_count += 1;
+        debug!("Parsing complete: {} valid projects found", entries.len());
+        entries
+    }
 
-                    let name = caps
-                        .get(1)
-                        .map(|m| m.as_str().trim().to_string())
-                        .unwrap_or_else(|| "Unknown".to_string());
+    /// Whether an entry links to another awesome list rather than a project
+    fn is_awesome_list_link(entry: &AwesomeEntry, repo_ref: &RepoRef) -> bool {
+        let repo_name = repo_ref.path.rsplit('/').next().unwrap_or("");
+        entry.name.to_lowercase().starts_with("awesome")
+            || repo_name.to_lowercase().starts_with("awesome")
+    }
 
-                    let url = caps.get(2).map(|m| m.as_str().trim().to_string());
+    /// Scrape a single awesome list README into at most `max_leads` leads.
+    /// `list_path` ends with this list; when `follow_lists` is set, links to
+    /// other awesome lists are returned as nested lists instead of becoming leads.
+    async fn scrape_repository(
+        &self,
+        repo: &str,
+        list_path: &[String],
+        follow_lists: bool,
+        max_leads: Option<usize>,
+    ) -> Result<ListScrape> {
+        let mut result = ListScrape::default();
+
+        let Some((forge, repo_ref)) = self.base.extractor.forges().resolve_spec(repo) else {
+            warn!("No configured forge hosts awesome list {}", repo);
+            return Ok(result);
+        };
+        debug!("Fetching {} README: {}", forge.name(), repo_ref.path);
 
-                    let description = caps
-                        .get(3)
-                        .map(|m| m.as_str().trim().to_string())
-                        .unwrap_or_else(|| "No description".to_string());
+        let content = match forge.fetch_readme(&repo_ref).await {
+            Ok(content) => content,
+            Err(e) => {
+                warn!("Failed to fetch README for {}: {}", repo, e);
+                return Ok(result);
+            }
+        };
 
-                    debug!(
-                        "Line {}: Found potential project '{}' -> {:?}",
-                        line_num + 1,
-                        name,
-                        url
-                    );
+        // Relative links resolve against the README's directory on the default branch
+        let branch = match forge.repo_info(&repo_ref).await {
+            Ok(info) => info.default_branch,
+            Err(_) => "HEAD".to_string(),
+        };
+        let base_url = forge.file_web_url(&repo_ref, &branch, "");
 
-                    // Filter out generic/navigation links
-                    if self.base.is_valid_project_link(&name, &url) {
-                        valid_count += 1;
-                        let url_ref = url.as_ref().map(|u| u.as_str()).unwrap_or("");
-                        scraped_data.push(ScrapedData {
-                            name: self.base.clean_project_name(&name),
-                            website: url.clone(),
-                            raw_text: description.clone(),
-                            html: format!("<a href='{}'>{}</a> - {}", url_ref, name, description),
-                        });
-                        debug!("✅ Added valid project: {}", name);
-                    } else {
-                        debug!("❌ Filtered out: {}", name);
-                    }
-                }
-            }
+        let entries = self.parse_awesome_content(&content, Some(&base_url));
+        debug!("Found {} projects in repository {}", entries.len(), repo);
 
-            // If we found projects with this pattern, don't try other patterns to avoid duplicates
-            if !scraped_data.is_empty() {
+        for entry in entries {
+            if lead_cap_reached(max_leads, result.leads.len()) {
                 break;
             }
-        }
-
-        debug!(
-            "Parsing complete: {} processed, {} valid projects found",
-            processed_count, valid_count
-        );
-        Ok(scraped_data)
-    }
-
-    /// Scrape a single GitHub repository's README
-    async fn scrape_repository(&self, repo: &str) -> Result<Vec<Lead>> {
-        let mut leads = Vec::new();
-
-        let url = format!("{}/{}/contents/readme.md", self.config.api_base, repo);
-        debug!("Fetching GitHub repo: {}", url);
-
-        let content = match self.base.fetch_github_readme(&url).await {
-            Ok(content) => content,
-            Err(_) => {
-                // Try README.md as fallback
-                let fallback_url = format!("{}/{}/contents/README.md", self.config.api_base, repo);
-                debug!("Trying fallback URL: {}", fallback_url);
-
-                match self.base.fetch_github_readme(&fallback_url).await {
-                    Ok(content) => content,
-                    Err(e) => {
-                        warn!("Failed to fetch README for {}: {}", repo, e);
-                        return Ok(leads);
+            if follow_lists {
+                let linked = self.base.extractor.forges().resolve(&entry.url);
+                if let Some((_, linked_ref)) = linked {
+                    if Self::is_awesome_list_link(&entry, &linked_ref) {
+                        debug!("📚 Nested awesome list: {}", entry.url);
+                        result.nested.push(entry.url);
+                        continue;
                     }
                 }
             }
-        };
 
-        let scraped_data = self.parse_github_awesome_content(&content)?;
-        debug!(
-            "Found {} projects in repository {}",
-            scraped_data.len(),
-            repo
-        );
+            let description = entry
+                .description
+                .unwrap_or_else(|| "No description".to_string());
+            let data = ScrapedData {
+                name: self.base.clean_project_name(&entry.name),
+                website: Some(entry.url.clone()),
+                html: format!(
+                    "<a href='{}'>{}</a> - {}",
+                    entry.url, entry.name, description
+                ),
+                raw_text: description,
+            };
 
-        for data in scraped_data {
             // Create source based on actual project URL, not awesome list
             let source = self.base.determine_source_from_url(&data.website);
-            let mut lead = self.base.create_lead_from_scraped_data(data, source).await;
-
-            // Extract real GitHub emails from commits if it's a GitHub project
-            if let Some(ref website) = lead.website {
-                if website.contains("github.com") {
-                    let commit_emails = self
-                        .base
-                        .extractor
-                        .extract_github_commit_emails(website)
-                        .await;
-                    if !commit_emails.is_empty() {
-                        // Use first (highest priority) email as github_email
-                        lead.github_email = commit_emails.into_iter().next();
-                    }
+            let mut lead = self
+                .base
+                .create_lead_from_scraped_data(data, source)
+                .await
+                .with_categories(entry.categories)
+                .with_list_path(list_path.to_vec());
+
+            // "Source Code" link next to a project website
+            if lead.repository.is_none() {
+                if let Some(repository) = entry.repository {
+                    lead.repository = Some(repository);
+                    self.base.extractor.enrich_repository_lead(&mut lead).await;
                 }
             }
 
-            leads.push(lead);
+            result.leads.push(lead);
         }
 
-        Ok(leads)
+        Ok(result)
+    }
+
+    /// Key identifying a list repository across shorthand and URL spellings
+    fn list_key(&self, spec: &str) -> String {
+        match self.base.extractor.forges().resolve_spec(spec) {
+            Some((forge, repo_ref)) => forge.repo_web_url(&repo_ref).to_lowercase(),
+            None => spec.to_lowercase(),
+        }
     }
+
+    /// Display name of a list in a lead's list path
+    fn list_label(&self, spec: &str) -> String {
+        self.base
+            .extractor
+            .forges()
+            .resolve_spec(spec)
+            .map(|(_, repo_ref)| repo_ref.path)
+            .unwrap_or_else(|| spec.to_string())
+    }
+}
+
+/// Leads and linked awesome lists found in one list
+#[derive(Debug, Default)]
+struct ListScrape {
+    leads: Vec<Lead>,
+    nested: Vec<String>,
 }
 
 #[async_trait]
@@ -175,13 +181,60 @@ impl SourceScraper for GitHubAwesomeScraper {
         info!("🚀 Starting GitHub Awesome lists scraping...");
         let mut all_leads = Vec::new();
 
-        for repo in &self.config.repositories {
-            info!("📚 Scraping repository: {}", repo);
-
-            match self.scrape_repository(repo).await {
-                Ok(mut leads) => {
-                    info!("✅ Found {} leads in {}", leads.len(), repo);
-                    all_leads.append(&mut leads);
+        // Breadth-first over lists: (spec, path of lists leading to it, depth)
+        let mut queue: VecDeque<(String, Vec<String>, usize)> = self
+            .config
+            .repositories
+            .iter()
+            .map(|repo| (repo.clone(), Vec::new(), 0))
+            .collect();
+        let mut visited = HashSet::new();
+        let mut fetched = 0;
+
+        while let Some((repo, parent_path, depth)) = queue.pop_front() {
+            if !visited.insert(self.list_key(&repo)) {
+                debug!("Skipping already visited list {}", repo);
+                continue;
+            }
+            if lead_cap_reached(self.config.max_leads, all_leads.len()) {
+                break;
+            }
+            if fetched >= self.config.max_lists {
+                warn!(
+                    "⚠️ Awesome list budget of {} reached, skipping remaining lists",
+                    self.config.max_lists
+                );
+                break;
+            }
+            fetched += 1;
+
+            info!("📚 Scraping repository: {} (depth {})", repo, depth);
+            let mut list_path = parent_path;
+            list_path.push(self.list_label(&repo));
+            // With recursion on, list links never become leads, even past the depth limit
+            let follow_lists = self.config.max_depth > 0;
+
+            let remaining = self
+                .config
+                .max_leads
+                .map(|max| max.saturating_sub(all_leads.len()));
+            match self
+                .scrape_repository(&repo, &list_path, follow_lists, remaining)
+                .await
+            {
+                Ok(mut result) => {
+                    info!(
+                        "✅ Found {} leads and {} nested lists in {}",
+                        result.leads.len(),
+                        result.nested.len(),
+                        repo
+                    );
+                    all_leads.append(&mut result.leads);
+                    if depth < self.config.max_depth {
+                        for nested in result.nested {
+                            queue.push_back((nested, list_path.clone(), depth + 1));
+                        }
+                    }
                 }
                 Err(e) => {
                     warn!("❌ Failed to scrape repository {}: {}", repo, e);
@@ -210,7 +263,34 @@ impl SourceScraper for GitHubAwesomeScraper {
 
     fn expected_leads_count(&self) -> Option<usize> {
         // Rough estimate: 100-500 projects per awesome list
-        Some(self.config.repositories.len() * 200)
+        Some(capped_estimate(
+            self.config.repositories.len() * 200,
+            self.config.max_leads,
+        ))
+    }
+
+    fn request_plan(&self) -> RequestPlan {
+        let forges = self.base.extractor.forges();
+        let urls: Vec<String> = self
+            .config
+            .repositories
+            .iter()
+            .map(|repo| match forges.resolve_spec(repo) {
+                Some((forge, repo_ref)) => forge.repo_web_url(&repo_ref),
+                None => repo.clone(),
+            })
+            .collect();
+        // README and repository info per list; nested lists count against max_lists
+        let lists = if self.config.max_depth > 0 {
+            self.config.max_lists
+        } else {
+            urls.len().min(self.config.max_lists)
+        };
+        RequestPlan {
+            listing_requests: lists * 2,
+            requests_per_lead: self.base.extractor.repository_requests(),
+            urls,
+        }
     }
 }
 
@@ -223,7 +303,9 @@ mod tests {
         let config = GitHubAwesomeConfig {
             enabled: true,
             repositories: vec!["sindresorhus/awesome".to_string()],
-            api_base: "https://api.github.com/repos".to_string(),
+            max_depth: 0,
+            max_lists: 50,
+            max_leads: None,
         };
         let client = reqwest::Client::new();
         let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
@@ -245,7 +327,7 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_github_awesome_content() {
+    fn test_parse_awesome_content() {
         let scraper = create_test_scraper();
 
         let sample_content = r#"
@@ -258,13 +340,73 @@ mod tests {
 - [Contents](#contents) - Navigation link (should be filtered)
         "#;
 
-        let result = scraper.parse_github_awesome_content(sample_content);
-        assert!(result.is_ok());
-
-        let scraped_data = result.unwrap();
+        let scraped_data = scraper.parse_awesome_content(sample_content, None);
         // Should find 2 valid projects (Contents link should be filtered out)
         assert_eq!(scraped_data.len(), 2);
         assert_eq!(scraped_data[0].name, "Awesome Project");
         assert_eq!(scraped_data[1].name, "Cool Tool");
+        assert_eq!(scraped_data[0].categories, vec!["Projects"]);
+    }
+
+    #[tokio::test]
+    async fn test_recursive_crawl_follows_nested_lists() {
+        use crate::config::{ForgeConfig, ForgesConfig};
+        use crate::forges::{test_server, ForgeRegistry};
+        use base64::{engine::general_purpose, Engine as _};
+
+        let readme = |markdown: &str| {
+            format!(
+                r#"{{"content": "{}"}}"#,
+                general_purpose::STANDARD.encode(markdown)
+            )
+        };
+        let api = test_server::serve(vec![
+            (
+                "/repos/acme/awesome-root/readme",
+                readme(
+                    "## Lists\n\
+                     - [Awesome Sub](https://github.test/acme/awesome-sub) - More tools.\n\
+                     - [Tool](https://tool.example) - A tool.\n",
+                ),
+            ),
+            (
+                "/repos/acme/awesome-sub/readme",
+                readme(
+                    "## Widgets\n\
+                     - [Widget](https://widget.example) - A widget.\n\
+                     - [Awesome Root](https://github.test/acme/awesome-root) - Back link.\n",
+                ),
+            ),
+        ])
+        .await;
+
+        let forges = ForgesConfig {
+            github: ForgeConfig::new("https://github.test", &api),
+            ..ForgesConfig::default()
+        };
+        let client = Client::new();
+        let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
+            .unwrap()
+            .with_forges(ForgeRegistry::from_config(client.clone(), &forges, None));
+        let config = GitHubAwesomeConfig {
+            enabled: true,
+            repositories: vec!["acme/awesome-root".to_string()],
+            max_depth: 1,
+            max_lists: 10,
+            max_leads: None,
+        };
+
+        let leads = GitHubAwesomeScraper::new(config, client, extractor)
+            .scrape()
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = leads.iter().map(|lead| lead.name.as_str()).collect();
+        assert_eq!(names, vec!["Tool", "Widget"]);
+        assert_eq!(leads[0].list_path, vec!["acme/awesome-root"]);
+        assert_eq!(
+            leads[1].list_path,
+            vec!["acme/awesome-root", "acme/awesome-sub"]
+        );
     }
 }
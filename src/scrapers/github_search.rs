@@ -0,0 +1,557 @@
+use super::{base::BaseScraper, capped_estimate, lead_cap_reached, RequestPlan, SourceScraper};
+use crate::{
+    config::{GitHubSearchConfig, GitHubSearchKind, GitHubSearchQuery},
+    errors::{Result, ScrapingError},
+    extractors::DataExtractor,
+    forges::OwnerProfile,
+    models::{Lead, Source},
+};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::HashSet;
+use tracing::{debug, info, warn};
+
+// GitHub search API structures
+#[derive(Debug, Deserialize)]
+struct SearchResponse<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepositoryHit {
+    html_url: String,
+    description: Option<String>,
+    owner: OwnerHit,
+}
+
+#[derive(Debug, Deserialize)]
+struct OwnerHit {
+    login: String,
+}
+
+/// Owner found by a search, with the repository that surfaced it (if any)
+struct OwnerCandidate {
+    login: String,
+    repository: Option<String>,
+    description: Option<String>,
+}
+
+/// GitHub repository/organization search scraper implementation
+pub struct GitHubSearchScraper {
+    config: GitHubSearchConfig,
+    github_token: Option<String>,
+    base: BaseScraper,
+}
+
+impl GitHubSearchScraper {
+    pub fn new(
+        config: GitHubSearchConfig,
+        github_token: Option<String>,
+        client: Client,
+        extractor: DataExtractor,
+    ) -> Self {
+        Self {
+            config,
+            github_token,
+            base: BaseScraper::new(client, extractor),
+        }
+    }
+
+    /// Build the `q` parameter of a search request from a configured query
+    fn build_search_query(&self, query: &GitHubSearchQuery) -> String {
+        let mut terms = Vec::new();
+
+        match query.kind {
+            GitHubSearchKind::Repositories => {
+                for topic in &query.topics {
+                    terms.push(format!("topic:{}", topic));
+                }
+                match (query.min_stars, query.max_stars) {
+                    (Some(min), Some(max)) => terms.push(format!("stars:{}..{}", min, max)),
+                    (Some(min), None) => terms.push(format!("stars:>={}", min)),
+                    (None, Some(max)) => terms.push(format!("stars:<={}", max)),
+                    (None, None) => {}
+                }
+                // Repository search has no location qualifier; owners are
+                // matched against it once their profile is fetched
+            }
+            GitHubSearchKind::Organizations => {
+                terms.push("type:org".to_string());
+                if let Some(ref location) = query.location {
+                    terms.push(format!("location:{}", Self::quote_term(location)));
+                }
+                if !query.topics.is_empty()
+                    || query.min_stars.is_some()
+                    || query.max_stars.is_some()
+                {
+                    debug!("Ignoring topic/star qualifiers for organization search");
+                }
+            }
+        }
+
+        if let Some(ref language) = query.language {
+            terms.push(format!("language:{}", Self::quote_term(language)));
+        }
+
+        match (&query.created_after, &query.created_before) {
+            (Some(after), Some(before)) => terms.push(format!("created:{}..{}", after, before)),
+            (Some(after), None) => terms.push(format!("created:>={}", after)),
+            (None, Some(before)) => terms.push(format!("created:<={}", before)),
+            (None, None) => {}
+        }
+
+        terms.join(" ")
+    }
+
+    /// Quote qualifier values containing spaces (e.g. `location:"New York"`)
+    fn quote_term(value: &str) -> String {
+        if value.contains(' ') {
+            format!("\"{}\"", value)
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Perform an authenticated GET against the GitHub API and decode the JSON body
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        debug!("GitHub API request: {}", url);
+
+        let mut request = self
+            .base
+            .client
+            .get(url)
+            .header("Accept", "application/vnd.github.v3+json")
+            .header("User-Agent", "Lead-Scraper/1.0");
+        if let Some(ref token) = self.github_token {
+            request = request.header("Authorization", format!("token {}", token));
+        }
+
+        let response = request.send().await.map_err(|e| {
+            ScrapingError::NetworkError(format!("GitHub API request failed for '{}': {}", url, e))
+        })?;
+
+        if !response.status().is_success() {
+            return Err(ScrapingError::NetworkError(format!(
+                "GitHub API error {}: {}",
+                response.status(),
+                url
+            )));
+        }
+
+        response.json().await.map_err(|e| {
+            ScrapingError::ParseError(format!("Failed to parse GitHub API response: {}", e))
+        })
+    }
+
+    /// User or organization profile, from the same API base as the search
+    async fn owner_profile(&self, login: &str) -> Result<OwnerProfile> {
+        self.get_json(&format!("{}/users/{}", self.config.api_base, login))
+            .await
+    }
+
+    /// Search API URL for one page of a query
+    fn search_url(
+        &self,
+        kind: GitHubSearchKind,
+        q: &str,
+        page: u32,
+    ) -> std::result::Result<String, url::ParseError> {
+        let endpoint = match kind {
+            GitHubSearchKind::Repositories => "repositories",
+            GitHubSearchKind::Organizations => "users",
+        };
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}/search/{}", self.config.api_base, endpoint),
+            &[
+                ("q", q.to_string()),
+                ("per_page", self.config.per_page.to_string()),
+                ("page", page.to_string()),
+            ],
+        )?;
+        Ok(url.to_string())
+    }
+
+    /// Run one configured query and collect the distinct owners it returns
+    async fn search_owners(&self, query: &GitHubSearchQuery, q: &str) -> Vec<OwnerCandidate> {
+        let mut candidates = Vec::new();
+
+        for page in 1..=self.config.max_pages {
+            let url = match self.search_url(query.kind, q, page) {
+                Ok(url) => url,
+                Err(e) => {
+                    warn!("Invalid GitHub search URL for '{}': {}", q, e);
+                    break;
+                }
+            };
+
+            let page_count = match query.kind {
+                GitHubSearchKind::Repositories => {
+                    match self.get_json::<SearchResponse<RepositoryHit>>(&url).await {
+                        Ok(response) => {
+                            let count = response.items.len();
+                            candidates.extend(response.items.into_iter().map(|hit| {
+                                OwnerCandidate {
+                                    login: hit.owner.login,
+                                    repository: Some(hit.html_url),
+                                    description: hit.description,
+                                }
+                            }));
+                            count
+                        }
+                        Err(e) => {
+                            warn!("❌ GitHub repository search failed for '{}': {}", q, e);
+                            break;
+                        }
+                    }
+                }
+                GitHubSearchKind::Organizations => {
+                    match self.get_json::<SearchResponse<OwnerHit>>(&url).await {
+                        Ok(response) => {
+                            let count = response.items.len();
+                            candidates.extend(response.items.into_iter().map(|hit| {
+                                OwnerCandidate {
+                                    login: hit.login,
+                                    repository: None,
+                                    description: None,
+                                }
+                            }));
+                            count
+                        }
+                        Err(e) => {
+                            warn!("❌ GitHub organization search failed for '{}': {}", q, e);
+                            break;
+                        }
+                    }
+                }
+            };
+
+            // Last page reached
+            if page_count < self.config.per_page as usize {
+                break;
+            }
+
+            // Search API is limited to 30 requests/minute with a token
+            self.base.rate_limit(2000).await;
+        }
+
+        candidates
+    }
+
+    /// Whether an owner matches the query's `location`, the way GitHub's
+    /// `location:` qualifier matches organizations
+    fn owner_in_location(profile: &OwnerProfile, location: Option<&str>) -> bool {
+        let Some(location) = location else {
+            return true;
+        };
+        profile
+            .location
+            .as_deref()
+            .is_some_and(|owner| owner.to_lowercase().contains(&location.to_lowercase()))
+    }
+
+    /// Build a lead from an owner profile and the search hit that surfaced it
+    fn lead_from_profile(
+        &self,
+        profile: OwnerProfile,
+        description: Option<String>,
+        query: &str,
+    ) -> Lead {
+        let name = profile
+            .name
+            .clone()
+            .unwrap_or_else(|| profile.login.clone());
+        let html_url = profile.html_url.clone();
+        let description = profile
+            .bio
+            .clone()
+            .or(description)
+            .map(|text| self.base.extractor.clean_text(&text));
+
+        let mut lead = Lead::new(
+            name,
+            Source::GitHubSearch {
+                query: query.to_string(),
+            },
+        )
+        .with_description(description);
+        self.base.extractor.apply_owner_profile(&mut lead, profile);
+
+        // Without a blog, the GitHub profile itself is the best website
+        if lead.website.is_none() {
+            lead.website = Some(html_url);
+        }
+        lead
+    }
+}
+
+#[async_trait]
+impl SourceScraper for GitHubSearchScraper {
+    async fn scrape(&self) -> Result<Vec<Lead>> {
+        info!("🚀 Starting GitHub search scraping...");
+        let mut leads = Vec::new();
+        let mut seen_owners = HashSet::new();
+
+        for query in &self.config.queries {
+            if lead_cap_reached(self.config.max_leads, leads.len()) {
+                break;
+            }
+            let q = self.build_search_query(query);
+            info!("🔎 GitHub search ({:?}): {}", query.kind, q);
+
+            for candidate in self.search_owners(query, &q).await {
+                if lead_cap_reached(self.config.max_leads, leads.len()) {
+                    break;
+                }
+                if !seen_owners.insert(candidate.login.to_lowercase()) {
+                    continue;
+                }
+
+                let profile = match self.owner_profile(&candidate.login).await {
+                    Ok(profile) => profile,
+                    Err(e) => {
+                        warn!("Failed to fetch GitHub owner {}: {}", candidate.login, e);
+                        continue;
+                    }
+                };
+
+                if query.kind == GitHubSearchKind::Repositories
+                    && !Self::owner_in_location(&profile, query.location.as_deref())
+                {
+                    debug!("Skipping {}: outside {:?}", candidate.login, query.location);
+                    continue;
+                }
+
+                let mut lead = self.lead_from_profile(profile, candidate.description, &q);
+
+                // The owner profile is already applied; add contributors and health
+                lead.repository = candidate.repository;
+                self.base
+                    .extractor
+                    .enrich_repository_activity(&mut lead)
+                    .await;
+
+                debug!("✅ Added GitHub owner: {}", lead.name);
+                leads.push(lead);
+
+                self.base.rate_limit(250).await;
+            }
+        }
+
+        info!("✅ GitHub search scraping complete: {} leads", leads.len());
+        Ok(leads)
+    }
+
+    fn source_name(&self) -> &'static str {
+        "GitHub Search"
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn expected_leads_count(&self) -> Option<usize> {
+        // Upper bound: every hit is a distinct owner
+        Some(capped_estimate(
+            self.config.queries.len() * (self.config.per_page * self.config.max_pages) as usize,
+            self.config.max_leads,
+        ))
+    }
+
+    fn request_plan(&self) -> RequestPlan {
+        let mut urls = Vec::new();
+        for query in &self.config.queries {
+            let q = self.build_search_query(query);
+            for page in 1..=self.config.max_pages {
+                match self.search_url(query.kind, &q, page) {
+                    Ok(url) => urls.push(url),
+                    Err(e) => warn!("Invalid GitHub search URL for '{}': {}", q, e),
+                }
+            }
+        }
+        // Owner profile, then contributors and repository health
+        let requests_per_lead = 1 + self.base.extractor.repository_activity_requests();
+        RequestPlan::new(urls, requests_per_lead)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PatternsConfig;
+
+    fn create_test_scraper() -> GitHubSearchScraper {
+        let config = GitHubSearchConfig {
+            enabled: true,
+            ..GitHubSearchConfig::default()
+        };
+        let client = reqwest::Client::new();
+        let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
+            .expect("Failed to create extractor");
+
+        GitHubSearchScraper::new(config, None, client, extractor)
+    }
+
+    fn query(kind: GitHubSearchKind) -> GitHubSearchQuery {
+        GitHubSearchQuery {
+            kind,
+            topics: vec![],
+            language: None,
+            min_stars: None,
+            max_stars: None,
+            created_after: None,
+            created_before: None,
+            location: None,
+        }
+    }
+
+    #[test]
+    fn test_source_name() {
+        let scraper = create_test_scraper();
+        assert_eq!(scraper.source_name(), "GitHub Search");
+        assert!(scraper.is_enabled());
+    }
+
+    #[test]
+    fn test_build_repository_query() {
+        let scraper = create_test_scraper();
+        let query = GitHubSearchQuery {
+            topics: vec!["developer-tools".to_string()],
+            language: Some("rust".to_string()),
+            min_stars: Some(100),
+            max_stars: Some(5000),
+            created_after: Some("2022-01-01".to_string()),
+            ..query(GitHubSearchKind::Repositories)
+        };
+
+        assert_eq!(
+            scraper.build_search_query(&query),
+            "topic:developer-tools stars:100..5000 language:rust created:>=2022-01-01"
+        );
+    }
+
+    #[test]
+    fn test_build_organization_query() {
+        let scraper = create_test_scraper();
+        let query = GitHubSearchQuery {
+            location: Some("New York".to_string()),
+            created_before: Some("2023-12-31".to_string()),
+            min_stars: Some(10),
+            ..query(GitHubSearchKind::Organizations)
+        };
+
+        assert_eq!(
+            scraper.build_search_query(&query),
+            "type:org location:\"New York\" created:<=2023-12-31"
+        );
+    }
+
+    #[test]
+    fn test_repository_owners_filtered_by_location() {
+        let profile = |location: Option<&str>| OwnerProfile {
+            login: "acme".to_string(),
+            name: None,
+            html_url: "https://github.com/acme".to_string(),
+            blog: None,
+            company: None,
+            email: None,
+            location: location.map(str::to_string),
+            twitter_username: None,
+            bio: None,
+            public_repos: None,
+            followers: None,
+        };
+
+        let scraper = create_test_scraper();
+        let query = GitHubSearchQuery {
+            location: Some("Germany".to_string()),
+            topics: vec!["cli".to_string()],
+            ..query(GitHubSearchKind::Repositories)
+        };
+        // Repository search has no location qualifier
+        assert_eq!(scraper.build_search_query(&query), "topic:cli");
+
+        let location = query.location.as_deref();
+        assert!(GitHubSearchScraper::owner_in_location(
+            &profile(Some("Berlin, germany")),
+            location
+        ));
+        assert!(!GitHubSearchScraper::owner_in_location(
+            &profile(Some("Paris, France")),
+            location
+        ));
+        assert!(!GitHubSearchScraper::owner_in_location(
+            &profile(None),
+            location
+        ));
+        assert!(GitHubSearchScraper::owner_in_location(&profile(None), None));
+    }
+
+    #[tokio::test]
+    async fn test_search_and_profiles_share_api_base() {
+        use crate::forges::test_server;
+
+        let api = test_server::serve(vec![
+            (
+                "/search/users",
+                r#"{"items": [{"login": "acme"}]}"#.to_string(),
+            ),
+            (
+                "/users/acme",
+                r#"{"login": "acme", "name": "Acme Inc",
+                    "html_url": "https://github.com/acme", "blog": "acme.dev"}"#
+                    .to_string(),
+            ),
+        ])
+        .await;
+
+        let config = GitHubSearchConfig {
+            enabled: true,
+            api_base: api,
+            max_pages: 1,
+            queries: vec![query(GitHubSearchKind::Organizations)],
+            ..GitHubSearchConfig::default()
+        };
+        let client = reqwest::Client::new();
+        let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
+            .expect("Failed to create extractor");
+
+        let leads = GitHubSearchScraper::new(config, None, client, extractor)
+            .scrape()
+            .await
+            .unwrap();
+        assert_eq!(leads.len(), 1);
+        assert_eq!(leads[0].name, "Acme Inc");
+        assert_eq!(leads[0].website.as_deref(), Some("https://acme.dev"));
+    }
+
+    #[test]
+    fn test_lead_from_profile() {
+        let scraper = create_test_scraper();
+        let profile: OwnerProfile = serde_json::from_str(
+            r#"{
+                "login": "acme",
+                "name": "Acme Inc",
+                "html_url": "https://github.com/acme",
+                "blog": "acme.dev",
+                "email": "hello@acme.dev",
+                "location": "Berlin, Germany",
+                "twitter_username": "acmehq",
+                "bio": null
+            }"#,
+        )
+        .expect("valid profile");
+
+        let lead = scraper.lead_from_profile(profile, Some("Acme CLI".to_string()), "type:org");
+        assert_eq!(lead.name, "Acme Inc");
+        assert_eq!(lead.website.as_deref(), Some("https://acme.dev"));
+        assert_eq!(lead.email.as_deref(), Some("hello@acme.dev"));
+        assert_eq!(lead.twitter.as_deref(), Some("https://twitter.com/acmehq"));
+        assert_eq!(lead.country(), Some("Germany"));
+        assert_eq!(
+            lead.location.as_ref().and_then(|l| l.city.as_deref()),
+            Some("Berlin")
+        );
+        assert_eq!(lead.description.as_deref(), Some("Acme CLI"));
+    }
+}
@@ -1,15 +1,21 @@
 use crate::errors::Result;
 use crate::models::Lead;
 use async_trait::async_trait;
+use serde::Serialize;
 
+pub mod awesome_list;
 pub mod base;
 pub mod betalist;
 pub mod github_awesome;
+pub mod github_search;
+pub mod package_registry;
 pub mod ycombinator;
 
 pub use base::BaseScraper;
 pub use betalist::BetaListScraper;
 pub use github_awesome::GitHubAwesomeScraper;
+pub use github_search::GitHubSearchScraper;
+pub use package_registry::PackageRegistryScraper;
 pub use ycombinator::YCombinatorScraper;
 
 /// Common trait for all source scrapers
@@ -28,6 +34,47 @@ pub trait SourceScraper {
     fn expected_leads_count(&self) -> Option<usize> {
         None
     }
+
+    /// Requests this scraper would make, without making any (`--dry-run`)
+    fn request_plan(&self) -> RequestPlan {
+        RequestPlan::default()
+    }
+}
+
+/// Requests a scraper plans to make before and after it knows its leads
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RequestPlan {
+    /// Listing pages, searches and registry queries, in order
+    pub urls: Vec<String>,
+    /// Requests behind `urls`; some take more than one call
+    pub listing_requests: usize,
+    /// Follow-up requests per lead (detail pages, forge enrichment), at most
+    pub requests_per_lead: usize,
+}
+
+impl RequestPlan {
+    pub fn new(urls: Vec<String>, requests_per_lead: usize) -> Self {
+        Self {
+            listing_requests: urls.len(),
+            urls,
+            requests_per_lead,
+        }
+    }
+
+    /// Estimated requests for a number of leads
+    pub fn estimated_requests(&self, leads: usize) -> usize {
+        self.listing_requests + leads * self.requests_per_lead
+    }
+}
+
+/// Whether a source capped at `max_leads` has collected enough
+pub fn lead_cap_reached(max_leads: Option<usize>, collected: usize) -> bool {
+    max_leads.is_some_and(|max| collected >= max)
+}
+
+/// An expected lead count, limited by the source's cap
+pub fn capped_estimate(expected: usize, max_leads: Option<usize>) -> usize {
+    max_leads.map_or(expected, |max| expected.min(max))
 }
 
 /// Factory function to create all enabled scrapers
@@ -35,7 +82,7 @@ pub fn create_scrapers(
     config: &crate::config::Config,
     client: &reqwest::Client,
     extractor: &crate::extractors::DataExtractor,
-) -> Vec<Box<dyn SourceScraper + Send + Sync>> {
+) -> Result<Vec<Box<dyn SourceScraper + Send + Sync>>> {
     let mut scrapers: Vec<Box<dyn SourceScraper + Send + Sync>> = Vec::new();
 
     // Y Combinator scraper
@@ -60,10 +107,30 @@ pub fn create_scrapers(
     if config.sources.betalist.enabled {
         scrapers.push(Box::new(BetaListScraper::new(
             config.sources.betalist.clone(),
+            &config.scraper,
+            client.clone(),
+            extractor.clone(),
+        )?));
+    }
+
+    // GitHub search scraper
+    if config.sources.github_search.enabled {
+        scrapers.push(Box::new(GitHubSearchScraper::new(
+            config.sources.github_search.clone(),
+            config.scraper.github_token.clone(),
+            client.clone(),
+            extractor.clone(),
+        )));
+    }
+
+    // Package registry scraper
+    if config.sources.package_registries.enabled {
+        scrapers.push(Box::new(PackageRegistryScraper::new(
+            config.sources.package_registries.clone(),
             client.clone(),
             extractor.clone(),
         )));
     }
 
-    scrapers
+    Ok(scrapers)
 }
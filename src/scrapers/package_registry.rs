@@ -0,0 +1,788 @@
+use super::{base::BaseScraper, capped_estimate, lead_cap_reached, RequestPlan, SourceScraper};
+use crate::{
+    config::{PackageRegistriesConfig, RegistryQueryConfig},
+    errors::Result,
+    extractors::DataExtractor,
+    models::{Lead, Person, Source},
+};
+use async_trait::async_trait;
+use reqwest::{Client, Url};
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use tracing::{debug, info, warn};
+
+// crates.io API structures
+#[derive(Debug, Deserialize)]
+struct CratesResponse {
+    crates: Vec<CrateEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateEntry {
+    name: String,
+    description: Option<String>,
+    homepage: Option<String>,
+    repository: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesOwnersResponse {
+    users: Vec<CratesOwner>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesOwner {
+    /// GitHub login, or `github:org:team` for team owners
+    login: String,
+    name: Option<String>,
+    kind: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesUserResponse {
+    user: CratesUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct CratesUser {
+    id: u64,
+}
+
+// npm registry search structures
+#[derive(Debug, Deserialize)]
+struct NpmSearchResponse {
+    objects: Vec<NpmSearchObject>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmSearchObject {
+    package: NpmPackage,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmPackage {
+    name: String,
+    description: Option<String>,
+    #[serde(default)]
+    links: NpmLinks,
+    publisher: Option<NpmPerson>,
+    #[serde(default)]
+    maintainers: Vec<NpmPerson>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct NpmLinks {
+    homepage: Option<String>,
+    repository: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NpmPerson {
+    username: Option<String>,
+    email: Option<String>,
+}
+
+// PyPI JSON API structures
+#[derive(Debug, Deserialize)]
+struct PyPiProject {
+    info: PyPiInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct PyPiInfo {
+    name: String,
+    summary: Option<String>,
+    home_page: Option<String>,
+    project_urls: Option<HashMap<String, String>>,
+    author: Option<String>,
+    author_email: Option<String>,
+    maintainer: Option<String>,
+    maintainer_email: Option<String>,
+}
+
+/// Package registries supported by this scraper
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Registry {
+    CratesIo,
+    Npm,
+    PyPi,
+}
+
+impl Registry {
+    fn name(&self) -> &'static str {
+        match self {
+            Registry::CratesIo => "crates.io",
+            Registry::Npm => "npm",
+            Registry::PyPi => "PyPI",
+        }
+    }
+}
+
+/// Registry-independent view of a published package
+#[derive(Debug, Clone)]
+struct PackageInfo {
+    name: String,
+    description: Option<String>,
+    homepage: Option<String>,
+    repository: Option<String>,
+    maintainers: Vec<Maintainer>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Maintainer {
+    name: Option<String>,
+    email: Option<String>,
+    /// GitHub login (crates.io owners sign in with GitHub)
+    github: Option<String>,
+}
+
+/// crates.io / npm / PyPI maintainer scraper implementation
+pub struct PackageRegistryScraper {
+    config: PackageRegistriesConfig,
+    base: BaseScraper,
+}
+
+impl PackageRegistryScraper {
+    pub fn new(config: PackageRegistriesConfig, client: Client, extractor: DataExtractor) -> Self {
+        Self {
+            config,
+            base: BaseScraper::new(client, extractor),
+        }
+    }
+
+    fn registry_config(&self, registry: Registry) -> &RegistryQueryConfig {
+        match registry {
+            Registry::CratesIo => &self.config.crates_io,
+            Registry::Npm => &self.config.npm,
+            Registry::PyPi => &self.config.pypi,
+        }
+    }
+
+    /// Build a URL with properly encoded query parameters
+    fn build_url(base: &str, path: &str, params: &[(&str, String)]) -> Option<String> {
+        match Url::parse_with_params(&format!("{}{}", base, path), params) {
+            Ok(url) => Some(url.to_string()),
+            Err(e) => {
+                warn!("Invalid registry URL {}{}: {}", base, path, e);
+                None
+            }
+        }
+    }
+
+    /// First URL a keyword or owner query fetches: the search itself, the
+    /// PyPI listing page, or the crates.io user to search by
+    fn query_url(
+        &self,
+        registry: Registry,
+        keyword: Option<&str>,
+        owner: Option<&str>,
+    ) -> Option<String> {
+        let config = self.registry_config(registry);
+        let limit = self.config.max_results_per_query.to_string();
+
+        match (registry, keyword, owner) {
+            (Registry::CratesIo, Some(keyword), _) => Self::build_url(
+                &config.api_base,
+                "/crates",
+                &[("per_page", limit), ("keyword", keyword.to_string())],
+            ),
+            (Registry::CratesIo, None, Some(owner)) => {
+                Some(format!("{}/users/{}", config.api_base, owner))
+            }
+            (Registry::Npm, Some(keyword), _) => Self::build_url(
+                &config.api_base,
+                "/-/v1/search",
+                &[("text", format!("keywords:{}", keyword)), ("size", limit)],
+            ),
+            (Registry::Npm, None, Some(owner)) => Self::build_url(
+                &config.api_base,
+                "/-/v1/search",
+                &[("text", format!("maintainer:{}", owner)), ("size", limit)],
+            ),
+            // PyPI has no JSON search API: project names come from HTML
+            (Registry::PyPi, Some(keyword), _) => {
+                Self::build_url(&config.api_base, "/search/", &[("q", keyword.to_string())])
+            }
+            (Registry::PyPi, None, Some(owner)) => {
+                Some(format!("{}/user/{}/", config.api_base, owner))
+            }
+            (_, None, None) => None,
+        }
+    }
+
+    /// Query one registry by keyword or owner
+    async fn query_registry(
+        &self,
+        registry: Registry,
+        keyword: Option<&str>,
+        owner: Option<&str>,
+    ) -> Result<Vec<PackageInfo>> {
+        let config = self.registry_config(registry);
+        let limit = self.config.max_results_per_query;
+        let Some(url) = self.query_url(registry, keyword, owner) else {
+            return Ok(Vec::new());
+        };
+
+        match registry {
+            Registry::CratesIo => {
+                let url = if keyword.is_some() {
+                    url
+                } else {
+                    // Owner queries search by the user's numeric id
+                    let user: CratesUserResponse = self.base.fetch_json(&url).await?;
+                    let params = [
+                        ("per_page", limit.to_string()),
+                        ("user_id", user.user.id.to_string()),
+                    ];
+                    let Some(url) = Self::build_url(&config.api_base, "/crates", &params) else {
+                        return Ok(Vec::new());
+                    };
+                    url
+                };
+                let response: CratesResponse = self.base.fetch_json(&url).await?;
+                let mut packages = Self::packages_from_crates(response);
+
+                // Search results carry no people: fetch each crate's owners
+                for package in &mut packages {
+                    let owners_url = format!("{}/crates/{}/owners", config.api_base, package.name);
+                    match self
+                        .base
+                        .fetch_json::<CratesOwnersResponse>(&owners_url)
+                        .await
+                    {
+                        Ok(owners) => package.maintainers = Self::maintainers_from_crates(owners),
+                        Err(e) => debug!(
+                            "Failed to fetch crates.io owners for {}: {}",
+                            package.name, e
+                        ),
+                    }
+                    self.base.rate_limit(200).await;
+                }
+                Ok(packages)
+            }
+            Registry::Npm => {
+                let response: NpmSearchResponse = self.base.fetch_json(&url).await?;
+                Ok(Self::packages_from_npm(response))
+            }
+            Registry::PyPi => {
+                // List project names, then fetch each project's metadata
+                let html = self.base.fetch_html(&url).await?;
+                let mut packages = Vec::new();
+                for name in Self::pypi_project_names(&html).into_iter().take(limit) {
+                    let metadata_url = format!("{}/pypi/{}/json", config.api_base, name);
+                    match self.base.fetch_json::<PyPiProject>(&metadata_url).await {
+                        Ok(project) => packages.push(Self::package_from_pypi(project)),
+                        Err(e) => debug!("Failed to fetch PyPI metadata for {}: {}", name, e),
+                    }
+                    self.base.rate_limit(200).await;
+                }
+                Ok(packages)
+            }
+        }
+    }
+
+    fn packages_from_crates(response: CratesResponse) -> Vec<PackageInfo> {
+        response
+            .crates
+            .into_iter()
+            .map(|entry| PackageInfo {
+                name: entry.name,
+                description: entry.description,
+                homepage: entry.homepage,
+                repository: entry.repository,
+                maintainers: Vec::new(),
+            })
+            .collect()
+    }
+
+    /// Individual crate owners; team owners are not people
+    fn maintainers_from_crates(response: CratesOwnersResponse) -> Vec<Maintainer> {
+        response
+            .users
+            .into_iter()
+            .filter(|owner| owner.kind.as_deref() != Some("team"))
+            .map(|owner| Maintainer {
+                name: owner.name.filter(|name| !name.trim().is_empty()),
+                email: None,
+                github: Some(owner.login),
+            })
+            .collect()
+    }
+
+    fn packages_from_npm(response: NpmSearchResponse) -> Vec<PackageInfo> {
+        response
+            .objects
+            .into_iter()
+            .map(|object| {
+                let package = object.package;
+                let maintainers = package
+                    .publisher
+                    .into_iter()
+                    .chain(package.maintainers)
+                    .map(|person| Maintainer {
+                        name: person.username,
+                        email: person.email,
+                        github: None,
+                    })
+                    .fold(Vec::new(), |mut acc: Vec<Maintainer>, m| {
+                        if !acc.contains(&m) {
+                            acc.push(m);
+                        }
+                        acc
+                    });
+
+                PackageInfo {
+                    name: package.name,
+                    description: package.description,
+                    homepage: package.links.homepage,
+                    repository: package.links.repository,
+                    maintainers,
+                }
+            })
+            .collect()
+    }
+
+    fn package_from_pypi(project: PyPiProject) -> PackageInfo {
+        let info = project.info;
+        let project_urls = info.project_urls.unwrap_or_default();
+
+        let find_url = |keys: &[&str]| {
+            project_urls
+                .iter()
+                .find(|(label, _)| keys.iter().any(|key| label.eq_ignore_ascii_case(key)))
+                .map(|(_, url)| url.clone())
+        };
+
+        let homepage = info
+            .home_page
+            .filter(|url| !url.is_empty())
+            .or_else(|| find_url(&["Homepage", "Home", "Website"]));
+        let repository = find_url(&["Source", "Source Code", "Repository", "Code", "GitHub"]);
+
+        let mut maintainers =
+            Self::parse_contacts(info.author.as_deref(), info.author_email.as_deref());
+        for maintainer in
+            Self::parse_contacts(info.maintainer.as_deref(), info.maintainer_email.as_deref())
+        {
+            if !maintainers.contains(&maintainer) {
+                maintainers.push(maintainer);
+            }
+        }
+
+        PackageInfo {
+            name: info.name,
+            description: info.summary,
+            homepage,
+            repository,
+            maintainers,
+        }
+    }
+
+    /// Parse PyPI-style contact fields (`"Jane Doe <jane@acme.dev>, bob@acme.dev"`)
+    fn parse_contacts(names: Option<&str>, emails: Option<&str>) -> Vec<Maintainer> {
+        let mut contacts = Vec::new();
+
+        for entry in emails.unwrap_or("").split(',') {
+            let entry = entry.trim();
+            if !entry.contains('@') {
+                continue;
+            }
+            let contact = match (entry.find('<'), entry.rfind('>')) {
+                (Some(start), Some(end)) if start < end => Maintainer {
+                    name: Some(entry[..start].trim().trim_matches('"').to_string())
+                        .filter(|name| !name.is_empty()),
+                    email: Some(entry[start + 1..end].trim().to_string()),
+                    github: None,
+                },
+                _ => Maintainer {
+                    name: None,
+                    email: Some(entry.to_string()),
+                    github: None,
+                },
+            };
+            contacts.push(contact);
+        }
+
+        // Plain author name without a usable email
+        if contacts.is_empty() {
+            if let Some(name) = names.map(str::trim).filter(|name| !name.is_empty()) {
+                contacts.push(Maintainer {
+                    name: Some(name.to_string()),
+                    email: None,
+                    github: None,
+                });
+            }
+        }
+
+        contacts
+    }
+
+    /// Extract project names from a PyPI search or user page
+    fn pypi_project_names(html: &str) -> Vec<String> {
+        let document = Html::parse_document(html);
+        let mut names = Vec::new();
+
+        if let Ok(selector) = Selector::parse("a.package-snippet") {
+            for element in document.select(&selector) {
+                let name = element
+                    .value()
+                    .attr("href")
+                    .and_then(|href| href.strip_prefix("/project/"))
+                    .map(|name| name.trim_end_matches('/').to_string());
+                if let Some(name) = name.filter(|name| !name.is_empty()) {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+            }
+        }
+
+        names
+    }
+
+    /// Normalize `git+https://github.com/x/y.git`-style repository URLs
+    fn normalize_repository_url(url: &str) -> String {
+        let url = url.trim();
+        let url = url.strip_prefix("git+").unwrap_or(url);
+        let url = url.strip_suffix(".git").unwrap_or(url);
+        url.trim_end_matches('/').to_string()
+    }
+
+    /// Build a lead from package metadata
+    fn lead_from_package(&self, registry: Registry, package: PackageInfo) -> Lead {
+        let repository = package
+            .repository
+            .filter(|url| url.starts_with("http") || url.starts_with("git+http"))
+            .map(|url| Self::normalize_repository_url(&url));
+        let homepage = package.homepage.filter(|url| url.starts_with("http"));
+        let website = homepage.or_else(|| repository.clone());
+
+        let email = package
+            .maintainers
+            .iter()
+            .find_map(|maintainer| maintainer.email.clone());
+
+        let description = package
+            .description
+            .map(|text| self.base.extractor.clean_text(&text));
+
+        let mut lead = Lead::new(
+            package.name,
+            Source::PackageRegistry {
+                registry: registry.name().to_string(),
+            },
+        )
+        .with_website(website)
+        .with_repository(repository)
+        .with_email(email)
+        .with_description(description);
+
+        for maintainer in package.maintainers {
+            let Some(name) = maintainer.name.or_else(|| maintainer.github.clone()) else {
+                continue;
+            };
+            lead.add_person(Person {
+                email: maintainer.email,
+                github: maintainer.github,
+                ..Person::new(&name, registry.name())
+            });
+        }
+        lead
+    }
+}
+
+#[async_trait]
+impl SourceScraper for PackageRegistryScraper {
+    async fn scrape(&self) -> Result<Vec<Lead>> {
+        info!("🚀 Starting package registry scraping...");
+        let mut leads = Vec::new();
+        let mut seen = HashSet::new();
+
+        for registry in [Registry::CratesIo, Registry::Npm, Registry::PyPi] {
+            let config = self.registry_config(registry);
+            if !config.enabled {
+                continue;
+            }
+
+            let queries = config
+                .keywords
+                .iter()
+                .map(|keyword| (Some(keyword.as_str()), None))
+                .chain(
+                    config
+                        .owners
+                        .iter()
+                        .map(|owner| (None, Some(owner.as_str()))),
+                );
+
+            for (keyword, owner) in queries {
+                if lead_cap_reached(self.config.max_leads, leads.len()) {
+                    break;
+                }
+                info!(
+                    "📦 Querying {} ({})",
+                    registry.name(),
+                    keyword
+                        .map(|k| format!("keyword: {}", k))
+                        .or_else(|| owner.map(|o| format!("owner: {}", o)))
+                        .unwrap_or_default()
+                );
+
+                let packages = match self.query_registry(registry, keyword, owner).await {
+                    Ok(packages) => packages,
+                    Err(e) => {
+                        warn!("❌ {} query failed: {}", registry.name(), e);
+                        continue;
+                    }
+                };
+
+                for package in packages {
+                    if lead_cap_reached(self.config.max_leads, leads.len()) {
+                        break;
+                    }
+                    if !seen.insert((registry, package.name.to_lowercase())) {
+                        continue;
+                    }
+
+                    let mut lead = self.lead_from_package(registry, package);
+
+                    // Commit emails, health and owner profile from the source repository
+                    self.base.extractor.enrich_repository_lead(&mut lead).await;
+
+                    debug!("✅ Added {} package: {}", registry.name(), lead.name);
+                    leads.push(lead);
+                }
+
+                self.base.rate_limit(1000).await;
+            }
+        }
+
+        info!(
+            "✅ Package registry scraping complete: {} leads",
+            leads.len()
+        );
+        Ok(leads)
+    }
+
+    fn source_name(&self) -> &'static str {
+        "Package Registries"
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn expected_leads_count(&self) -> Option<usize> {
+        let queries: usize = [&self.config.crates_io, &self.config.npm, &self.config.pypi]
+            .iter()
+            .filter(|registry| registry.enabled)
+            .map(|registry| registry.keywords.len() + registry.owners.len())
+            .sum();
+        Some(capped_estimate(
+            queries * self.config.max_results_per_query,
+            self.config.max_leads,
+        ))
+    }
+
+    fn request_plan(&self) -> RequestPlan {
+        let mut urls = Vec::new();
+        let mut listing_requests = 0;
+        for registry in [Registry::CratesIo, Registry::Npm, Registry::PyPi] {
+            let config = self.registry_config(registry);
+            if !config.enabled {
+                continue;
+            }
+            let queries = config
+                .keywords
+                .iter()
+                .map(|keyword| (Some(keyword.as_str()), None))
+                .chain(
+                    config
+                        .owners
+                        .iter()
+                        .map(|owner| (None, Some(owner.as_str()))),
+                );
+            for (keyword, owner) in queries {
+                let Some(url) = self.query_url(registry, keyword, owner) else {
+                    continue;
+                };
+                urls.push(url);
+                listing_requests += match (registry, keyword) {
+                    // User lookup, the search by user id, then owners per crate
+                    (Registry::CratesIo, None) => 2 + self.config.max_results_per_query,
+                    // Search, then owners per crate
+                    (Registry::CratesIo, Some(_)) => 1 + self.config.max_results_per_query,
+                    // Listing page, then metadata per project
+                    (Registry::PyPi, _) => 1 + self.config.max_results_per_query,
+                    _ => 1,
+                };
+            }
+        }
+        RequestPlan {
+            urls,
+            listing_requests,
+            requests_per_lead: self.base.extractor.repository_requests(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PatternsConfig;
+
+    fn create_test_scraper() -> PackageRegistryScraper {
+        let config = PackageRegistriesConfig {
+            enabled: true,
+            ..PackageRegistriesConfig::default()
+        };
+        let client = reqwest::Client::new();
+        let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
+            .expect("Failed to create extractor");
+
+        PackageRegistryScraper::new(config, client, extractor)
+    }
+
+    #[test]
+    fn test_source_name() {
+        let scraper = create_test_scraper();
+        assert_eq!(scraper.source_name(), "Package Registries");
+        assert!(scraper.is_enabled());
+    }
+
+    #[test]
+    fn test_npm_package_to_lead() {
+        let scraper = create_test_scraper();
+        let response: NpmSearchResponse = serde_json::from_str(
+            r#"{"objects": [{"package": {
+                "name": "acme-sdk",
+                "description": "Official Acme SDK",
+                "links": {"repository": "https://github.com/acme/acme-js"},
+                "publisher": {"username": "jane", "email": "jane@acme.dev"},
+                "maintainers": [{"username": "jane", "email": "jane@acme.dev"}]
+            }}]}"#,
+        )
+        .expect("valid npm response");
+
+        let packages = PackageRegistryScraper::packages_from_npm(response);
+        assert_eq!(packages[0].maintainers.len(), 1);
+
+        let lead = scraper.lead_from_package(Registry::Npm, packages[0].clone());
+        assert_eq!(lead.name, "acme-sdk");
+        assert_eq!(
+            lead.website.as_deref(),
+            Some("https://github.com/acme/acme-js")
+        );
+        assert_eq!(
+            lead.repository.as_deref(),
+            Some("https://github.com/acme/acme-js")
+        );
+        assert_eq!(lead.email.as_deref(), Some("jane@acme.dev"));
+        assert_eq!(lead.people.len(), 1);
+        assert_eq!(lead.people[0].name, "jane");
+        assert_eq!(lead.people[0].email.as_deref(), Some("jane@acme.dev"));
+        assert_eq!(lead.people[0].origin, "npm");
+    }
+
+    #[tokio::test]
+    async fn test_crates_owners_become_people() {
+        use crate::forges::test_server;
+
+        let api = test_server::serve(vec![
+            (
+                "/crates/acme-sdk/owners",
+                r#"{"users": [
+                    {"id": 1, "login": "jdoe", "kind": "user", "name": "Jane Doe"},
+                    {"id": 2, "login": "bob", "kind": "user", "name": null},
+                    {"id": 3, "login": "github:acme:core", "kind": "team", "name": "Core"}
+                ]}"#
+                .to_string(),
+            ),
+            (
+                "/crates?",
+                r#"{"crates": [{"name": "acme-sdk", "description": "Acme SDK",
+                    "homepage": "https://acme.dev", "repository": null}]}"#
+                    .to_string(),
+            ),
+        ])
+        .await;
+
+        let mut scraper = create_test_scraper();
+        scraper.config.crates_io.api_base = api;
+        let packages = scraper
+            .query_registry(Registry::CratesIo, Some("sdk"), None)
+            .await
+            .unwrap();
+        let lead = scraper.lead_from_package(Registry::CratesIo, packages[0].clone());
+
+        let people: Vec<(&str, Option<&str>)> = lead
+            .people
+            .iter()
+            .map(|person| (person.name.as_str(), person.github.as_deref()))
+            .collect();
+        assert_eq!(
+            people,
+            vec![("Jane Doe", Some("jdoe")), ("bob", Some("bob"))]
+        );
+        assert!(lead
+            .people
+            .iter()
+            .all(|person| person.origin == "crates.io"));
+    }
+
+    #[test]
+    fn test_pypi_package_metadata() {
+        let project: PyPiProject = serde_json::from_str(
+            r#"{"info": {
+                "name": "acme",
+                "summary": "Acme client",
+                "home_page": "",
+                "project_urls": {"Homepage": "https://acme.dev", "Source": "git+https://github.com/acme/acme-py.git"},
+                "author": "Jane Doe",
+                "author_email": "Jane Doe <jane@acme.dev>, ops@acme.dev",
+                "maintainer": null,
+                "maintainer_email": null
+            }}"#,
+        )
+        .expect("valid PyPI response");
+
+        let package = PackageRegistryScraper::package_from_pypi(project);
+        assert_eq!(package.homepage.as_deref(), Some("https://acme.dev"));
+        assert_eq!(
+            package.maintainers,
+            vec![
+                Maintainer {
+                    name: Some("Jane Doe".to_string()),
+                    email: Some("jane@acme.dev".to_string()),
+                    github: None,
+                },
+                Maintainer {
+                    name: None,
+                    email: Some("ops@acme.dev".to_string()),
+                    github: None,
+                },
+            ]
+        );
+        assert_eq!(
+            PackageRegistryScraper::normalize_repository_url(
+                package.repository.as_deref().unwrap()
+            ),
+            "https://github.com/acme/acme-py"
+        );
+    }
+
+    #[test]
+    fn test_pypi_project_names() {
+        let html = r#"
+            <a class="package-snippet" href="/project/acme/">acme</a>
+            <a class="package-snippet" href="/project/acme-cli/">acme-cli</a>
+            <a class="package-snippet" href="/project/acme/">acme</a>
+        "#;
+
+        assert_eq!(
+            PackageRegistryScraper::pypi_project_names(html),
+            vec!["acme".to_string(), "acme-cli".to_string()]
+        );
+    }
+}
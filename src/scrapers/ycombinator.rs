@@ -1,9 +1,9 @@
-use super::{base::BaseScraper, SourceScraper};
+use super::{base::BaseScraper, capped_estimate, lead_cap_reached, RequestPlan, SourceScraper};
 use crate::{
     config::YCombinatorConfig,
-    errors::{Result, ScrapingError},
+    errors::Result,
     extractors::DataExtractor,
-    models::{Lead, ScrapedData, Source},
+    models::{Lead, Person, ScrapedData, Source},
 };
 use async_trait::async_trait;
 use reqwest::Client;
@@ -70,6 +70,38 @@ impl YCombinatorScraper {
 
         Ok(scraped_data)
     }
+
+    /// Link to the company's own YC page (`/companies/<slug>`) in a listing row
+    fn company_page_url(&self, html: &str) -> Option<String> {
+        let fragment = Html::parse_fragment(html);
+        let selector = Selector::parse("a[href]").ok()?;
+
+        fragment.select(&selector).find_map(|link| {
+            let href = link.value().attr("href")?;
+            let path = href
+                .strip_prefix(self.config.base_url.trim_end_matches('/'))
+                .unwrap_or(href);
+            let slug = path.strip_prefix("/companies/")?;
+            (!slug.is_empty() && !slug.contains(['?', '/'])).then(|| {
+                format!(
+                    "{}/companies/{}",
+                    self.config.base_url.trim_end_matches('/'),
+                    slug
+                )
+            })
+        })
+    }
+
+    /// Founders listed on a company's YC page
+    async fn fetch_founders(&self, company_url: &str) -> Vec<Person> {
+        match self.base.fetch_html(company_url).await {
+            Ok(html) => self.base.extractor.extract_people(&html, "Y Combinator"),
+            Err(e) => {
+                warn!("Failed to fetch YC company page {}: {}", company_url, e);
+                Vec::new()
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -79,6 +111,9 @@ impl SourceScraper for YCombinatorScraper {
         let mut leads = Vec::new();
 
         for endpoint in &self.config.endpoints {
+            if lead_cap_reached(self.config.max_leads, leads.len()) {
+                break;
+            }
             let url = format!("{}{}", self.config.base_url, endpoint);
             debug!("Fetching Y Combinator endpoint: {}", url);
 
@@ -86,10 +121,26 @@ impl SourceScraper for YCombinatorScraper {
                 Ok(html) => {
                     let scraped_data = self.parse_ycombinator_page(&html)?;
                     for data in scraped_data {
-                        let lead = self
+                        if lead_cap_reached(self.config.max_leads, leads.len()) {
+                            break;
+                        }
+                        let company_page = self
+                            .config
+                            .fetch_founders
+                            .then(|| self.company_page_url(&data.html))
+                            .flatten();
+
+                        let mut lead = self
                             .base
                             .create_lead_from_scraped_data(data, Source::YCombinator)
                             .await;
+
+                        if let Some(company_page) = company_page {
+                            for founder in self.fetch_founders(&company_page).await {
+                                lead.add_person(founder);
+                            }
+                            self.base.rate_limit(250).await;
+                        }
                         leads.push(lead);
                     }
                     debug!(
@@ -122,7 +173,21 @@ impl SourceScraper for YCombinatorScraper {
 
     fn expected_leads_count(&self) -> Option<usize> {
         // Rough estimate: 50-200 companies per batch
-        Some(self.config.endpoints.len() * 100)
+        Some(capped_estimate(
+            self.config.endpoints.len() * 100,
+            self.config.max_leads,
+        ))
+    }
+
+    fn request_plan(&self) -> RequestPlan {
+        let urls = self
+            .config
+            .endpoints
+            .iter()
+            .map(|endpoint| format!("{}{}", self.config.base_url, endpoint))
+            .collect();
+        let requests_per_lead = usize::from(self.config.fetch_founders);
+        RequestPlan::new(urls, requests_per_lead)
     }
 }
 
@@ -138,6 +203,8 @@ mod tests {
             base_url: "https://www.ycombinator.com".to_string(),
             endpoints: vec!["/companies?batch=W24".to_string()],
             selectors: HashMap::new(),
+            fetch_founders: false,
+            max_leads: None,
         };
         let client = reqwest::Client::new();
         let extractor = DataExtractor::new(&PatternsConfig::default(), client.clone(), None)
@@ -177,4 +244,19 @@ mod tests {
         assert!(!scraped_data.is_empty());
         assert_eq!(scraped_data[0].name, "Test Company");
     }
+
+    #[test]
+    fn test_company_page_url() {
+        let scraper = create_test_scraper();
+
+        let row = r#"<a href="/companies/acme">Acme</a> <a href="https://acme.dev">Site</a>"#;
+        assert_eq!(
+            scraper.company_page_url(row).as_deref(),
+            Some("https://www.ycombinator.com/companies/acme")
+        );
+        assert_eq!(
+            scraper.company_page_url(r#"<a href="/companies?batch=W24">W24</a>"#),
+            None
+        );
+    }
 }
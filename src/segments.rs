@@ -0,0 +1,131 @@
+use crate::config::SegmentConfig;
+use crate::errors::{Result, ScrapingError};
+use crate::models::Lead;
+use std::collections::HashMap;
+
+impl SegmentConfig {
+    /// Whether a (scored) lead belongs to the segment
+    pub fn matches(&self, lead: &Lead) -> bool {
+        self.when.matches(lead) && self.min_score.is_none_or(|min| lead.score_total() >= min)
+    }
+
+    /// Segment name usable as a file name (`DACH DevTools` -> `dach-devtools`)
+    pub fn file_stem(&self) -> String {
+        let stem: String = self
+            .name
+            .to_lowercase()
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        stem.split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+/// Reject segments whose names give an empty file stem or the same stem as
+/// another segment's, which would overwrite its outputs
+pub fn check_segment_names(segments: &[SegmentConfig]) -> Result<()> {
+    let mut stems: HashMap<String, &str> = HashMap::new();
+    for segment in segments {
+        let stem = segment.file_stem();
+        if stem.is_empty() {
+            return Err(ScrapingError::ConfigError(format!(
+                "Segment name '{}' has no letters or digits to name its files",
+                segment.name
+            )));
+        }
+        if let Some(other) = stems.insert(stem.clone(), &segment.name) {
+            return Err(ScrapingError::ConfigError(format!(
+                "Segments '{}' and '{}' would both write segments/{}.json",
+                other, segment.name, stem
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Leads of each segment, in configuration order. Segments may overlap.
+pub fn segment_leads<'a>(
+    segments: &'a [SegmentConfig],
+    leads: &'a [Lead],
+) -> Vec<(&'a SegmentConfig, Vec<&'a Lead>)> {
+    segments
+        .iter()
+        .map(|segment| {
+            let members = leads.iter().filter(|lead| segment.matches(lead)).collect();
+            (segment, members)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::models::{IndustryTag, LeadScore, Source};
+
+    #[test]
+    fn test_segments_from_config() {
+        let config: Config = serde_yaml::from_str(include_str!("../config/scraper.yaml"))
+            .expect("shipped config is valid");
+        let dach = config
+            .segments
+            .iter()
+            .find(|segment| segment.name == "dach-devtools")
+            .expect("shipped DACH segment");
+
+        let mut lead = Lead::new("Acme".to_string(), Source::BetaList)
+            .with_email(Some("jane@acme.de".to_string()));
+        lead.location = Some(crate::models::Location {
+            country_code: Some("DE".to_string()),
+            country: Some("Germany".to_string()),
+            ..Default::default()
+        });
+        lead.industries = vec![IndustryTag {
+            name: "devtools".to_string(),
+            label: "Developer Tools".to_string(),
+            score: 4.0,
+            matched: vec!["sdk".to_string()],
+        }];
+        assert!(!dach.matches(&lead), "unscored leads miss min_score");
+        lead.score = Some(LeadScore {
+            total: 5.0,
+            reasons: Vec::new(),
+        });
+        assert!(dach.matches(&lead));
+
+        let mut french = lead.clone();
+        french.location.as_mut().unwrap().country_code = Some("FR".to_string());
+        let leads = vec![lead, french];
+        let segmented = segment_leads(&config.segments, &leads);
+        let (_, members) = segmented
+            .iter()
+            .find(|(segment, _)| segment.name == "dach-devtools")
+            .unwrap();
+        assert_eq!(members.len(), 1);
+
+        let segment = SegmentConfig {
+            name: "DACH / DevTools (Q3)".to_string(),
+            description: None,
+            when: Default::default(),
+            min_score: None,
+        };
+        assert_eq!(segment.file_stem(), "dach-devtools-q3");
+
+        assert!(check_segment_names(&config.segments).is_ok());
+        let named = |name: &str| SegmentConfig {
+            name: name.to_string(),
+            ..segment.clone()
+        };
+        assert!(check_segment_names(&[named("DACH DevTools"), named("dach-devtools")]).is_err());
+        assert!(check_segment_names(&[named("🚀 / ✨")]).is_err());
+    }
+}
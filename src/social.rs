@@ -0,0 +1,388 @@
+use crate::models::SocialNetwork;
+use url::Url;
+
+/// Social profile a link points at
+#[derive(Debug, Clone, PartialEq)]
+pub struct SocialLink {
+    pub network: SocialNetwork,
+    /// Account name, page slug or invite code
+    pub handle: String,
+    /// Canonical profile URL
+    pub url: String,
+}
+
+/// Accounts of platforms and site builders that show up in page chrome
+/// ("Follow us", "Made with ...") rather than belonging to the lead
+const PLATFORM_ACCOUNTS: &[&str] = &[
+    "twitter",
+    "x",
+    "github",
+    "linkedin",
+    "facebook",
+    "instagram",
+    "youtube",
+    "discord",
+    "ycombinator",
+    "producthunt",
+    "betalist",
+    "crunchbase",
+    "medium",
+    "webflow",
+    "wix",
+    "squarespace",
+    "shopify",
+    "wordpress",
+    "vercel",
+    "netlify",
+];
+
+/// Non-profile first path segments on X/Twitter
+const TWITTER_RESERVED: &[&str] = &[
+    "intent",
+    "share",
+    "search",
+    "hashtag",
+    "home",
+    "i",
+    "login",
+    "signup",
+    "explore",
+    "settings",
+    "tos",
+    "privacy",
+    "messages",
+    "notifications",
+];
+
+/// Non-profile first path segments on GitHub
+const GITHUB_RESERVED: &[&str] = &[
+    "about",
+    "apps",
+    "collections",
+    "contact",
+    "customer-stories",
+    "enterprise",
+    "explore",
+    "features",
+    "login",
+    "marketplace",
+    "orgs",
+    "pricing",
+    "security",
+    "settings",
+    "signup",
+    "site",
+    "sponsors",
+    "topics",
+    "trending",
+];
+
+/// Mastodon instances recognized without a `rel="me"` hint
+const MASTODON_HOSTS: &[&str] = &[
+    "mastodon.social",
+    "mastodon.online",
+    "fosstodon.org",
+    "hachyderm.io",
+    "infosec.exchange",
+    "mas.to",
+    "mstdn.social",
+    "techhub.social",
+    "indieweb.social",
+];
+
+/// Classify a link target as a social profile. Share/intent links, posts,
+/// videos and platform accounts are rejected. `rel_me` marks `rel="me"`
+/// anchors, which identify Mastodon profiles on any instance.
+pub fn classify_social_url(href: &str, rel_me: bool) -> Option<SocialLink> {
+    let url = Url::parse(href.trim()).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    let host = url.host_str()?.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let host = host.strip_prefix("m.").unwrap_or(host);
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|segments| segments.filter(|s| !s.is_empty()).collect())
+        .unwrap_or_default();
+    let first = segments.first().copied().unwrap_or("");
+
+    let (network, handle, canonical) = match host {
+        "twitter.com" | "x.com" => {
+            if segments.len() != 1 || TWITTER_RESERVED.contains(&first.to_lowercase().as_str()) {
+                return None;
+            }
+            let handle = first.trim_start_matches('@');
+            if !is_handle(handle, "_") {
+                return None;
+            }
+            (
+                SocialNetwork::Twitter,
+                handle.to_string(),
+                format!("https://twitter.com/{}", handle),
+            )
+        }
+        "linkedin.com" => match (first, segments.get(1)) {
+            ("company", Some(slug)) => (
+                SocialNetwork::LinkedInCompany,
+                slug.to_string(),
+                format!("https://www.linkedin.com/company/{}", slug),
+            ),
+            ("in", Some(slug)) => (
+                SocialNetwork::LinkedInPerson,
+                slug.to_string(),
+                format!("https://www.linkedin.com/in/{}", slug),
+            ),
+            _ => return None,
+        },
+        "github.com" => {
+            // Repository links belong to projects, not profiles
+            if segments.len() != 1 || GITHUB_RESERVED.contains(&first.to_lowercase().as_str()) {
+                return None;
+            }
+            if !is_handle(first, "-") {
+                return None;
+            }
+            (
+                SocialNetwork::GitHub,
+                first.to_string(),
+                format!("https://github.com/{}", first),
+            )
+        }
+        "bsky.app" => match (first, segments.get(1)) {
+            ("profile", Some(handle)) if segments.len() == 2 => (
+                SocialNetwork::Bluesky,
+                handle.to_string(),
+                format!("https://bsky.app/profile/{}", handle),
+            ),
+            _ => return None,
+        },
+        "youtube.com" => {
+            let handle = match (first, segments.get(1)) {
+                (handle, None) if handle.starts_with('@') => handle.to_string(),
+                ("channel" | "c" | "user", Some(name)) if segments.len() == 2 => {
+                    format!("{}/{}", first, name)
+                }
+                _ => return None,
+            };
+            (
+                SocialNetwork::YouTube,
+                handle.trim_start_matches('@').to_string(),
+                format!("https://www.youtube.com/{}", handle),
+            )
+        }
+        "discord.gg" if segments.len() == 1 => (
+            SocialNetwork::Discord,
+            first.to_string(),
+            format!("https://discord.gg/{}", first),
+        ),
+        "discord.com" | "discordapp.com" => match (first, segments.get(1)) {
+            ("invite", Some(code)) => (
+                SocialNetwork::Discord,
+                code.to_string(),
+                format!("https://discord.gg/{}", code),
+            ),
+            _ => return None,
+        },
+        "facebook.com" | "fb.com" => {
+            let reserved = [
+                "sharer",
+                "sharer.php",
+                "share",
+                "share.php",
+                "dialog",
+                "plugins",
+                "tr",
+                "login",
+                "events",
+                "groups",
+                "watch",
+            ];
+            if segments.len() != 1 || reserved.contains(&first.to_lowercase().as_str()) {
+                return None;
+            }
+            if !is_handle(first, ".-") {
+                return None;
+            }
+            (
+                SocialNetwork::Facebook,
+                first.to_string(),
+                format!("https://www.facebook.com/{}", first),
+            )
+        }
+        "instagram.com" => {
+            let reserved = ["p", "reel", "reels", "explore", "stories", "accounts"];
+            if segments.len() != 1 || reserved.contains(&first) || !is_handle(first, "._") {
+                return None;
+            }
+            (
+                SocialNetwork::Instagram,
+                first.to_string(),
+                format!("https://www.instagram.com/{}", first),
+            )
+        }
+        "crunchbase.com" => match (first, segments.get(1)) {
+            ("organization", Some(slug)) => (
+                SocialNetwork::Crunchbase,
+                slug.to_string(),
+                format!("https://www.crunchbase.com/organization/{}", slug),
+            ),
+            _ => return None,
+        },
+        _ => {
+            // Mastodon: https://<instance>/@user
+            let known_instance = MASTODON_HOSTS.contains(&host)
+                || host.contains("mastodon")
+                || host.starts_with("mstdn.");
+            let user = first.strip_prefix('@')?;
+            if segments.len() != 1 || !(known_instance || rel_me) || !is_handle(user, "_.") {
+                return None;
+            }
+            (
+                SocialNetwork::Mastodon,
+                format!("{}@{}", user, host),
+                format!("https://{}/@{}", host, user),
+            )
+        }
+    };
+
+    if PLATFORM_ACCOUNTS.contains(&handle.to_lowercase().as_str()) {
+        return None;
+    }
+
+    Some(SocialLink {
+        network,
+        handle,
+        url: canonical,
+    })
+}
+
+/// Alphanumeric handle, allowing the given punctuation
+fn is_handle(handle: &str, extra: &str) -> bool {
+    !handle.is_empty()
+        && handle
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || extra.contains(c))
+}
+
+fn normalize_brand(text: &str) -> String {
+    text.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_lowercase()
+}
+
+/// Brands long enough to tell the lead's accounts from others
+fn usable_brands(brands: &[String]) -> impl Iterator<Item = String> + '_ {
+    brands
+        .iter()
+        .map(|brand| normalize_brand(brand))
+        .filter(|brand| brand.len() >= 3)
+}
+
+/// Whether `brand_affinity` has anything to compare handles with
+pub fn has_brand_hints(brands: &[String]) -> bool {
+    usable_brands(brands).next().is_some()
+}
+
+/// How well a handle matches the lead's name or domain (higher is better)
+pub fn brand_affinity(handle: &str, brands: &[String]) -> usize {
+    let handle = normalize_brand(handle.split('@').next().unwrap_or(handle));
+    if handle.is_empty() {
+        return 0;
+    }
+
+    usable_brands(brands)
+        .map(|brand| {
+            if handle == brand {
+                3
+            } else if handle.contains(&brand) || brand.contains(&handle) {
+                2
+            } else {
+                0
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_social_urls() {
+        let classify = |url: &str| classify_social_url(url, false).map(|l| (l.network, l.url));
+
+        assert_eq!(
+            classify("https://x.com/acmehq?ref=site"),
+            Some((
+                SocialNetwork::Twitter,
+                "https://twitter.com/acmehq".to_string()
+            ))
+        );
+        assert_eq!(
+            classify("https://www.linkedin.com/company/acme-inc/about/"),
+            Some((
+                SocialNetwork::LinkedInCompany,
+                "https://www.linkedin.com/company/acme-inc".to_string()
+            ))
+        );
+        assert_eq!(
+            classify("https://linkedin.com/in/janedoe").map(|(n, _)| n),
+            Some(SocialNetwork::LinkedInPerson)
+        );
+        assert_eq!(
+            classify("https://fosstodon.org/@acme"),
+            Some((
+                SocialNetwork::Mastodon,
+                "https://fosstodon.org/@acme".to_string()
+            ))
+        );
+        assert_eq!(
+            classify("https://bsky.app/profile/acme.dev").map(|(n, _)| n),
+            Some(SocialNetwork::Bluesky)
+        );
+        assert_eq!(
+            classify("https://discord.com/invite/abc123"),
+            Some((
+                SocialNetwork::Discord,
+                "https://discord.gg/abc123".to_string()
+            ))
+        );
+        assert_eq!(
+            classify("https://www.crunchbase.com/organization/acme").map(|(n, _)| n),
+            Some(SocialNetwork::Crunchbase)
+        );
+        assert_eq!(
+            classify("https://www.youtube.com/@acme").map(|(n, _)| n),
+            Some(SocialNetwork::YouTube)
+        );
+
+        // Share/intent links, posts, repositories and platform accounts
+        assert_eq!(classify("https://twitter.com/intent/tweet?text=hi"), None);
+        assert_eq!(classify("https://twitter.com/share"), None);
+        assert_eq!(
+            classify("https://www.facebook.com/sharer/sharer.php?u=x"),
+            None
+        );
+        assert_eq!(
+            classify("https://www.linkedin.com/shareArticle?url=x"),
+            None
+        );
+        assert_eq!(classify("https://www.instagram.com/p/Cxyz/"), None);
+        assert_eq!(classify("https://www.youtube.com/watch?v=abc"), None);
+        assert_eq!(classify("https://github.com/acme/tool"), None);
+        assert_eq!(classify("https://twitter.com/ycombinator"), None);
+        assert_eq!(classify("https://medium.com/@someone"), None);
+        assert!(classify_social_url("https://social.example/@jane", true).is_some());
+    }
+
+    #[test]
+    fn test_brand_affinity() {
+        let brands = vec!["Acme Cloud".to_string(), "acme.dev".to_string()];
+        assert_eq!(brand_affinity("acmecloud", &brands), 3);
+        assert_eq!(brand_affinity("acmeclouddev", &brands), 2);
+        assert_eq!(brand_affinity("somebodyelse", &brands), 0);
+    }
+}
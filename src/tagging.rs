@@ -0,0 +1,211 @@
+use crate::errors::{Result, ScrapingError};
+use crate::models::{IndustryTag, Lead};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+
+/// Taxonomy as written in `config/taxonomy.yaml`
+#[derive(Debug, Clone, Deserialize)]
+struct Taxonomy {
+    #[serde(default = "default_min_score")]
+    min_score: f32,
+    #[serde(default)]
+    field_weights: FieldWeights,
+    tags: Vec<TagDefinition>,
+}
+
+fn default_min_score() -> f32 {
+    3.0
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FieldWeights {
+    description: f32,
+    categories: f32,
+    website: f32,
+}
+
+impl Default for FieldWeights {
+    fn default() -> Self {
+        Self {
+            description: 1.0,
+            categories: 2.0,
+            website: 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TagDefinition {
+    name: String,
+    label: Option<String>,
+    #[serde(default)]
+    keywords: BTreeMap<String, f32>,
+    #[serde(default)]
+    phrases: BTreeMap<String, f32>,
+    #[serde(default)]
+    negative: Vec<String>,
+    min_score: Option<f32>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledTag {
+    name: String,
+    label: String,
+    /// Keywords and phrases with their weights
+    terms: Vec<(String, Regex, f32)>,
+    negative: Vec<Regex>,
+    min_score: f32,
+}
+
+/// Assigns industry tags to leads from a keyword taxonomy
+#[derive(Debug, Clone)]
+pub struct Tagger {
+    tags: Vec<CompiledTag>,
+    field_weights: FieldWeights,
+}
+
+impl Tagger {
+    /// Load a taxonomy file
+    pub fn load(path: &str) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ScrapingError::ConfigError(format!("Failed to read taxonomy '{}': {}", path, e))
+        })?;
+        Self::from_yaml(&content)
+            .map_err(|e| ScrapingError::ConfigError(format!("Invalid taxonomy '{}': {}", path, e)))
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let taxonomy: Taxonomy = serde_yaml::from_str(content)?;
+
+        let mut tags = Vec::new();
+        for tag in taxonomy.tags {
+            let mut terms = Vec::new();
+            for (term, weight) in tag.keywords.iter().chain(&tag.phrases) {
+                terms.push((term.clone(), term_regex(term)?, *weight));
+            }
+            let negative = tag
+                .negative
+                .iter()
+                .map(|term| term_regex(term))
+                .collect::<Result<_>>()?;
+
+            tags.push(CompiledTag {
+                label: tag.label.unwrap_or_else(|| tag.name.clone()),
+                name: tag.name,
+                terms,
+                negative,
+                min_score: tag.min_score.unwrap_or(taxonomy.min_score),
+            });
+        }
+
+        Ok(Self {
+            tags,
+            field_weights: taxonomy.field_weights,
+        })
+    }
+
+    /// Industry tags for a lead, highest score first
+    pub fn tag(&self, lead: &Lead) -> Vec<IndustryTag> {
+        let website = lead
+            .website_meta
+            .as_ref()
+            .map(|meta| {
+                [meta.title.as_deref(), meta.description.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .chain(meta.keywords.iter().map(String::as_str))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .unwrap_or_default();
+        let fields = [
+            (
+                lead.description.as_deref().unwrap_or(""),
+                self.field_weights.description,
+            ),
+            (&lead.categories.join(" "), self.field_weights.categories),
+            (&website, self.field_weights.website),
+        ];
+
+        let mut tags: Vec<IndustryTag> = self
+            .tags
+            .iter()
+            .filter(|tag| {
+                !tag.negative
+                    .iter()
+                    .any(|pattern| fields.iter().any(|(text, _)| pattern.is_match(text)))
+            })
+            .filter_map(|tag| {
+                let mut score = 0.0;
+                let mut matched = Vec::new();
+                for (term, pattern, weight) in &tag.terms {
+                    for (text, field_weight) in &fields {
+                        if pattern.is_match(text) {
+                            score += weight * field_weight;
+                            if !matched.contains(term) {
+                                matched.push(term.clone());
+                            }
+                        }
+                    }
+                }
+                (score >= tag.min_score && score > 0.0).then(|| IndustryTag {
+                    name: tag.name.clone(),
+                    label: tag.label.clone(),
+                    score,
+                    matched,
+                })
+            })
+            .collect();
+
+        tags.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        tags
+    }
+}
+
+/// Whole-word, case-insensitive pattern; words of a phrase may be separated
+/// by any whitespace
+fn term_regex(term: &str) -> Result<Regex> {
+    let words: Vec<String> = term.split_whitespace().map(regex::escape).collect();
+    Regex::new(&format!(r"(?i)(?:^|\W){}(?:\W|$)", words.join(r"\s+")))
+        .map_err(|e| ScrapingError::RegexError(format!("Invalid taxonomy term '{}': {}", term, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Source;
+
+    #[test]
+    fn test_tag_with_shipped_taxonomy() {
+        let tagger = Tagger::from_yaml(include_str!("../config/taxonomy.yaml"))
+            .expect("shipped taxonomy is valid");
+
+        let lead = Lead::new("Acme".to_string(), Source::BetaList)
+            .with_description(Some(
+                "Payment APIs and an SDK for invoicing, built for developers".to_string(),
+            ))
+            .with_categories(vec!["Developer Tools".to_string(), "CLI".to_string()]);
+        let tags = tagger.tag(&lead);
+        let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+        assert_eq!(names, vec!["devtools", "fintech"]);
+        assert!(tags[0].matched.contains(&"cli".to_string()));
+        assert_eq!(tags[1].label, "Fintech");
+
+        // "learning" alone is not enough, and machine learning vetoes edtech
+        let lead = Lead::new("Tutor".to_string(), Source::BetaList)
+            .with_description(Some("Machine learning tutoring for students".to_string()));
+        let names: Vec<String> = tagger.tag(&lead).into_iter().map(|tag| tag.name).collect();
+        assert_eq!(names, vec!["ai-ml"]);
+
+        // Keywords match whole words only ("capital" is not "api")
+        let lead = Lead::new("Fund".to_string(), Source::BetaList)
+            .with_description(Some("Venture capital for founders".to_string()));
+        assert!(tagger.tag(&lead).is_empty());
+    }
+}
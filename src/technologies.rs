@@ -0,0 +1,290 @@
+use crate::errors::{Result, ScrapingError};
+use crate::models::Technology;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+
+/// Fingerprint rule as written in `config/fingerprints.yaml`
+#[derive(Debug, Clone, Deserialize)]
+struct TechnologyRule {
+    name: String,
+    #[serde(default)]
+    categories: Vec<String>,
+    #[serde(default)]
+    html: Vec<String>,
+    #[serde(default)]
+    scripts: Vec<String>,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    #[serde(default)]
+    cookies: Vec<String>,
+    #[serde(default)]
+    meta: HashMap<String, String>,
+    #[serde(default)]
+    implies: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FingerprintFile {
+    technologies: Vec<TechnologyRule>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    name: String,
+    categories: Vec<String>,
+    html: Vec<Regex>,
+    scripts: Vec<Regex>,
+    /// Lowercase header name, value pattern (`None` = header present)
+    headers: Vec<(String, Option<Regex>)>,
+    cookies: Vec<Regex>,
+    meta: Vec<(String, Regex)>,
+    implies: Vec<String>,
+}
+
+impl CompiledRule {
+    fn matches(&self, page: &PageSnapshot, scripts: &[&str], meta: &[(String, &str)]) -> bool {
+        self.html.iter().any(|pattern| pattern.is_match(&page.html))
+            || self
+                .scripts
+                .iter()
+                .any(|pattern| scripts.iter().any(|src| pattern.is_match(src)))
+            || self
+                .cookies
+                .iter()
+                .any(|pattern| page.cookies.iter().any(|cookie| pattern.is_match(cookie)))
+            || self.headers.iter().any(|(name, pattern)| {
+                page.headers.iter().any(|(header, value)| {
+                    header == name && pattern.as_ref().is_none_or(|p| p.is_match(value))
+                })
+            })
+            || self.meta.iter().any(|(name, pattern)| {
+                meta.iter()
+                    .any(|(tag, content)| tag == name && pattern.is_match(content))
+            })
+    }
+}
+
+/// What a website returned: the page plus the response metadata the
+/// fingerprints look at
+#[derive(Debug, Clone, Default)]
+pub struct PageSnapshot {
+    pub html: String,
+    /// Response headers, names lowercased
+    pub headers: Vec<(String, String)>,
+    /// Names of cookies set by the response
+    pub cookies: Vec<String>,
+}
+
+impl PageSnapshot {
+    /// Snapshot of a response; the body is read into `html`
+    pub async fn from_response(response: reqwest::Response) -> Result<Self> {
+        let mut headers = Vec::new();
+        let mut cookies = Vec::new();
+        for (name, value) in response.headers() {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+            if name == reqwest::header::SET_COOKIE {
+                if let Some((cookie, _)) = value.split_once('=') {
+                    cookies.push(cookie.trim().to_string());
+                }
+            }
+            headers.push((name.as_str().to_lowercase(), value.to_string()));
+        }
+
+        let html = response.text().await.map_err(|e| {
+            ScrapingError::NetworkError(format!("Failed to read response body: {}", e))
+        })?;
+
+        Ok(Self {
+            html,
+            headers,
+            cookies,
+        })
+    }
+}
+
+/// Matches fetched websites against technology fingerprints
+#[derive(Debug, Clone)]
+pub struct TechnologyDetector {
+    rules: Vec<CompiledRule>,
+}
+
+impl TechnologyDetector {
+    /// Load fingerprints from a YAML rule file
+    pub fn load(path: &str) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            ScrapingError::ConfigError(format!("Failed to read fingerprint file '{}': {}", path, e))
+        })?;
+        Self::from_yaml(&content).map_err(|e| {
+            ScrapingError::ConfigError(format!("Invalid fingerprint file '{}': {}", path, e))
+        })
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let file: FingerprintFile = serde_yaml::from_str(content)?;
+        let compile = |pattern: &str| {
+            Regex::new(pattern).map_err(|e| {
+                ScrapingError::RegexError(format!("Invalid fingerprint '{}': {}", pattern, e))
+            })
+        };
+
+        let mut rules = Vec::new();
+        for rule in file.technologies {
+            let mut headers = Vec::new();
+            for (name, pattern) in &rule.headers {
+                let pattern = match pattern.as_str() {
+                    "" => None,
+                    pattern => Some(compile(pattern)?),
+                };
+                headers.push((name.to_lowercase(), pattern));
+            }
+            let mut meta = Vec::new();
+            for (name, pattern) in &rule.meta {
+                meta.push((name.to_lowercase(), compile(pattern)?));
+            }
+
+            rules.push(CompiledRule {
+                html: rule
+                    .html
+                    .iter()
+                    .map(|p| compile(p))
+                    .collect::<Result<_>>()?,
+                scripts: rule
+                    .scripts
+                    .iter()
+                    .map(|p| compile(p))
+                    .collect::<Result<_>>()?,
+                cookies: rule
+                    .cookies
+                    .iter()
+                    .map(|p| compile(p))
+                    .collect::<Result<_>>()?,
+                headers,
+                meta,
+                name: rule.name,
+                categories: rule.categories,
+                implies: rule.implies,
+            });
+        }
+
+        Ok(Self { rules })
+    }
+
+    /// Technologies on a page, in rule-file order, implied ones included
+    pub fn detect(&self, page: &PageSnapshot) -> Vec<Technology> {
+        use scraper::{Html, Selector};
+
+        let document = Html::parse_document(&page.html);
+        let scripts: Vec<&str> = Selector::parse("script[src]")
+            .map(|selector| {
+                document
+                    .select(&selector)
+                    .filter_map(|script| script.value().attr("src"))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let meta: Vec<(String, &str)> = Selector::parse("meta[name][content]")
+            .map(|selector| {
+                document
+                    .select(&selector)
+                    .filter_map(|tag| {
+                        let name = tag.value().attr("name")?.to_lowercase();
+                        Some((name, tag.value().attr("content")?))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut detected: Vec<&str> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.matches(page, &scripts, &meta))
+            .map(|rule| rule.name.as_str())
+            .collect();
+
+        // Implied technologies, transitively (Gatsby -> React)
+        let mut index = 0;
+        while index < detected.len() {
+            let implied = self
+                .rule(detected[index])
+                .map(|rule| rule.implies.as_slice())
+                .unwrap_or_default();
+            for name in implied {
+                if !detected.contains(&name.as_str()) {
+                    detected.push(name);
+                }
+            }
+            index += 1;
+        }
+
+        detected
+            .into_iter()
+            .map(|name| Technology {
+                name: name.to_string(),
+                categories: self
+                    .rule(name)
+                    .map(|rule| rule.categories.clone())
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+
+    fn rule(&self, name: &str) -> Option<&CompiledRule> {
+        self.rules.iter().find(|rule| rule.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_with_shipped_fingerprints() {
+        let detector = TechnologyDetector::from_yaml(include_str!("../config/fingerprints.yaml"))
+            .expect("shipped fingerprints are valid");
+
+        let page = PageSnapshot {
+            html: r#"<html><head>
+                <script src="https://js.stripe.com/v3/"></script>
+                <script src="https://widget.intercom.io/widget/abc123"></script>
+                </head><body><div id="__next"></div>
+                <script id="__NEXT_DATA__" type="application/json">{}</script>
+                </body></html>"#
+                .to_string(),
+            headers: vec![
+                ("server".to_string(), "cloudflare".to_string()),
+                ("x-powered-by".to_string(), "Next.js".to_string()),
+            ],
+            cookies: vec!["_hjSessionUser_123".to_string()],
+        };
+
+        let names: Vec<String> = detector
+            .detect(&page)
+            .into_iter()
+            .map(|technology| technology.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "Stripe",
+                "Next.js",
+                "Intercom",
+                "Hotjar",
+                "Cloudflare",
+                "React"
+            ]
+        );
+
+        let wordpress = PageSnapshot {
+            html: r#"<meta name="generator" content="WordPress 6.4">"#.to_string(),
+            ..PageSnapshot::default()
+        };
+        let detected = detector.detect(&wordpress);
+        assert_eq!(detected[0].name, "WordPress");
+        assert_eq!(detected[0].categories, vec!["CMS"]);
+        assert_eq!(detected[1].name, "PHP");
+    }
+}
@@ -0,0 +1,130 @@
+use crate::config::TextNormalization;
+use unicode_normalization::UnicodeNormalization;
+
+/// Normalize scraped text without losing non-Latin scripts:
+///
+/// 1. decode HTML entities (`&amp;`, `&eacute;`, `&#x4E2D;`)
+/// 2. NFKC (full-width letters, ligatures and compatibility forms fold to
+///    their plain equivalents; accents stay composed)
+/// 3. drop control and format characters, and emoji when configured
+/// 4. collapse whitespace runs into single spaces
+pub fn normalize_text(text: &str, options: &TextNormalization) -> String {
+    let decoded = if options.decode_entities {
+        html_escape::decode_html_entities(text).into_owned()
+    } else {
+        text.to_string()
+    };
+
+    let chars: Vec<char> = decoded.nfkc().collect();
+    let filtered: String = chars
+        .iter()
+        .enumerate()
+        .filter(|(i, c)| {
+            if c.is_whitespace() {
+                return true;
+            }
+            if options.strip_control && is_control_or_format(**c) {
+                return false;
+            }
+            !(options.strip_emoji && (is_emoji(**c) || joins_emoji(&chars, *i)))
+        })
+        .map(|(_, c)| c)
+        .collect();
+
+    if options.collapse_whitespace {
+        filtered.split_whitespace().collect::<Vec<_>>().join(" ")
+    } else {
+        filtered
+    }
+}
+
+/// C0/C1 controls and invisible format characters (zero-width spaces, BOM,
+/// bidi marks); ZWJ/ZWNJ are kept because Indic and Persian text need them
+fn is_control_or_format(c: char) -> bool {
+    c.is_control()
+        || matches!(
+            c,
+            '\u{00AD}'
+                | '\u{200B}'
+                | '\u{200E}'
+                | '\u{200F}'
+                | '\u{202A}'..='\u{202E}'
+                | '\u{2060}'..='\u{2064}'
+                | '\u{FEFF}'
+        )
+}
+
+/// Emoji and pictographs, including the modifiers that build emoji sequences
+fn is_emoji(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1F000}'..='\u{1FAFF}'   // pictographs, emoticons, transport, flags
+            | '\u{2600}'..='\u{27BF}' // misc symbols, dingbats
+            | '\u{2B00}'..='\u{2BFF}' // arrows and stars (⭐, ⬆)
+            | '\u{FE0E}'..='\u{FE0F}' // variation selectors
+            | '\u{E0020}'..='\u{E007F}' // tag sequences (subdivision flags)
+            | '\u{20E3}' // keycap
+    )
+}
+
+/// A zero-width joiner between two pictographs (`👩‍💻`); ZWJs inside words
+/// belong to the script and stay
+fn joins_emoji(chars: &[char], i: usize) -> bool {
+    chars[i] == '\u{200D}'
+        && i > 0
+        && chars.get(i + 1).is_some_and(|next| is_emoji(*next))
+        && is_emoji(chars[i - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clean(text: &str) -> String {
+        normalize_text(text, &TextNormalization::default())
+    }
+
+    #[test]
+    fn test_keeps_non_latin_scripts() {
+        assert_eq!(clean("Based in  Zürich\n"), "Based in Zürich");
+        assert_eq!(clean("Société Générale"), "Société Générale");
+        assert_eq!(
+            clean("東京を拠点とする\nスタートアップ"),
+            "東京を拠点とする スタートアップ"
+        );
+        assert_eq!(clean("서울에 본사를 둔 핀테크"), "서울에 본사를 둔 핀테크");
+        assert_eq!(clean("Компания из Москвы"), "Компания из Москвы");
+        assert_eq!(clean("شركة ناشئة في دبي"), "شركة ناشئة في دبي");
+        assert_eq!(clean("मुंबई स्थित"), "मुंबई स्थित");
+        // Joiners shaping Devanagari half forms and Persian letters survive
+        // emoji stripping
+        assert_eq!(clean("क्\u{200D}ष"), "क्\u{200D}ष");
+        assert_eq!(clean("ه\u{200D}ا"), "ه\u{200D}ا");
+    }
+
+    #[test]
+    fn test_normalization_pipeline() {
+        // Decomposed "é" composes, full-width and ligatures fold
+        assert_eq!(clean("Cafe\u{301}"), "Café");
+        assert_eq!(clean("ＡＣＭＥ　Ｉｎｃ"), "ACME Inc");
+        assert_eq!(clean("\u{FB01}nance"), "finance");
+
+        // Entities, control and zero-width characters
+        assert_eq!(
+            clean("R&amp;D in M&uuml;nchen &#x2014; ok"),
+            "R&D in München — ok"
+        );
+        assert_eq!(
+            clean("zero\u{200B}width\u{0007} bell\u{FEFF}"),
+            "zerowidth bell"
+        );
+
+        // Emoji are stripped by default, kept on request
+        assert_eq!(clean("🚀 Launch fast ✨ 👩‍💻 🇩🇪"), "Launch fast");
+        let keep_emoji = TextNormalization {
+            strip_emoji: false,
+            ..TextNormalization::default()
+        };
+        assert_eq!(normalize_text("🚀 Launch", &keep_emoji), "🚀 Launch");
+    }
+}
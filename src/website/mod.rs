@@ -0,0 +1,393 @@
+use crate::config::WebsitesConfig;
+use crate::forges::ForgeRegistry;
+use crate::models::{Lead, WebsiteState, WebsiteStatus};
+use chrono::Utc;
+use reqwest::{Client, StatusCode};
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
+use std::sync::OnceLock;
+use url::Url;
+
+const BUNDLED_SUFFIXES: &str = include_str!("public_suffixes.txt");
+
+/// Multi-label public suffixes (`co.uk`, `github.io`)
+fn public_suffixes() -> &'static HashSet<&'static str> {
+    static SUFFIXES: OnceLock<HashSet<&'static str>> = OnceLock::new();
+    SUFFIXES.get_or_init(|| {
+        BUNDLED_SUFFIXES
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .collect()
+    })
+}
+
+/// Domain a host was registered under: one label below its public suffix
+/// (`www.shop.acme.co.uk` -> `acme.co.uk`, `acme.github.io` stays).
+/// `None` for IP addresses, single-label hosts and bare suffixes.
+pub fn registrable_domain(host: &str) -> Option<String> {
+    let host = host.trim().trim_end_matches('.').to_lowercase();
+    if host.parse::<IpAddr>().is_ok() || host.trim_matches(['[', ']']).parse::<IpAddr>().is_ok() {
+        return None;
+    }
+    let labels: Vec<&str> = host.split('.').collect();
+    if labels.len() < 2 || labels.iter().any(|label| label.is_empty()) {
+        return None;
+    }
+    // Longest listed suffix wins; every top-level domain is a suffix
+    let suffix_labels = (2..=labels.len())
+        .rev()
+        .find(|&n| public_suffixes().contains(labels[labels.len() - n..].join(".").as_str()))
+        .unwrap_or(1);
+    (suffix_labels < labels.len()).then(|| labels[labels.len() - suffix_labels - 1..].join("."))
+}
+
+/// Whether `host` is `domain` or one of its subdomains
+fn within(host: &str, domain: &str) -> bool {
+    host == domain
+        || host
+            .strip_suffix(domain)
+            .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// Canonicalizes lead websites and checks whether they are still up
+#[derive(Debug, Clone)]
+pub struct WebsiteChecker {
+    client: Client,
+    config: WebsitesConfig,
+}
+
+impl WebsiteChecker {
+    pub fn new(client: Client, config: &WebsitesConfig) -> Self {
+        let mut config = config.clone();
+        config.parked_phrases = config
+            .parked_phrases
+            .iter()
+            .map(|phrase| phrase.to_lowercase())
+            .collect();
+        Self { client, config }
+    }
+
+    /// Parse a website, assuming `https://` when the scheme is missing, and
+    /// drop the fragment and tracking parameters
+    pub fn canonical_url(&self, raw: &str) -> Option<Url> {
+        let raw = raw.trim();
+        let mut url = match Url::parse(raw) {
+            Ok(url) if url.host_str().is_some() => url,
+            _ => Url::parse(&format!("https://{}", raw)).ok()?,
+        };
+        if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+            return None;
+        }
+        url.set_fragment(None);
+        self.strip_tracking_params(&mut url);
+        Some(url)
+    }
+
+    /// Remove `utm_*`, `gclid`, `ref` and the other configured parameters
+    pub fn strip_tracking_params(&self, url: &mut Url) {
+        if url.query().is_none() {
+            return;
+        }
+        let kept: Vec<(String, String)> = url
+            .query_pairs()
+            .filter(|(name, _)| !self.is_tracking_param(name))
+            .map(|(name, value)| (name.into_owned(), value.into_owned()))
+            .collect();
+        if kept.is_empty() {
+            url.set_query(None);
+        } else {
+            url.query_pairs_mut().clear().extend_pairs(kept);
+        }
+    }
+
+    fn is_tracking_param(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.config
+            .tracking_params
+            .iter()
+            .any(|param| match param.strip_suffix('*') {
+                Some(prefix) => name.starts_with(&prefix.to_lowercase()),
+                None => name == param.to_lowercase(),
+            })
+    }
+
+    /// Directory site whose links are listings, not the lead's own website
+    pub fn is_listing_host(&self, host: &str) -> bool {
+        self.config
+            .listing_hosts
+            .iter()
+            .any(|listing| within(host, listing))
+    }
+
+    fn is_parking_host(&self, host: &str) -> bool {
+        self.config
+            .parking_hosts
+            .iter()
+            .any(|parking| within(host, parking))
+    }
+
+    /// Page of a domain parking service or marketplace
+    pub fn is_parked_page(&self, html: &str) -> bool {
+        let html = html.to_lowercase();
+        self.config
+            .parked_phrases
+            .iter()
+            .any(|phrase| html.contains(phrase.as_str()))
+    }
+
+    /// Strip tracking parameters from the lead's website and set its
+    /// canonical domain. Forge and listing hosts get no domain: they are
+    /// shared by many leads.
+    pub fn canonicalize(&self, lead: &mut Lead, forges: &ForgeRegistry) {
+        let Some(url) = lead.website.as_deref().and_then(|w| self.canonical_url(w)) else {
+            lead.canonical_domain = None;
+            return;
+        };
+        let raw = lead.website.as_deref().unwrap_or_default();
+        if raw.contains('?') || raw.contains('#') {
+            lead.website = Some(url.to_string());
+        }
+        let host = url.host_str().unwrap_or_default();
+        lead.canonical_domain = if forges.is_forge_host(host) || self.is_listing_host(host) {
+            None
+        } else {
+            registrable_domain(host)
+        };
+    }
+
+    /// GET a website, following redirects
+    pub async fn check(&self, website: &str) -> WebsiteStatus {
+        let mut status = WebsiteStatus {
+            final_url: None,
+            http_status: None,
+            redirected: false,
+            state: WebsiteState::Unknown,
+            error: None,
+            checked_at: Utc::now(),
+        };
+        let Some(requested) = self.canonical_url(website) else {
+            status.error = Some(format!("Invalid website URL: {}", website));
+            return status;
+        };
+
+        let response = match self.client.get(requested.as_str()).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                // DNS failures surface as connect errors
+                if e.is_connect() {
+                    status.state = WebsiteState::Dead;
+                }
+                status.error = Some(e.to_string());
+                return status;
+            }
+        };
+
+        let mut final_url = response.url().clone();
+        final_url.set_fragment(None);
+        self.strip_tracking_params(&mut final_url);
+        let parking_host = final_url
+            .host_str()
+            .is_some_and(|host| self.is_parking_host(host));
+        let code = response.status();
+        status.http_status = Some(code.as_u16());
+        status.redirected = final_url != requested;
+        status.final_url = Some(final_url.to_string());
+
+        status.state = if parking_host {
+            WebsiteState::Parked
+        } else if code.is_success() {
+            match response.text().await {
+                Ok(html) if self.is_parked_page(&html) => WebsiteState::Parked,
+                _ => WebsiteState::Live,
+            }
+        } else if code == StatusCode::NOT_FOUND || code == StatusCode::GONE {
+            WebsiteState::Dead
+        } else {
+            WebsiteState::Unknown
+        };
+        if !code.is_success() {
+            status.error = Some(format!("HTTP {}", code));
+        }
+        status
+    }
+
+    /// Check the lead's website; a live site that redirected elsewhere
+    /// becomes the lead's website and canonical domain
+    pub async fn check_lead(&self, lead: &mut Lead, forges: &ForgeRegistry) {
+        // Forge and listing pages say nothing about the company's own site
+        if lead.canonical_domain.is_none() {
+            return;
+        }
+        let Some(website) = lead.website.clone() else {
+            return;
+        };
+        let status = self.check(&website).await;
+        if status.state == WebsiteState::Live && status.redirected {
+            if let Some(ref final_url) = status.final_url {
+                lead.website = Some(final_url.clone());
+                self.canonicalize(lead, forges);
+            }
+        }
+        lead.website_status = Some(status);
+    }
+}
+
+/// Merge leads sharing a dedup key into the first of them, keeping order
+pub fn dedupe(leads: Vec<Lead>) -> Vec<Lead> {
+    let mut merged: Vec<Lead> = Vec::with_capacity(leads.len());
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for lead in leads {
+        let Some(key) = lead.dedup_key() else {
+            merged.push(lead);
+            continue;
+        };
+        match positions.get(&key) {
+            Some(&position) => merged[position].merge(lead),
+            None => {
+                positions.insert(key, merged.len());
+                merged.push(lead);
+            }
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ForgesConfig;
+    use crate::forges::test_server;
+    use crate::models::{PhoneNumber, Source};
+
+    fn checker() -> WebsiteChecker {
+        WebsiteChecker::new(Client::new(), &WebsitesConfig::default())
+    }
+
+    fn forges() -> ForgeRegistry {
+        ForgeRegistry::from_config(Client::new(), &ForgesConfig::default(), None)
+    }
+
+    #[test]
+    fn test_registrable_domain() {
+        for (host, domain) in [
+            ("acme.com", Some("acme.com")),
+            ("www.acme.com", Some("acme.com")),
+            ("WWW.Shop.Acme.co.uk.", Some("acme.co.uk")),
+            ("app.acme.com.au", Some("acme.com.au")),
+            ("acme.github.io", Some("acme.github.io")),
+            ("docs.acme.vercel.app", Some("acme.vercel.app")),
+            ("co.uk", None),
+            ("github.io", None),
+            ("localhost", None),
+            ("127.0.0.1", None),
+        ] {
+            assert_eq!(registrable_domain(host).as_deref(), domain, "{}", host);
+        }
+    }
+
+    #[test]
+    fn test_canonicalize_and_dedupe() {
+        let checker = checker();
+        let url = checker
+            .canonical_url("acme.com/pricing?utm_source=betalist&plan=pro&fbclid=x#top")
+            .unwrap();
+        assert_eq!(url.as_str(), "https://acme.com/pricing?plan=pro");
+
+        let forges = forges();
+        let mut from_betalist = Lead::new("Acme".to_string(), Source::BetaList).with_website(Some(
+            "http://www.acme.com/?utm_source=betalist&ref=betalist".to_string(),
+        ));
+        checker.canonicalize(&mut from_betalist, &forges);
+        assert_eq!(
+            from_betalist.website.as_deref(),
+            Some("http://www.acme.com/")
+        );
+        assert_eq!(from_betalist.canonical_domain.as_deref(), Some("acme.com"));
+
+        let mut from_yc = Lead::new("Acme Inc".to_string(), Source::YCombinator)
+            .with_website(Some("https://acme.com".to_string()))
+            .with_email(Some("hello@acme.com".to_string()))
+            .with_phones(vec![PhoneNumber {
+                e164: "+14155550100".to_string(),
+                country: Some("US".to_string()),
+                raw: "(415) 555-0100".to_string(),
+            }]);
+        checker.canonicalize(&mut from_yc, &forges);
+
+        let mut listing = Lead::new("Other".to_string(), Source::BetaList)
+            .with_website(Some("https://betalist.com/startups/other".to_string()));
+        checker.canonicalize(&mut listing, &forges);
+        assert_eq!(listing.canonical_domain, None);
+
+        let mut on_github = Lead::new("Tool".to_string(), Source::BetaList)
+            .with_website(Some("https://github.com/acme/tool".to_string()));
+        checker.canonicalize(&mut on_github, &forges);
+        assert_eq!(on_github.canonical_domain, None);
+
+        let leads = dedupe(vec![from_betalist, listing, from_yc, on_github]);
+        assert_eq!(leads.len(), 3);
+        assert_eq!(leads[0].name, "Acme");
+        assert_eq!(leads[0].email.as_deref(), Some("hello@acme.com"));
+        assert_eq!(leads[0].phones.len(), 1);
+        assert_eq!(leads[0].source, Source::BetaList);
+        assert_eq!(
+            leads[0].sources,
+            vec![Source::BetaList, Source::YCombinator]
+        );
+        assert_eq!(leads[1].name, "Other");
+        assert_eq!(leads[1].sources, vec![Source::BetaList]);
+    }
+
+    #[tokio::test]
+    async fn test_check_follows_redirects_and_classifies() {
+        let parked = "<html><body><h1>This domain is for sale!</h1></body></html>";
+        let base = test_server::serve_raw(vec![
+            (
+                "/old",
+                "HTTP/1.1 301 Moved Permanently\r\nLocation: /home?utm_medium=redirect\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            ),
+            (
+                "/home",
+                "HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nHello".to_string(),
+            ),
+            (
+                "/parked",
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    parked.len(),
+                    parked
+                ),
+            ),
+            (
+                "/blocked",
+                "HTTP/1.1 403 Forbidden\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string(),
+            ),
+        ])
+        .await;
+        let checker = checker();
+
+        let status = checker.check(&format!("{}/old", base)).await;
+        assert_eq!(status.state, WebsiteState::Live);
+        assert_eq!(status.http_status, Some(200));
+        assert!(status.redirected);
+        assert_eq!(status.final_url, Some(format!("{}/home", base)));
+
+        let status = checker.check(&format!("{}/parked", base)).await;
+        assert_eq!(status.state, WebsiteState::Parked);
+
+        let status = checker.check(&format!("{}/missing", base)).await;
+        assert_eq!(status.state, WebsiteState::Dead);
+        assert_eq!(status.http_status, Some(404));
+
+        let status = checker.check(&format!("{}/blocked", base)).await;
+        assert_eq!(status.state, WebsiteState::Unknown);
+
+        // Nothing listening
+        let status = checker.check("http://127.0.0.1:1/").await;
+        assert_eq!(status.state, WebsiteState::Dead);
+        assert!(status.error.is_some());
+    }
+}